@@ -2,9 +2,26 @@ use rand::prelude::*;
 use rand::rngs::OsRng;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+#[cfg(feature = "testing")]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+#[cfg(feature = "testing")]
+static DETERMINISTIC_MODE: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "testing")]
+static DETERMINISTIC_COUNTER: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "testing")]
+static DETERMINISTIC_SEEDED: AtomicBool = AtomicBool::new(false);
 
 /// Generates a unique hash
+///
+/// When a deterministic seed has been set with [`set_deterministic_seed`](set_deterministic_seed),
+/// this instead returns the next value of a counter starting at that seed.
 pub fn get_unique_hash() -> u64 {
+  #[cfg(feature = "testing")]
+  if DETERMINISTIC_MODE.load(Ordering::SeqCst) {
+    return DETERMINISTIC_COUNTER.fetch_add(1, Ordering::SeqCst);
+  }
+
   let mut seed = vec![0; 16];
   OsRng.fill_bytes(&mut seed);
 
@@ -13,3 +30,56 @@ pub fn get_unique_hash() -> u64 {
 
   hasher.finish()
 }
+
+/// Makes every following call to [`get_unique_hash`](get_unique_hash) return a monotonically increasing
+/// counter, starting at the given seed.
+///
+/// The seed can only be set once per process, as resetting the counter would hand out hashes that are
+/// already in use. Every call after the first does nothing and returns false.
+///
+/// The counter is shared across every thread, so the exact values are only reproducible when hashes are
+/// generated from a single thread, such as with ``cargo test -- --test-threads=1``.
+#[cfg(feature = "testing")]
+pub fn set_deterministic_seed(seed: u64) -> bool {
+  if DETERMINISTIC_SEEDED
+    .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+    .is_err()
+  {
+    return false;
+  }
+
+  DETERMINISTIC_COUNTER.store(seed, Ordering::SeqCst);
+  DETERMINISTIC_MODE.store(true, Ordering::SeqCst);
+
+  true
+}
+
+/// Returns [`get_unique_hash`](get_unique_hash) to generating random hashes.
+///
+/// Since the seed can only be set once, this lasts for the rest of the process.
+#[cfg(feature = "testing")]
+pub fn clear_deterministic_seed() {
+  DETERMINISTIC_MODE.store(false, Ordering::SeqCst);
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deterministic_hashes_increase() {
+    assert!(set_deterministic_seed(100));
+
+    let first_hash = get_unique_hash();
+    let second_hash = get_unique_hash();
+    let was_reseeded = set_deterministic_seed(100);
+    let third_hash = get_unique_hash();
+
+    clear_deterministic_seed();
+
+    assert_eq!(first_hash, 100);
+    assert_eq!(second_hash, 101);
+    assert!(!was_reseeded);
+    assert_eq!(third_hash, 102);
+  }
+}
@@ -159,7 +159,7 @@ impl Player {
 
       if model_tags.remove(AnimationActor::NAME) {
         let animation_names: Vec<String> = model_tags.into_iter().collect();
-        let Some(animation_name) = animation_names.get(0) else {
+        let Some(animation_name) = animation_names.first() else {
           continue;
         };
 
@@ -38,13 +38,12 @@ fn main() {
   // let path = PathBuf::from("examples/worlds/test_world.wrld");
   // // let path = PathBuf::from("examples/worlds/large_test_world.wrld");
   // let stored_world = StoredWorld::load(&path).unwrap();
-  // let screen_data = ScreenData::from_world(stored_world);
+  // let (screen_data, _) = ScreenData::from_world(stored_world).unwrap();
   //
   // let stored_world = StoredWorld::load(path).unwrap();
   // log::info!("{:#?}", stored_world);
   //
-  // let (_printing_thread_handle, printing_thread_kill_sender) =
-  //   screen_data.spawn_printing_thread(60, None);
+  // let printing_thread = screen_data.spawn_printing_thread(60, None);
   //
   // let mut model_manager = screen_data.get_model_manager();
   //
@@ -79,7 +78,7 @@ fn main() {
   //     .log_if_err();
   // }
   //
-  // let _ = printing_thread_kill_sender.send(());
+  // printing_thread.kill().unwrap();
 }
 
 #[allow(dead_code)]
@@ -115,6 +114,7 @@ fn create_large_test_world() {
     .unwrap();
 }
 
+#[allow(unused)]
 trait ResultTraits<T> {
   /// Logs the result if it's an error.
   /// The message will be under 'Error' when logged.
@@ -16,6 +16,11 @@ pub struct ConfigData {
   pub tick_duration: u32,
   pub grid_width: u32,
   pub grid_height: u32,
+
+  /// The height of a terminal cell divided by its width.
+  ///
+  /// Used to scale the y axis for radial queries so circles appear round on screen.
+  pub aspect_ratio: f32,
 }
 
 impl Default for ConfigData {
@@ -27,10 +32,35 @@ impl Default for ConfigData {
       tick_duration: 24,
       grid_width: 175,
       grid_height: 40,
+      aspect_ratio: 2.0,
     }
   }
 }
 
+impl ConfigData {
+  /// Returns the duration of a tick in milliseconds.
+  pub fn tick_duration_ms(&self) -> u32 {
+    self.tick_duration
+  }
+
+  /// Returns the width of the grid in characters.
+  pub fn grid_width(&self) -> u32 {
+    self.grid_width
+  }
+
+  /// Returns the height of the grid in characters.
+  pub fn grid_height(&self) -> u32 {
+    self.grid_height
+  }
+
+  /// Returns the width of a row in a frame, which includes the new line at the end of the row.
+  ///
+  /// Use this when converting between indices in a frame and coordinates.
+  pub fn frame_width(&self) -> usize {
+    self.grid_width as usize + 1
+  }
+}
+
 /// Returns a new ConfigData.
 ///
 /// If a config.toml didn't already exist
@@ -133,7 +163,8 @@ impl ConfigTraits for ConfigBuilder<DefaultState> {
       .set_default("empty_pixel", default_data.empty_pixel)?
       .set_default("tick_duration", default_data.tick_duration)?
       .set_default("grid_width", default_data.grid_width)?
-      .set_default("grid_height", default_data.grid_height)
+      .set_default("grid_height", default_data.grid_height)?
+      .set_default("aspect_ratio", default_data.aspect_ratio as f64)
   }
 }
 
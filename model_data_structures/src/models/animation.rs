@@ -53,6 +53,81 @@ impl ModelAnimationData {
       .get_current_model_appearance(&self.animations)
   }
 
+  /// Returns which frame is currently being displayed.
+  ///
+  /// This can be compared between calls to tell when the current appearance has changed.
+  pub fn get_displayed_frame(&self) -> DisplayedFrame<'_> {
+    self
+      .model_animator
+      .borrow_mut()
+      .get_displayed_frame(&self.animations)
+  }
+
+  /// Returns true if there are animations queued or currently running.
+  ///
+  /// Any animations that have finished running since the last check are removed from the queue first.
+  pub fn has_animations_to_run(&self) -> bool {
+    let mut model_animator = self.model_animator.borrow_mut();
+
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = model_animator.get_current_model_appearance(&self.animations);
+
+    model_animator.has_animations_to_run()
+  }
+
+  /// Returns how many ticks have passed since the last animation in the queue finished running.
+  ///
+  /// 0 is returned if there are animations currently running.
+  /// None is returned if no animation has finished running yet.
+  pub fn ticks_since_last_animation(&self) -> Option<u64> {
+    let mut model_animator = self.model_animator.borrow_mut();
+
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = model_animator.get_current_model_appearance(&self.animations);
+
+    model_animator.ticks_since_last_animation()
+  }
+
+  /// Returns true if an animation finished running since the last time this was called.
+  ///
+  /// Animations that run forever never finish.
+  pub fn animation_just_finished(&self) -> bool {
+    self
+      .model_animator
+      .borrow_mut()
+      .take_animation_finished(&self.animations)
+  }
+
+  /// Returns the index of the frame currently being displayed in the running animation.
+  ///
+  /// None is returned if there's no animation running.
+  pub fn current_frame_index(&self) -> Option<u64> {
+    self
+      .model_animator
+      .borrow_mut()
+      .current_frame_index(&self.animations)
+  }
+
+  /// Returns how many loops of the running animation haven't been completed yet.
+  ///
+  /// None is returned if there's no animation running, or the running animation loops forever.
+  pub fn animation_loops_remaining(&self) -> Option<u64> {
+    self
+      .model_animator
+      .borrow_mut()
+      .animation_loops_remaining(&self.animations)
+  }
+
+  /// Returns the anchor offset of the frame currently being displayed in the running animation.
+  ///
+  /// None is returned if there's no animation running.
+  pub fn current_anchor_offset(&self) -> Option<(isize, isize)> {
+    self
+      .model_animator
+      .borrow_mut()
+      .current_anchor_offset(&self.animations)
+  }
+
   /// Returns true if there's an animation with the given name.
   pub fn contains_animation(&self, animation_name: &str) -> bool {
     self.animations.contains_key(animation_name)
@@ -131,6 +206,55 @@ impl ModelAnimationData {
     Ok(())
   }
 
+  /// Moves the first instance of the animation in the queue to the given index.
+  ///
+  /// Indices past the back of the queue will move the animation to the back.
+  ///
+  /// # Errors
+  ///
+  /// - The animation wasn't in the queue.
+  pub fn move_queued_animation(
+    &mut self,
+    animation_name: &str,
+    to_index: usize,
+  ) -> Result<(), AnimationError> {
+    self.model_animator.borrow_mut().move_queued_animation(
+      &self.animations,
+      animation_name,
+      to_index,
+    )
+  }
+
+  /// Freezes the running animation in place until the animations are resumed.
+  pub fn pause_animations(&mut self) {
+    self.model_animator.borrow_mut().pause()
+  }
+
+  /// Continues running animations from where they were paused.
+  pub fn resume_animations(&mut self) {
+    self.model_animator.borrow_mut().resume()
+  }
+
+  /// Changes how fast animations are played, a multiplier of 2.0 plays animations twice as fast.
+  ///
+  /// Refer to [`ModelAnimator::set_animation_speed`](ModelAnimator::set_animation_speed) for more details.
+  pub fn set_animation_speed(&mut self, multiplier: f32) {
+    self
+      .model_animator
+      .borrow_mut()
+      .set_animation_speed(multiplier)
+  }
+
+  /// Returns the multiplier for how fast animations are played.
+  pub fn get_animation_speed(&self) -> f32 {
+    self.model_animator.borrow().get_animation_speed()
+  }
+
+  /// Returns true if the animations are paused.
+  pub fn animations_are_paused(&self) -> bool {
+    self.model_animator.borrow().is_paused()
+  }
+
   /// Removes every animation running in the animation queue.
   ///
   /// The last existing animation to be run in the list is assigned to the last_run_animation.
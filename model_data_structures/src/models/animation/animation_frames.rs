@@ -18,6 +18,11 @@ pub struct AnimationFrame {
   appearance: Sprite,
   /// This is how many ticks this frame should live for.
   frame_duration: u32,
+  /// How far the model's anchor is shifted while this frame is displayed.
+  ///
+  /// The offset only applies to this frame, it's reset when the frame changes rather than accumulated.
+  #[serde(default)]
+  anchor_offset: (isize, isize),
 }
 
 // TODO: Add documentation
@@ -26,6 +31,11 @@ pub enum AnimationLoopCount {
   Forever,
   /// Contains how many times an animation should loop for.
   Limited(u64),
+  /// Plays the frames forwards then backwards, and contains how many times to do so.
+  ///
+  /// The first and last frames aren't repeated when the direction changes,
+  /// so an animation with frames 0, 1, and 2 cycles through 0 -> 1 -> 2 -> 1.
+  PingPong(u64),
 }
 
 impl AnimationFrames {
@@ -59,20 +69,86 @@ impl AnimationFrames {
 
   /// Takes an exclusive amount of frames that've been iterated through.
   ///
-  /// Returns true if the amount of frames iterated exceeds the animation's duration.
+  /// Returns true once the amount of frames iterated reaches the animation's duration,
+  /// and stays true for any amount past it.
   ///
   /// The duration of an animation would be frames * loop_count.
   pub fn reached_loop_count(&self, frames_iterated_through: u64) -> bool {
-    let animation_loops_occurred = frames_iterated_through / self.frame_count();
+    let animation_loops_occurred =
+      frames_iterated_through / self.cycle_frame_indices().count() as u64;
 
     self.loop_count.reached_loop_count(animation_loops_occurred)
   }
 
+  /// Takes the amount of ticks since the animation started.
+  ///
+  /// Returns how many loops of the animation haven't been completed yet.
+  /// The loop currently being displayed counts as remaining until its last frame has finished,
+  /// meaning a `Limited(3)` animation returns 3 on its first tick, and 1 during its final loop.
+  /// Once every loop has finished 0 is returned.
+  ///
+  /// None is returned if the animation loops forever.
+  pub fn remaining_loops(&self, ticks_since_started: u64) -> Option<u64> {
+    let (AnimationLoopCount::Limited(loop_count) | AnimationLoopCount::PingPong(loop_count)) =
+      self.loop_count
+    else {
+      return None;
+    };
+    let completed_loops = ticks_since_started
+      .checked_div(self.get_cycle_duration())
+      .unwrap_or(0);
+
+    Some(loop_count.saturating_sub(completed_loops))
+  }
+
   /// Returns a reference to the list of frames in the animation.
   pub fn get_frames(&self) -> &Vec<AnimationFrame> {
     &self.frames
   }
 
+  /// Returns a copy of the animation with its frames in reverse order.
+  ///
+  /// The loop count, resting appearance, and duration of each frame are kept as they are.
+  pub fn reversed(&self) -> AnimationFrames {
+    let mut frames = self.frames.clone();
+    frames.reverse();
+
+    Self::new(frames, self.loop_count, self.resting_appearance.clone())
+  }
+
+  /// Adds every frame of the other animation onto the end of this one.
+  ///
+  /// This animation's loop count and resting appearance are kept, the other animation's are ignored.
+  pub fn append(&mut self, other: &AnimationFrames) {
+    self.frames.extend(other.frames.iter().cloned());
+  }
+
+  /// Sets the duration of every frame in the animation to the given amount of ticks.
+  ///
+  /// A duration of 0 is clamped to 1, as frames must last for at least a tick.
+  pub fn with_uniform_duration(mut self, ticks: u32) -> Self {
+    let ticks = ticks.max(1);
+
+    self
+      .frames
+      .iter_mut()
+      .for_each(|frame| frame.frame_duration = ticks);
+
+    self
+  }
+
+  /// Multiplies the duration of every frame in the animation by the given factor.
+  ///
+  /// A factor of 0 is clamped to 1, as frames must last for at least a tick.
+  /// Durations that would overflow are capped at `u32::MAX`.
+  pub fn scale_durations(&mut self, factor: u32) {
+    let factor = factor.max(1);
+
+    self.frames.iter_mut().for_each(|frame| {
+      frame.frame_duration = frame.frame_duration.saturating_mul(factor);
+    });
+  }
+
   /// Returns the loop count of this animation.
   pub fn get_loop_count(&self) -> &AnimationLoopCount {
     &self.loop_count
@@ -82,7 +158,7 @@ impl AnimationFrames {
   ///
   /// Returns the old resting appearance if it existed.
   pub fn set_resting_appearance(&mut self, new_resting_appearance: Sprite) -> Option<Sprite> {
-    std::mem::replace(&mut self.resting_appearance, Some(new_resting_appearance))
+    self.resting_appearance.replace(new_resting_appearance)
   }
 
   /// Returns a reference to the animation's resting appearance.
@@ -96,7 +172,9 @@ impl AnimationFrames {
   ///
   /// None is returned if the animation runs to infinity.
   pub fn get_total_duration(&self) -> Option<u64> {
-    let AnimationLoopCount::Limited(loop_count) = self.loop_count else {
+    let (AnimationLoopCount::Limited(loop_count) | AnimationLoopCount::PingPong(loop_count)) =
+      self.loop_count
+    else {
       return None;
     };
 
@@ -104,12 +182,25 @@ impl AnimationFrames {
   }
 
   /// Returns the total ticks for each loop of the animation.
+  ///
+  /// For ping-pong animations, a loop is a full pass forwards and back.
   pub fn get_cycle_duration(&self) -> u64 {
     self
-      .frames
-      .iter()
-      .map(AnimationFrame::get_frame_duration)
-      .sum::<u32>() as u64
+      .cycle_frame_indices()
+      .map(|frame_index| self.frames[frame_index].get_frame_duration() as u64)
+      .sum()
+  }
+
+  /// Returns the index of every frame in the order they're displayed during a single loop of the animation.
+  fn cycle_frame_indices(&self) -> impl Iterator<Item = usize> {
+    let frame_count = self.frames.len();
+    let returning_frames = if let AnimationLoopCount::PingPong(_) = self.loop_count {
+      1..frame_count.saturating_sub(1)
+    } else {
+      0..0
+    };
+
+    (0..frame_count).chain(returning_frames.rev())
   }
 
   /// Returns the frame in the animation based on the amount of ticks.
@@ -123,6 +214,15 @@ impl AnimationFrames {
   ///
   /// None is returned if the ticks passed in surpasses the total duration of the animation itself.
   pub fn get_frame_based_on_ticks(&self, ticks: u64) -> Option<&AnimationFrame> {
+    let current_frame = self.get_frame_index_based_on_ticks(ticks)?;
+
+    self.get_frame(current_frame)
+  }
+
+  /// Returns the index of the frame that would be displayed after the given amount of ticks.
+  ///
+  /// Follows the same rules as [`get_frame_based_on_ticks`](AnimationFrames::get_frame_based_on_ticks).
+  pub fn get_frame_index_based_on_ticks(&self, ticks: u64) -> Option<u64> {
     let total_animation_duration = self.get_total_duration();
 
     if let Some(total_animation_duration) = total_animation_duration {
@@ -140,8 +240,8 @@ impl AnimationFrames {
 
     let mut remaining_ticks = ticks % cycle_duration;
 
-    let current_frame = self.frames.iter().position(|frame| {
-      let frame_duration = frame.get_frame_duration() as u64;
+    let current_frame = self.cycle_frame_indices().find(|frame_index| {
+      let frame_duration = self.frames[*frame_index].get_frame_duration() as u64;
 
       if remaining_ticks < frame_duration {
         true
@@ -152,7 +252,7 @@ impl AnimationFrames {
       }
     })? as u64;
 
-    self.get_frame(current_frame)
+    Some(current_frame)
   }
 
   /// Returns an [`AnimationValidityErrorData`](crate::models::animation::errors::AnimationValidityErrorData)
@@ -207,9 +307,24 @@ impl AnimationFrame {
     Self {
       appearance,
       frame_duration: duration,
+      anchor_offset: (0, 0),
     }
   }
 
+  /// Sets how far the model's anchor is shifted while this frame is displayed.
+  ///
+  /// The offset isn't accumulated between frames, once the frame changes the next frame's offset is used instead.
+  pub fn with_anchor_offset(mut self, anchor_offset: (isize, isize)) -> Self {
+    self.anchor_offset = anchor_offset;
+
+    self
+  }
+
+  /// Returns how far the model's anchor is shifted while this frame is displayed.
+  pub fn get_anchor_offset(&self) -> (isize, isize) {
+    self.anchor_offset
+  }
+
   pub fn get_frame_duration(&self) -> u32 {
     self.frame_duration
   }
@@ -217,13 +332,23 @@ impl AnimationFrame {
   pub fn get_appearance(&self) -> &Sprite {
     &self.appearance
   }
+
+  /// Returns the character the anchor is replaced with while this frame is displayed.
+  pub fn anchor_replacement_char(&self) -> char {
+    self.appearance.anchor_replacement_character()
+  }
 }
 
 impl AnimationLoopCount {
+  /// Returns true once the counter has reached the maximum amount of loops.
+  ///
+  /// Any counter past the maximum is also considered to have reached it.
+  /// [`Forever`](AnimationLoopCount::Forever) never reaches its loop count.
   pub fn reached_loop_count(&self, current_loop_counter: u64) -> bool {
     match self {
       AnimationLoopCount::Forever => false,
-      AnimationLoopCount::Limited(max_loop_count) => max_loop_count == &current_loop_counter,
+      AnimationLoopCount::Limited(max_loop_count)
+      | AnimationLoopCount::PingPong(max_loop_count) => &current_loop_counter >= max_loop_count,
     }
   }
 }
@@ -284,6 +409,45 @@ mod tests {
     assert_eq!(unlimited_animation.get_total_duration(), None);
   }
 
+  #[cfg(test)]
+  mod frame_duration_logic {
+    use super::*;
+
+    #[test]
+    fn uniform_duration() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(1))
+          .with_uniform_duration(4);
+
+      assert_eq!(animation.get_cycle_duration(), 12);
+      assert_eq!(animation.get_frame_index_based_on_ticks(5), Some(1));
+    }
+
+    #[test]
+    fn scaled_durations() {
+      let mut animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(2));
+
+      animation.scale_durations(3);
+
+      assert_eq!(animation.get_total_duration(), Some(18));
+    }
+
+    #[test]
+    fn zero_durations_are_clamped() {
+      let mut animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(1))
+          .with_uniform_duration(0);
+
+      animation.scale_durations(0);
+
+      assert!(animation
+        .get_frames()
+        .iter()
+        .all(|frame| frame.get_frame_duration() == 1));
+    }
+  }
+
   #[cfg(test)]
   mod get_frame_based_on_ticks_logic {
     use super::*;
@@ -347,6 +511,59 @@ mod tests {
     }
   }
 
+  #[cfg(test)]
+  mod ping_pong_logic {
+    use super::*;
+
+    #[test]
+    fn frame_sequence() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::PingPong(2));
+
+      let expected_indices = vec![0, 1, 2, 1, 0, 1, 2, 1];
+
+      let indices: Vec<u64> = (0..8)
+        .map(|tick| animation.get_frame_index_based_on_ticks(tick).unwrap())
+        .collect();
+
+      assert_eq!(indices, expected_indices);
+      assert_eq!(
+        animation.get_frame_based_on_ticks(3),
+        animation.get_frame(1)
+      );
+      assert!(animation.get_frame_based_on_ticks(8).is_none());
+    }
+
+    #[test]
+    fn durations() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::PingPong(2));
+
+      assert_eq!(animation.get_cycle_duration(), 4);
+      assert_eq!(animation.get_total_duration(), Some(8));
+    }
+
+    #[test]
+    fn reached_loop_count() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::PingPong(2));
+
+      assert!(!animation.reached_loop_count(7));
+      assert!(animation.reached_loop_count(8));
+    }
+
+    #[test]
+    fn single_frame() {
+      let frames =
+        TestingData::get_test_frames(vec![(TestingData::get_frame_appearance('x'), 2, 'x')]);
+      let animation = AnimationFrames::new(frames, AnimationLoopCount::PingPong(3), None);
+
+      assert_eq!(animation.get_cycle_duration(), 2);
+      assert_eq!(animation.get_frame_index_based_on_ticks(5), Some(0));
+      assert!(animation.get_frame_index_based_on_ticks(6).is_none());
+    }
+  }
+
   #[test]
   fn frame_count_logic() {
     let loop_count = AnimationLoopCount::Limited(2);
@@ -355,6 +572,17 @@ mod tests {
     assert_eq!(animation.frame_count(), 3);
   }
 
+  #[test]
+  fn anchor_replacement_char_comes_from_the_sprite() {
+    let frames = TestingData::get_test_frames(vec![
+      (TestingData::get_frame_appearance('x'), 1, 'x'),
+      (TestingData::get_frame_appearance('y'), 1, '-'),
+    ]);
+
+    assert_eq!(frames[0].anchor_replacement_char(), 'x');
+    assert_eq!(frames[1].anchor_replacement_char(), '-');
+  }
+
   #[cfg(test)]
   mod reached_loop_count_logic {
     use super::*;
@@ -379,6 +607,50 @@ mod tests {
       assert!(!unlimited_animation.reached_loop_count(9999));
       assert!(!unlimited_animation.reached_loop_count(9999));
     }
+
+    #[test]
+    fn counter_past_the_final_loop() {
+      let loop_count_limited = AnimationLoopCount::Limited(2);
+      let limited_animation = TestingData::get_test_animation(['l', 'm', 'n'], loop_count_limited);
+
+      assert!(limited_animation.reached_loop_count(7));
+      assert!(limited_animation.reached_loop_count(9));
+      assert!(AnimationLoopCount::PingPong(2).reached_loop_count(3));
+    }
+  }
+
+  #[cfg(test)]
+  mod remaining_loops_logic {
+    use super::*;
+
+    #[test]
+    fn limited_animation() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(3));
+
+      assert_eq!(animation.remaining_loops(0), Some(3));
+      assert_eq!(animation.remaining_loops(2), Some(3));
+      assert_eq!(animation.remaining_loops(3), Some(2));
+      assert_eq!(animation.remaining_loops(8), Some(1));
+      assert_eq!(animation.remaining_loops(9), Some(0));
+      assert_eq!(animation.remaining_loops(100), Some(0));
+    }
+
+    #[test]
+    fn ping_pong_animation() {
+      let animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::PingPong(2));
+
+      assert_eq!(animation.remaining_loops(3), Some(2));
+      assert_eq!(animation.remaining_loops(4), Some(1));
+    }
+
+    #[test]
+    fn unlimited_animation() {
+      let animation = TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Forever);
+
+      assert!(animation.remaining_loops(0).is_none());
+    }
   }
 
   #[test]
@@ -467,9 +739,7 @@ mod tests {
     fn every_frame_is_invalid() {
       let loop_count = AnimationLoopCount::Limited(2);
       let broken_sprite = Sprite::new_unchecked("xx\nxcc", 'c', 'x', 'c', 4);
-      let animation_frames = std::iter::repeat((1, broken_sprite.clone()))
-        .take(4)
-        .collect();
+      let animation_frames = std::iter::repeat_n((1, broken_sprite.clone()), 4).collect();
       let mut animation = AnimationFrames::from((loop_count, animation_frames, None));
       animation.set_resting_appearance(broken_sprite);
       let animation_name = "test_animation".to_string();
@@ -519,4 +789,102 @@ mod tests {
       assert_eq!(result, expected_result);
     }
   }
+
+  #[test]
+  fn with_anchor_offset_logic() {
+    let sprite = Sprite::new("a", 'a', 'x', '-').unwrap();
+    let frame = AnimationFrame::new(sprite.clone(), 1);
+    let lunging_frame = AnimationFrame::new(sprite, 1).with_anchor_offset((2, 0));
+
+    assert_eq!(frame.get_anchor_offset(), (0, 0));
+    assert_eq!(lunging_frame.get_anchor_offset(), (2, 0));
+  }
+
+  #[test]
+  fn append_logic() {
+    let resting_appearance = Sprite::new("a", 'a', 'r', '-').unwrap();
+    let mut animation = AnimationFrames::new(
+      TestingData::get_test_frames(vec![
+        (TestingData::get_frame_appearance('x'), 2, 'x'),
+        (TestingData::get_frame_appearance('y'), 1, 'y'),
+      ]),
+      AnimationLoopCount::Limited(1),
+      Some(resting_appearance.clone()),
+    );
+    let other_animation = AnimationFrames::new(
+      TestingData::get_test_frames(vec![(TestingData::get_frame_appearance('z'), 3, 'z')]),
+      AnimationLoopCount::Forever,
+      None,
+    );
+    let original_animation = animation.clone();
+
+    animation.append(&other_animation);
+
+    assert_eq!(animation.frame_count(), 3);
+    assert_eq!(
+      animation.get_cycle_duration(),
+      original_animation.get_cycle_duration() + other_animation.get_cycle_duration()
+    );
+    assert_eq!(animation.get_loop_count(), &AnimationLoopCount::Limited(1));
+    assert_eq!(
+      animation.get_resting_appearance(),
+      Some(&resting_appearance)
+    );
+    // Ticks 0-1 are 'x', tick 2 is 'y', and ticks 3-5 are the appended 'z'.
+    assert_eq!(
+      animation.get_frame_based_on_ticks(2),
+      original_animation.get_frame(1)
+    );
+    assert_eq!(
+      animation.get_frame_based_on_ticks(3),
+      other_animation.get_frame(0)
+    );
+    assert_eq!(
+      animation.get_frame_based_on_ticks(5),
+      other_animation.get_frame(0)
+    );
+    assert!(animation.get_frame_based_on_ticks(6).is_none());
+  }
+
+  #[test]
+  fn reversed_logic() {
+    let mut frames = TestingData::get_test_frames(vec![
+      (TestingData::get_frame_appearance('x'), 1, 'x'),
+      (TestingData::get_frame_appearance('y'), 2, 'y'),
+      (TestingData::get_frame_appearance('z'), 3, 'z'),
+    ]);
+    let resting_appearance = frames[0].get_appearance().clone();
+    let animation = AnimationFrames::new(
+      frames.clone(),
+      AnimationLoopCount::Limited(2),
+      Some(resting_appearance),
+    );
+    frames.reverse();
+
+    let reversed_animation = animation.reversed();
+
+    assert_eq!(reversed_animation.get_frame(0), animation.get_frame(2));
+    assert_eq!(reversed_animation.get_frames(), &frames);
+    assert_eq!(
+      reversed_animation.get_loop_count(),
+      animation.get_loop_count()
+    );
+    assert_eq!(
+      reversed_animation.get_resting_appearance(),
+      animation.get_resting_appearance()
+    );
+    assert_eq!(
+      reversed_animation.get_total_duration(),
+      animation.get_total_duration()
+    );
+    // The last frame of the original lasts 3 ticks, which is now at the start.
+    assert_eq!(
+      reversed_animation.get_frame_based_on_ticks(2),
+      animation.get_frame(2)
+    );
+    assert_eq!(
+      reversed_animation.get_frame_based_on_ticks(3),
+      animation.get_frame(1)
+    );
+  }
 }
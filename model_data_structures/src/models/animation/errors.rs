@@ -28,11 +28,53 @@ pub enum AnimationError {
   )]
   AnimationDoesntExist { invalid_animation_name: String },
 
+  /// A model was checked for how long it's been idle, but it has yet to finish running an animation.
+  #[error("A model has yet to finish running an animation.")]
+  NoAnimationHasFinished,
+
+  /// Attempted to move an animation within a model's queue, but it wasn't in the queue.
+  #[error(
+    "Attempted to move an animation that isn't queued. animation_name: {:?}",
+    animation_name
+  )]
+  AnimationNotQueued { animation_name: String },
+
   /// An instance of [`ModelAnimationData`](crate::models::animation::ModelAnimationData) contained animations with frames containing invalid Sprites.
   #[error("Invalid sprites found in a model's animation data: {:?}", .0)]
   AnimationValidityCheckFailed(Vec<AnimationValidityErrorData>),
 }
 
+impl AnimationError {
+  /// Returns a human readable, single line description of the error.
+  pub fn user_message(&self) -> String {
+    match self {
+      AnimationError::AnimationParserError(parser_error) => {
+        format!("Failed to parse an animation file: {parser_error}.")
+      }
+      AnimationError::AnimationDoesntExist {
+        invalid_animation_name,
+      } => format!("The animation {invalid_animation_name:?} doesn't exist."),
+      AnimationError::NoAnimationHasFinished => {
+        "The model has yet to finish running an animation.".to_string()
+      }
+      AnimationError::AnimationNotQueued { animation_name } => {
+        format!("The animation {animation_name:?} isn't queued.")
+      }
+      AnimationError::AnimationValidityCheckFailed(invalid_animations) => {
+        let animation_names: Vec<&str> = invalid_animations
+          .iter()
+          .map(|animation| animation.animation_name.as_str())
+          .collect();
+
+        format!(
+          "Invalid sprites were found in the animations: {}.",
+          animation_names.join(", ")
+        )
+      }
+    }
+  }
+}
+
 /// Since almost no error is returned to the user from the animation parser, most errors here will only ever
 /// be logged during parsing.
 /// This means that it is up to the animation parser to log both the file and error if anything goes wrong,
@@ -10,8 +10,14 @@ use std::{
   time::Duration,
 };
 
+/// The lowest speed animations can be set to through [`set_animation_speed`](ModelAnimator::set_animation_speed).
+pub const MINIMUM_ANIMATION_SPEED: f32 = 0.01;
+
+/// The highest speed animations can be set to through [`set_animation_speed`](ModelAnimator::set_animation_speed).
+pub const MAXIMUM_ANIMATION_SPEED: f32 = 100.0;
+
 /// Handles the current running animations, when they started, and what the last run animation was.
-#[derive(Default, Clone, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct ModelAnimator {
   /// Contains the list of names of animations to be run.
   /// Should be push_back -> pop_front
@@ -20,20 +26,153 @@ pub struct ModelAnimator {
   /// The tickrate contained is based on the tickrate in the config file.
   current_animation_start: Option<EventSync>,
   last_run_animation: Option<String>,
+  /// Contains an EventSync for when the last animation in the queue finished running.
+  ///
+  /// None if no animation has finished running yet.
+  #[serde(skip)]
+  last_animation_finish: Option<EventSync>,
+  /// Set when an animation finishes running, and cleared once it's been checked through
+  /// [`take_animation_finished`](ModelAnimator::take_animation_finished).
+  #[serde(skip)]
+  animation_finished: bool,
+  /// When true, the time since the current animation started is frozen,
+  /// along with the time since the last animation finished.
+  #[serde(skip)]
+  paused: bool,
+  /// How many animation ticks pass for every tick of real time.
+  #[serde(skip, default = "default_animation_speed")]
+  animation_speed: f32,
+}
+
+impl Default for ModelAnimator {
+  fn default() -> Self {
+    Self {
+      animation_queue: VecDeque::new(),
+      current_animation_start: None,
+      last_run_animation: None,
+      last_animation_finish: None,
+      animation_finished: false,
+      paused: false,
+      animation_speed: default_animation_speed(),
+    }
+  }
+}
+
+/// The EventSyncs are rebuilt from their elapsed time rather than copied, as a copied EventSync
+/// shares its clock with the original. Pausing, resuming, or restarting the animations of a clone
+/// would otherwise change the animations of the original as well.
+impl Clone for ModelAnimator {
+  fn clone(&self) -> Self {
+    Self {
+      animation_queue: self.animation_queue.clone(),
+      current_animation_start: self
+        .current_animation_start
+        .as_ref()
+        .map(|event_sync| self.new_event_sync(event_sync.time_since_started())),
+      last_run_animation: self.last_run_animation.clone(),
+      last_animation_finish: self
+        .last_animation_finish
+        .as_ref()
+        .map(|event_sync| self.new_event_sync(event_sync.time_since_started())),
+      animation_finished: self.animation_finished,
+      paused: self.paused,
+      animation_speed: self.animation_speed,
+    }
+  }
+}
+
+fn default_animation_speed() -> f32 {
+  1.0
+}
+
+/// Identifies the sprite an animator is displaying, without needing to compare sprites.
+///
+/// The names are borrowed from the animation list, and the same frame always refers to the same
+/// sprite for as long as that list is left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayedFrame<'a> {
+  /// No animation is being displayed, meaning the model's default sprite is used.
+  Default,
+  /// The resting appearance of the last run animation is being displayed.
+  ///
+  /// The default sprite is used if that animation has no resting appearance.
+  Resting { animation_name: &'a str },
+  /// A frame of the currently running animation is being displayed.
+  Frame {
+    animation_name: &'a str,
+    frame_index: u64,
+  },
+}
+
+impl<'a> DisplayedFrame<'a> {
+  /// Returns the name of the animation the frame belongs to, None for the default sprite.
+  pub fn animation_name(&self) -> Option<&'a str> {
+    match self {
+      Self::Default => None,
+      Self::Resting { animation_name } | Self::Frame { animation_name, .. } => Some(animation_name),
+    }
+  }
+
+  /// Returns the index of the frame within its animation, None for the default sprite and resting appearances.
+  pub fn frame_index(&self) -> Option<u64> {
+    match self {
+      Self::Frame { frame_index, .. } => Some(*frame_index),
+      _ => None,
+    }
+  }
+
+  /// Returns the sprite being displayed, None if the model's default sprite should be used.
+  pub fn get_sprite(
+    &self,
+    animation_list: &'a HashMap<String, AnimationFrames>,
+  ) -> Option<&'a Sprite> {
+    match self {
+      Self::Default => None,
+      Self::Resting { animation_name } => animation_list
+        .get(*animation_name)?
+        .get_resting_appearance(),
+      Self::Frame {
+        animation_name,
+        frame_index,
+      } => Some(
+        animation_list
+          .get(*animation_name)?
+          .get_frame(*frame_index)?
+          .get_appearance(),
+      ),
+    }
+  }
 }
 
 impl ModelAnimator {
-  /// If there's any animations running, returns
+  /// If there's any animations running, returns the sprite of the frame currently being displayed.
+  ///
+  /// Otherwise the resting appearance of the last run animation is returned, if it has one.
   pub fn get_current_model_appearance<'a>(
     &mut self,
     animation_list: &'a HashMap<String, AnimationFrames>,
   ) -> Option<&'a Sprite> {
+    self
+      .get_displayed_frame(animation_list)
+      .get_sprite(animation_list)
+  }
+
+  /// Returns which frame is currently being displayed.
+  ///
+  /// Any animations that have finished running are removed from the queue first.
+  pub fn get_displayed_frame<'a>(
+    &mut self,
+    animation_list: &'a HashMap<String, AnimationFrames>,
+  ) -> DisplayedFrame<'a> {
     self.remove_missing_animations_from_queue(animation_list);
 
     if !self.has_animations_to_run() {
-      return animation_list
-        .get(self.get_last_run_animation()?)?
-        .get_resting_appearance();
+      return self
+        .get_last_run_animation()
+        .and_then(|animation_name| animation_list.get_key_value(animation_name))
+        .map_or(DisplayedFrame::Default, |(animation_name, _)| {
+          DisplayedFrame::Resting { animation_name }
+        });
     }
 
     if let Err(error) = self.remove_finished_animations(animation_list) {
@@ -42,12 +181,110 @@ impl ModelAnimator {
       self.step_animation_queue(animation_list);
     }
 
-    let ticks_since_start_of_animation = self.get_current_animation_start()?.ticks_since_started();
+    self
+      .get_running_frame(animation_list)
+      .unwrap_or(DisplayedFrame::Default)
+  }
+
+  /// Returns the frame of the running animation that would be displayed right now.
+  fn get_running_frame<'a>(
+    &self,
+    animation_list: &'a HashMap<String, AnimationFrames>,
+  ) -> Option<DisplayedFrame<'a>> {
+    let ticks_since_start_of_animation = self.scaled_ticks_since_started()?;
+    let (animation_name, current_animation) =
+      animation_list.get_key_value(self.get_current_animation()?)?;
+    let frame_index =
+      current_animation.get_frame_index_based_on_ticks(ticks_since_start_of_animation)?;
+
+    Some(DisplayedFrame::Frame {
+      animation_name,
+      frame_index,
+    })
+  }
+
+  /// Returns the index of the frame currently being displayed in the running animation.
+  ///
+  /// None is returned if there's no animation running.
+  pub fn current_frame_index(
+    &mut self,
+    animation_list: &HashMap<String, AnimationFrames>,
+  ) -> Option<u64> {
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = self.get_current_model_appearance(animation_list);
+
+    let ticks_since_start_of_animation = self.scaled_ticks_since_started()?;
+    let current_animation = animation_list.get(self.get_current_animation()?)?;
+
+    current_animation.get_frame_index_based_on_ticks(ticks_since_start_of_animation)
+  }
+
+  /// Returns how many loops of the running animation haven't been completed yet.
+  ///
+  /// Refer to [`AnimationFrames::remaining_loops`] for the exact semantics.
+  ///
+  /// None is returned if there's no animation running, or the running animation loops forever.
+  pub fn animation_loops_remaining(
+    &mut self,
+    animation_list: &HashMap<String, AnimationFrames>,
+  ) -> Option<u64> {
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = self.get_current_model_appearance(animation_list);
 
+    let ticks_since_start_of_animation = self.scaled_ticks_since_started()?;
     let current_animation = animation_list.get(self.get_current_animation()?)?;
-    let current_frame = current_animation.get_frame_based_on_ticks(ticks_since_start_of_animation);
 
-    Some(current_frame?.get_appearance())
+    current_animation.remaining_loops(ticks_since_start_of_animation)
+  }
+
+  /// Returns the anchor offset of the frame currently being displayed in the running animation.
+  ///
+  /// The offset belongs to the current frame only, and isn't accumulated across frames.
+  ///
+  /// None is returned if there's no animation running.
+  pub fn current_anchor_offset(
+    &mut self,
+    animation_list: &HashMap<String, AnimationFrames>,
+  ) -> Option<(isize, isize)> {
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = self.get_current_model_appearance(animation_list);
+
+    let ticks_since_start_of_animation = self.scaled_ticks_since_started()?;
+    let current_animation = animation_list.get(self.get_current_animation()?)?;
+    let current_frame =
+      current_animation.get_frame_based_on_ticks(ticks_since_start_of_animation)?;
+
+    Some(current_frame.get_anchor_offset())
+  }
+
+  /// Returns how many ticks have passed since the last animation in the queue finished running.
+  ///
+  /// 0 is returned if there are animations currently running.
+  /// None is returned if no animation has finished running yet.
+  pub fn ticks_since_last_animation(&self) -> Option<u64> {
+    if self.has_animations_to_run() {
+      return Some(0);
+    }
+
+    self
+      .last_animation_finish
+      .as_ref()
+      .map(EventSync::ticks_since_started)
+  }
+
+  /// Returns true if an animation finished running since the last time this was called.
+  ///
+  /// Only animations that run out their duration count, so animations that run forever will
+  /// never cause this to return true. If multiple animations finished since the last check, true
+  /// is only returned once.
+  pub fn take_animation_finished(
+    &mut self,
+    animation_list: &HashMap<String, AnimationFrames>,
+  ) -> bool {
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = self.get_current_model_appearance(animation_list);
+
+    std::mem::take(&mut self.animation_finished)
   }
 
   /// Returns true if there are animations that can be run.
@@ -99,6 +336,10 @@ impl ModelAnimator {
       if animation_list.contains_key(&last_run_animation) {
         self.last_run_animation = Some(last_run_animation);
       }
+
+      if self.animation_queue.is_empty() {
+        self.last_animation_finish = Some(self.new_event_sync(Duration::ZERO));
+      }
     }
 
     if self.animation_queue.is_empty() {
@@ -117,17 +358,17 @@ impl ModelAnimator {
     &self,
     animation_list: &HashMap<String, AnimationFrames>,
   ) -> Option<Duration> {
-    let animation_start = self.get_current_animation_start()?;
+    let scaled_time_since_started = self.scaled_time_since_started()?;
     let current_animation = animation_list.get(self.get_current_animation()?)?;
     let duration_of_last_run_animation = current_animation.get_total_duration()?;
-    let ticks_since_started = animation_start.ticks_since_started();
+    let ticks_since_started = self.scaled_ticks_since_started()?;
 
     if ticks_since_started > duration_of_last_run_animation {
-      let remainder_time = animation_start.time_since_last_tick();
+      let remainder_time =
+        scaled_time_since_started.saturating_sub(ticks_to_duration(ticks_since_started));
 
       Some(
-        Duration::from_millis(duration_of_last_run_animation * CONFIG.tick_duration as u64)
-          + remainder_time,
+        self.unscale_duration(ticks_to_duration(duration_of_last_run_animation) + remainder_time),
       )
     } else {
       None
@@ -161,10 +402,59 @@ impl ModelAnimator {
       self.last_run_animation = Some(last_run_animation);
     }
 
+    if self.has_animations_to_run() {
+      self.last_animation_finish = Some(self.new_event_sync(Duration::ZERO));
+    }
+
     self.animation_queue.clear();
     self.current_animation_start = None;
   }
 
+  /// Moves the first instance of the animation in the queue to the given index.
+  ///
+  /// Indices past the back of the queue will move the animation to the back.
+  ///
+  /// The currently running animation is only restarted if the front of the queue changed, which
+  /// happens when the running animation is moved back, or another animation is moved to the front.
+  ///
+  /// # Errors
+  ///
+  /// - The animation wasn't in the queue.
+  pub fn move_queued_animation(
+    &mut self,
+    animation_list: &HashMap<String, AnimationFrames>,
+    animation_name: &str,
+    to_index: usize,
+  ) -> Result<(), AnimationError> {
+    // Updating the current appearance will drop every animation that has finished running.
+    let _ = self.get_current_model_appearance(animation_list);
+
+    let Some(from_index) = self
+      .animation_queue
+      .iter()
+      .position(|queued_animation| queued_animation == animation_name)
+    else {
+      return Err(AnimationError::AnimationNotQueued {
+        animation_name: animation_name.to_string(),
+      });
+    };
+    let to_index = to_index.min(self.animation_queue.len() - 1);
+
+    if from_index == to_index {
+      return Ok(());
+    }
+
+    if let Some(animation) = self.animation_queue.remove(from_index) {
+      self.animation_queue.insert(to_index, animation);
+    }
+
+    if from_index == 0 || to_index == 0 {
+      self.restart_animation_start();
+    }
+
+    Ok(())
+  }
+
   /// Assigns the passed in new animation if there's none currently running.
   /// Otherwise adds the new animation to the back of the queue.
   pub fn add_new_animation_to_queue(&mut self, new_animation: String) {
@@ -180,7 +470,7 @@ impl ModelAnimator {
   /// If there are no animations currently running, assigns current_animation_start to None.
   fn restart_animation_start(&mut self) {
     self.current_animation_start = if self.has_animations_to_run() {
-      Some(EventSync::new(CONFIG.tick_duration))
+      Some(self.new_event_sync(Duration::ZERO))
     } else {
       None
     }
@@ -188,11 +478,7 @@ impl ModelAnimator {
 
   fn restart_animation_start_with_remaining_time(&mut self, remaining_time: Duration) {
     self.current_animation_start = if self.has_animations_to_run() {
-      Some(EventSync::from_starting_time(
-        CONFIG.tick_duration,
-        remaining_time,
-        false,
-      ))
+      Some(self.new_event_sync(remaining_time))
     } else {
       None
     }
@@ -208,10 +494,10 @@ impl ModelAnimator {
       return Ok(());
     }
 
-    let mut remaining_duration = self
-      .get_current_animation_start()
-      .ok_or(anyhow!("No animation start."))?
-      .ticks_since_started();
+    let scaled_time_since_started = self
+      .scaled_time_since_started()
+      .ok_or(anyhow!("No animation start."))?;
+    let mut remaining_duration = duration_to_ticks(scaled_time_since_started);
 
     self
       .animation_queue
@@ -238,17 +524,21 @@ impl ModelAnimator {
         }
       });
 
-    let remainder_time = self
-      .get_current_animation_start()
-      .ok_or(anyhow!("No animation start."))?
-      .time_since_last_tick();
+    let remainder_time = scaled_time_since_started.saturating_sub(ticks_to_duration(
+      duration_to_ticks(scaled_time_since_started),
+    ));
     let remaining_duration =
-      Duration::from_millis(remaining_duration * CONFIG.tick_duration as u64) + remainder_time;
+      self.unscale_duration(ticks_to_duration(remaining_duration) + remainder_time);
 
     self.restart_animation_start_with_remaining_time(remaining_duration);
 
+    if !self.has_animations_to_run() {
+      self.last_animation_finish = Some(self.new_event_sync(remaining_duration));
+    }
+
     if last_run_animation.is_some() {
       self.last_run_animation = last_run_animation;
+      self.animation_finished = true;
     }
 
     Ok(())
@@ -270,14 +560,102 @@ impl ModelAnimator {
       return Ok(false); // Infinite animation duration.
     };
 
-    let current_animation_start = self
-      .get_current_animation_start()
+    let ticks_since_animation_start = self
+      .scaled_ticks_since_started()
       .ok_or(anyhow!("No animation start."))?;
-    let ticks_since_animation_start = current_animation_start.ticks_since_started();
 
     Ok(ticks_since_animation_start >= animation_duration)
   }
 
+  /// Freezes the running animation in place until [`resume`](ModelAnimator::resume) is called.
+  ///
+  /// Animations started while paused also stay frozen until resumed.
+  pub fn pause(&mut self) {
+    self.paused = true;
+
+    self
+      .current_animation_start
+      .iter_mut()
+      .chain(self.last_animation_finish.iter_mut())
+      .for_each(EventSync::pause);
+  }
+
+  /// Continues running animations from where they were paused.
+  pub fn resume(&mut self) {
+    self.paused = false;
+
+    self
+      .current_animation_start
+      .iter_mut()
+      .chain(self.last_animation_finish.iter_mut())
+      .for_each(|event_sync| {
+        if let Err(error) = event_sync.unpause() {
+          log::error!("Failed to resume an animation: {error:?}");
+        }
+      });
+  }
+
+  /// Returns true if the animator is paused.
+  pub fn is_paused(&self) -> bool {
+    self.paused
+  }
+
+  /// Changes how fast animations are played, a multiplier of 2.0 plays animations twice as fast.
+  ///
+  /// The multiplier is clamped between [`MINIMUM_ANIMATION_SPEED`](MINIMUM_ANIMATION_SPEED) and
+  /// [`MAXIMUM_ANIMATION_SPEED`](MAXIMUM_ANIMATION_SPEED).
+  /// Multipliers that are NaN or infinite are ignored, leaving the speed unchanged.
+  ///
+  /// The stored frame durations are left untouched, the multiplier only changes how many ticks an
+  /// animation has been running for. The running animation continues from the frame it was on.
+  ///
+  /// Animations with a `Limited` loop count still play the same amount of loops, so a multiplier of
+  /// 2.0 makes them finish in half the time.
+  pub fn set_animation_speed(&mut self, multiplier: f32) {
+    if !multiplier.is_finite() {
+      log::warn!("Ignored an attempt to set the animation speed to {multiplier}.");
+
+      return;
+    }
+
+    let scaled_time_since_started = self.scaled_time_since_started();
+
+    self.animation_speed = multiplier.clamp(MINIMUM_ANIMATION_SPEED, MAXIMUM_ANIMATION_SPEED);
+
+    if let Some(scaled_time_since_started) = scaled_time_since_started {
+      let elapsed_time = self.unscale_duration(scaled_time_since_started);
+
+      self.current_animation_start = Some(self.new_event_sync(elapsed_time));
+    }
+  }
+
+  /// Returns the multiplier for how fast animations are played.
+  pub fn get_animation_speed(&self) -> f32 {
+    self.animation_speed
+  }
+
+  /// Returns how long the current animation has been running for, scaled by the animation speed.
+  fn scaled_time_since_started(&self) -> Option<Duration> {
+    let time_since_started = self.get_current_animation_start()?.time_since_started();
+
+    Some(time_since_started.mul_f64(self.animation_speed as f64))
+  }
+
+  /// Returns how many ticks the current animation has been running for, scaled by the animation speed.
+  fn scaled_ticks_since_started(&self) -> Option<u64> {
+    self.scaled_time_since_started().map(duration_to_ticks)
+  }
+
+  /// Converts a duration scaled by the animation speed back to real time.
+  fn unscale_duration(&self, scaled_duration: Duration) -> Duration {
+    scaled_duration.div_f64(self.animation_speed as f64)
+  }
+
+  /// Creates an EventSync that's already had the given time pass, and is paused if the animator is.
+  fn new_event_sync(&self, elapsed_time: Duration) -> EventSync {
+    EventSync::from_starting_time(CONFIG.tick_duration, elapsed_time, self.paused)
+  }
+
   pub fn clear_all_data(&mut self) {
     std::mem::take(self);
   }
@@ -287,6 +665,14 @@ impl ModelAnimator {
   }
 }
 
+fn ticks_to_duration(ticks: u64) -> Duration {
+  Duration::from_millis(ticks * CONFIG.tick_duration as u64)
+}
+
+fn duration_to_ticks(duration: Duration) -> u64 {
+  (duration.as_millis() / CONFIG.tick_duration as u128) as u64
+}
+
 impl std::fmt::Debug for ModelAnimator {
   fn fmt(
     &self,
@@ -296,12 +682,18 @@ impl std::fmt::Debug for ModelAnimator {
       .current_animation_start
       .as_ref()
       .map(EventSync::time_since_started);
+    let last_animation_finish = self
+      .last_animation_finish
+      .as_ref()
+      .map(EventSync::time_since_started);
 
     formatter
       .debug_struct("ModelAnimator")
       .field("animation_queue", &self.animation_queue)
       .field("time_since_start_of_animation", &animation_start)
       .field("last_run_animation", &self.last_run_animation)
+      .field("animation_speed", &self.animation_speed)
+      .field("time_since_last_animation_finish", &last_animation_finish)
       .finish()
   }
 }
@@ -669,6 +1061,334 @@ mod tests {
     assert!(model_animator.animation_queue.is_empty());
   }
 
+  #[cfg(test)]
+  mod ticks_since_last_animation_logic {
+    use super::*;
+
+    #[test]
+    fn no_animation_has_run() {
+      let model_animator = ModelAnimator::default();
+
+      assert!(model_animator.ticks_since_last_animation().is_none());
+    }
+
+    #[test]
+    fn animation_is_running() {
+      let animation_list = get_test_animation_list();
+      let animation_name = animation_list.keys().next().unwrap().to_owned();
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.add_new_animation_to_queue(animation_name);
+
+      assert_eq!(model_animator.ticks_since_last_animation(), Some(0));
+    }
+
+    #[test]
+    fn animation_was_stepped_out_of_queue() {
+      let animation_list = get_test_animation_list();
+      let animation_name = animation_list.keys().next().unwrap().to_owned();
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.add_new_animation_to_queue(animation_name);
+      model_animator.step_animation_queue(&animation_list);
+
+      let ticks_before = model_animator.ticks_since_last_animation().unwrap();
+      model_animator
+        .last_animation_finish
+        .as_ref()
+        .unwrap()
+        .wait_for_x_ticks(2)
+        .unwrap();
+      let ticks_after = model_animator.ticks_since_last_animation().unwrap();
+
+      assert_eq!(ticks_before, 0);
+      assert_eq!(ticks_after, 2);
+    }
+  }
+
+  #[cfg(test)]
+  mod animation_loops_remaining_logic {
+    use super::*;
+
+    #[test]
+    fn no_animation_running() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      assert!(model_animator
+        .animation_loops_remaining(&animation_list)
+        .is_none());
+    }
+
+    #[test]
+    fn limited_animation_running() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+
+      let result = model_animator.animation_loops_remaining(&animation_list);
+
+      assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn unlimited_animation_running() {
+      let mut animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      animation_list.insert(
+        "Forever".to_string(),
+        TestingData::get_test_animation(['x', 'y', 'z'], AnimationLoopCount::Forever),
+      );
+
+      model_animator.add_new_animation_to_queue("Forever".to_string());
+
+      assert!(model_animator
+        .animation_loops_remaining(&animation_list)
+        .is_none());
+    }
+  }
+
+  #[cfg(test)]
+  mod current_frame_index_logic {
+    use super::*;
+
+    #[test]
+    fn no_animation_running() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      assert!(model_animator
+        .current_frame_index(&animation_list)
+        .is_none());
+    }
+
+    #[test]
+    fn index_advances_each_tick() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+
+      let expected_indices = vec![Some(0), Some(1), Some(2)];
+
+      let indices: Vec<Option<u64>> = (0..3)
+        .map(|_| {
+          let index = model_animator.current_frame_index(&animation_list);
+
+          model_animator
+            .current_animation_start
+            .as_ref()
+            .unwrap()
+            .wait_for_tick()
+            .unwrap();
+
+          index
+        })
+        .collect();
+
+      assert_eq!(indices, expected_indices);
+      // The animation is finished after 3 ticks.
+      assert!(model_animator
+        .current_frame_index(&animation_list)
+        .is_none());
+    }
+  }
+
+  #[cfg(test)]
+  mod pause_logic {
+    use super::*;
+
+    #[test]
+    fn paused_animation_does_not_advance() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      let tick_counter = EventSync::new(CONFIG.tick_duration);
+
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      model_animator.pause();
+      tick_counter.wait_for_x_ticks(2).unwrap();
+
+      assert!(model_animator.is_paused());
+      assert_eq!(model_animator.current_frame_index(&animation_list), Some(0));
+
+      model_animator.resume();
+      model_animator
+        .current_animation_start
+        .as_ref()
+        .unwrap()
+        .wait_for_tick()
+        .unwrap();
+
+      assert!(!model_animator.is_paused());
+      assert_eq!(model_animator.current_frame_index(&animation_list), Some(1));
+    }
+
+    #[test]
+    fn animation_queued_while_paused_is_frozen() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      let tick_counter = EventSync::new(CONFIG.tick_duration);
+
+      model_animator.pause();
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      tick_counter.wait_for_x_ticks(2).unwrap();
+
+      assert_eq!(model_animator.current_frame_index(&animation_list), Some(0));
+    }
+  }
+
+  #[cfg(test)]
+  mod animation_speed_logic {
+    use super::*;
+
+    #[test]
+    fn doubled_speed_skips_frames() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      model_animator.set_animation_speed(2.0);
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      model_animator
+        .current_animation_start
+        .as_ref()
+        .unwrap()
+        .wait_for_tick()
+        .unwrap();
+
+      assert_eq!(model_animator.current_frame_index(&animation_list), Some(2));
+    }
+
+    #[test]
+    fn speed_is_clamped() {
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.set_animation_speed(0.0);
+
+      assert_eq!(
+        model_animator.get_animation_speed(),
+        MINIMUM_ANIMATION_SPEED
+      );
+    }
+
+    #[test]
+    fn speed_is_clamped_to_maximum() {
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.set_animation_speed(f32::MAX);
+
+      assert_eq!(
+        model_animator.get_animation_speed(),
+        MAXIMUM_ANIMATION_SPEED
+      );
+    }
+
+    #[test]
+    fn non_finite_speed_is_ignored() {
+      let mut model_animator = ModelAnimator::default();
+      model_animator.set_animation_speed(2.0);
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+
+      model_animator.set_animation_speed(f32::NAN);
+      model_animator.set_animation_speed(f32::INFINITY);
+
+      assert_eq!(model_animator.get_animation_speed(), 2.0);
+    }
+
+    #[test]
+    fn changing_speed_keeps_current_frame() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      model_animator
+        .current_animation_start
+        .as_ref()
+        .unwrap()
+        .wait_for_tick()
+        .unwrap();
+      model_animator.set_animation_speed(MINIMUM_ANIMATION_SPEED);
+
+      assert_eq!(model_animator.current_frame_index(&animation_list), Some(1));
+    }
+  }
+
+  #[cfg(test)]
+  mod move_queued_animation_logic {
+    use super::*;
+
+    #[test]
+    fn second_animation_moved_to_front() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      model_animator.add_new_animation_to_queue("TestTwo".to_string());
+      model_animator.add_new_animation_to_queue("TestThree".to_string());
+
+      let expected_queue = VecDeque::from([
+        "TestTwo".to_string(),
+        "TestOne".to_string(),
+        "TestThree".to_string(),
+      ]);
+
+      model_animator
+        .move_queued_animation(&animation_list, "TestTwo", 0)
+        .unwrap();
+
+      assert_eq!(model_animator.animation_queue, expected_queue);
+      assert!(model_animator.current_animation_start.is_some());
+    }
+
+    #[test]
+    fn running_animation_is_undisturbed() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+      model_animator.add_new_animation_to_queue("TestOne".to_string());
+      model_animator.add_new_animation_to_queue("TestTwo".to_string());
+      model_animator.add_new_animation_to_queue("TestThree".to_string());
+      model_animator
+        .current_animation_start
+        .as_ref()
+        .unwrap()
+        .wait_for_tick()
+        .unwrap();
+
+      let expected_queue = VecDeque::from([
+        "TestOne".to_string(),
+        "TestThree".to_string(),
+        "TestTwo".to_string(),
+      ]);
+
+      // Indices past the back of the queue move the animation to the back.
+      model_animator
+        .move_queued_animation(&animation_list, "TestTwo", 10)
+        .unwrap();
+      let ticks_since_start = model_animator
+        .current_animation_start
+        .as_ref()
+        .unwrap()
+        .ticks_since_started();
+
+      assert_eq!(model_animator.animation_queue, expected_queue);
+      assert_eq!(ticks_since_start, 1);
+    }
+
+    #[test]
+    fn animation_not_queued() {
+      let animation_list = get_test_animation_list();
+      let mut model_animator = ModelAnimator::default();
+
+      let expected_error = AnimationError::AnimationNotQueued {
+        animation_name: "TestOne".to_string(),
+      };
+
+      let result = model_animator
+        .move_queued_animation(&animation_list, "TestOne", 0)
+        .unwrap_err();
+
+      assert_eq!(result, expected_error);
+    }
+  }
+
   #[test]
   fn start_time_is_resetting() {
     let animation_list = get_test_animation_list();
@@ -17,7 +17,9 @@ pub enum ModelError {
   #[error("A model's sprite was found to be a non-rectangular shape.")]
   NonRectangularShape,
 
-  /// A model's [`Strata`](crate::models::strata::Strata) wasn't within 0-100.
+  /// A model's [`Strata`](crate::models::strata::Strata) was outside of the accepted range.
+  ///
+  /// This is currently never returned, as every strata is accepted.
   #[error("A model was found to contain an invalid strata: {:?}", .0)]
   IncorrectStrataRange(Strata),
 
@@ -58,6 +60,25 @@ pub enum ModelError {
   #[error("Model has been place of of bounds of the screen.")]
   ModelOutOfBounds,
 
+  /// Attempted to insert a model into a world that already contains the maximum amount of models.
+  #[error("Attempted to insert a model into a world that's already at its model limit.")]
+  ModelLimitReached,
+
+  /// Attempted to attach a model to itself, or to one of its own children.
+  #[error("Attempted to attach a model to itself or one of its children.")]
+  CircularAttachment,
+
+  /// Attempted to replace a model's sprite with one that has its anchor in a different place,
+  /// which would move the model in the world.
+  #[error(
+    "Attempted to replace a model's sprite with one that has its anchor in a different position."
+  )]
+  SpriteAnchorMoved,
+
+  /// Attempted to crop a region that extends past the edges of a sprite.
+  #[error("Attempted to crop a region outside of a sprite's dimensions.")]
+  SpriteCropOutOfBounds,
+
   /// A stored list of errors returned when checking if a sprite has any issues with it's data.
   #[error("A sprite was found to be invalid. Reason(s): {:?}", .0)]
   SpriteValidityChecks(Vec<Self>),
@@ -73,6 +94,88 @@ pub enum ModelError {
   Other(String),
 }
 
+impl ModelError {
+  /// The exit code for errors that don't fit into any other category.
+  pub const GENERAL_EXIT_CODE: i32 = 1;
+  /// The exit code for when a model or animation file contained invalid data.
+  pub const PARSE_ERROR_EXIT_CODE: i32 = 2;
+  /// The exit code for when a model file couldn't be found.
+  pub const FILE_MISSING_EXIT_CODE: i32 = 3;
+  /// The exit code for when a model's sprite, hitbox, or animations failed a validity check.
+  pub const VALIDITY_FAILURE_EXIT_CODE: i32 = 4;
+  /// The exit code for when something was placed outside of the bounds it's allowed in.
+  pub const OUT_OF_BOUNDS_EXIT_CODE: i32 = 5;
+
+  /// Returns a stable exit code for the category of this error.
+  ///
+  /// This is intended for command line tools built on top of this crate.
+  /// The categories are:
+  ///
+  /// - [`PARSE_ERROR_EXIT_CODE`](ModelError::PARSE_ERROR_EXIT_CODE) for invalid model and animation files.
+  /// - [`FILE_MISSING_EXIT_CODE`](ModelError::FILE_MISSING_EXIT_CODE) for model files that couldn't be found.
+  /// - [`VALIDITY_FAILURE_EXIT_CODE`](ModelError::VALIDITY_FAILURE_EXIT_CODE) for invalid sprites, hitboxes, and animations.
+  /// - [`OUT_OF_BOUNDS_EXIT_CODE`](ModelError::OUT_OF_BOUNDS_EXIT_CODE) for models and regions out of bounds.
+  /// - [`GENERAL_EXIT_CODE`](ModelError::GENERAL_EXIT_CODE) for everything else.
+  pub fn exit_code(&self) -> i32 {
+    match self {
+      ModelError::ModelCreationError(
+        ModelCreationError::ModelFileDoesntExist(_)
+        | ModelCreationError::CouldntReadAppearanceFile(_),
+      ) => Self::FILE_MISSING_EXIT_CODE,
+      ModelError::ModelCreationError(_)
+      | ModelError::AnimationError(AnimationError::AnimationParserError(_)) => {
+        Self::PARSE_ERROR_EXIT_CODE
+      }
+      ModelError::NoAnchor
+      | ModelError::NonRectangularShape
+      | ModelError::IncorrectStrataRange(_)
+      | ModelError::MultipleAnchorsFound(_)
+      | ModelError::SpriteAnchorMatchesAirCharacter
+      | ModelError::ModelSpriteContainsNewAnchorCharacter
+      | ModelError::IndexLargerThanHitboxArea
+      | ModelError::SpriteAnchorMoved
+      | ModelError::SpriteValidityChecks(_)
+      | ModelError::MissingCrutialFieldsInStoredDisplayModel
+      | ModelError::AnimationError(AnimationError::AnimationValidityCheckFailed(_)) => {
+        Self::VALIDITY_FAILURE_EXIT_CODE
+      }
+      ModelError::ModelOutOfBounds | ModelError::SpriteCropOutOfBounds => {
+        Self::OUT_OF_BOUNDS_EXIT_CODE
+      }
+      ModelError::ModelAlreadyExists
+      | ModelError::ModelDoesntExist
+      | ModelError::ModelLimitReached
+      | ModelError::CircularAttachment
+      | ModelError::AnimationError(_)
+      | ModelError::Other(_) => Self::GENERAL_EXIT_CODE,
+    }
+  }
+
+  /// Returns a human readable, single line description of the error.
+  ///
+  /// Unlike the Debug output, this doesn't contain the names of any internal types.
+  pub fn user_message(&self) -> String {
+    match self {
+      ModelError::IncorrectStrataRange(strata) => {
+        format!("A model contained an invalid strata of {}.", strata.0)
+      }
+      ModelError::MultipleAnchorsFound(anchor_indices) => format!(
+        "A model's sprite contained {} anchors, but only one is allowed.",
+        anchor_indices.len()
+      ),
+      ModelError::ModelCreationError(creation_error) => creation_error.user_message(),
+      ModelError::AnimationError(animation_error) => animation_error.user_message(),
+      ModelError::SpriteValidityChecks(errors) => {
+        let reasons: Vec<String> = errors.iter().map(Self::user_message).collect();
+
+        format!("A sprite was found to be invalid. {}", reasons.join(" "))
+      }
+      ModelError::Other(reason) => reason.to_owned(),
+      _ => self.to_string(),
+    }
+  }
+}
+
 /// This is the list of possible errors that could happen when parsing a model file.
 #[derive(Error, Debug, PartialEq, Eq, Clone)]
 // This error won't be implemented for this as model files are going to be replaced in the near future.
@@ -87,7 +190,9 @@ pub enum ModelCreationError {
   /// A Model has a strata range that's impossible.
   ///
   /// Returns the given strata range.
-  InvalidStrataRange(usize),
+  ///
+  /// This is currently never returned, as every strata is accepted.
+  InvalidStrataRange(i32),
 
   /// When parsing the appearance of the model, it was found to be non-rectangular.
   InvalidSkinShape,
@@ -95,6 +200,19 @@ pub enum ModelCreationError {
   /// When parsing the hitbox of the model, it was found to be non-rectangular.
   InvalidHitboxShape,
 
+  /// The anchor was found in one of the Appearance and Hitbox_Dimensions sections, but not the other.
+  ///
+  /// Contains the name of the section that was missing the anchor.
+  AnchorMissingInSection(String),
+
+  /// The model file had both an ``Appearance`` section and an ``appearance_path``.
+  AppearanceDefinedTwice,
+
+  /// The file given by ``appearance_path`` couldn't be read.
+  ///
+  /// Contains the path that was given.
+  CouldntReadAppearanceFile(OsString),
+
   /// One or more fields of data were missing from the model file.
   ///
   /// Contains a list of everything that was missing.
@@ -111,8 +229,177 @@ pub enum ModelCreationError {
   /// The model file exists, but has no content inside of it.
   ModelFileIsEmpty,
 }
+
+impl ModelCreationError {
+  /// Returns a human readable, single line description of the error.
+  pub fn user_message(&self) -> String {
+    match self {
+      ModelCreationError::InvalidSyntax(line) => {
+        format!("The model file contained invalid syntax on line {line}.")
+      }
+      ModelCreationError::InvalidStringSizeAtLine(line) => {
+        format!("Expected a single character on line {line} of the model file.")
+      }
+      ModelCreationError::InvalidStrataRange(strata) => {
+        format!("The model file contained an invalid strata of {strata}.")
+      }
+      ModelCreationError::InvalidSkinShape => {
+        "The appearance in the model file isn't rectangular.".to_string()
+      }
+      ModelCreationError::InvalidHitboxShape => {
+        "The hitbox in the model file isn't rectangular.".to_string()
+      }
+      ModelCreationError::AnchorMissingInSection(section_name) => {
+        format!("The {section_name} section of the model file is missing the anchor.")
+      }
+      ModelCreationError::AppearanceDefinedTwice => {
+        "The model file has both an Appearance section and an appearance_path.".to_string()
+      }
+      ModelCreationError::CouldntReadAppearanceFile(path) => {
+        format!("The appearance file {:?} couldn't be read.", path)
+      }
+      ModelCreationError::MissingData(missing_fields) => format!(
+        "The model file is missing the fields: {}.",
+        missing_fields.join(", ")
+      ),
+      ModelCreationError::NonModelFile => "The file given isn't a model file.".to_string(),
+      ModelCreationError::ModelFileDoesntExist(Some(path)) => {
+        format!("The model file {:?} doesn't exist.", path)
+      }
+      ModelCreationError::ModelFileDoesntExist(None) => "The model file doesn't exist.".to_string(),
+      ModelCreationError::ModelFileIsEmpty => "The model file is empty.".to_string(),
+    }
+  }
+}
+
 impl std::fmt::Display for ModelCreationError {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
     write!(f, "{:?}", self)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(test)]
+  mod source_logic {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn animation_error_is_the_source() {
+      let animation_error = AnimationError::NoAnimationHasFinished;
+      let model_error = ModelError::from(animation_error.clone());
+
+      let source = model_error.source().unwrap();
+
+      assert_eq!(source.to_string(), animation_error.to_string());
+    }
+
+    #[test]
+    fn model_creation_error_is_the_source() {
+      let creation_error = ModelCreationError::ModelFileIsEmpty;
+      let model_error = ModelError::from(creation_error.clone());
+
+      let source = model_error.source().unwrap();
+
+      assert_eq!(source.to_string(), creation_error.to_string());
+    }
+
+    #[test]
+    fn animation_parser_error_is_the_source() {
+      let parser_error = AnimationParserError::InvalidSyntax(1);
+      let animation_error = AnimationError::from(parser_error.clone());
+
+      let source = animation_error.source().unwrap();
+
+      assert_eq!(source.to_string(), parser_error.to_string());
+    }
+
+    #[test]
+    fn errors_without_a_wrapped_error_have_no_source() {
+      assert!(ModelError::ModelDoesntExist.source().is_none());
+      assert!(ModelCreationError::ModelFileIsEmpty.source().is_none());
+    }
+  }
+
+  #[cfg(test)]
+  mod exit_code_logic {
+    use super::*;
+
+    #[test]
+    fn categories_have_distinct_codes() {
+      let errors = [
+        ModelError::ModelCreationError(ModelCreationError::InvalidSyntax(3)),
+        ModelError::ModelCreationError(ModelCreationError::ModelFileDoesntExist(None)),
+        ModelError::NoAnchor,
+        ModelError::ModelOutOfBounds,
+        ModelError::ModelDoesntExist,
+      ];
+      let expected_codes = [
+        ModelError::PARSE_ERROR_EXIT_CODE,
+        ModelError::FILE_MISSING_EXIT_CODE,
+        ModelError::VALIDITY_FAILURE_EXIT_CODE,
+        ModelError::OUT_OF_BOUNDS_EXIT_CODE,
+        ModelError::GENERAL_EXIT_CODE,
+      ];
+
+      let exit_codes: Vec<i32> = errors.iter().map(ModelError::exit_code).collect();
+
+      assert_eq!(exit_codes, expected_codes);
+    }
+
+    #[test]
+    fn nested_validity_checks() {
+      let error = ModelError::SpriteValidityChecks(vec![ModelError::NoAnchor]);
+
+      assert_eq!(error.exit_code(), ModelError::VALIDITY_FAILURE_EXIT_CODE);
+    }
+  }
+
+  #[cfg(test)]
+  mod user_message_logic {
+    use super::*;
+
+    #[test]
+    fn creation_error_message() {
+      let error = ModelError::ModelCreationError(ModelCreationError::MissingData(vec![
+        "Anchor".to_string(),
+        "Strata".to_string(),
+      ]));
+
+      let message = error.user_message();
+
+      assert_eq!(
+        message,
+        "The model file is missing the fields: Anchor, Strata."
+      );
+    }
+
+    #[test]
+    fn animation_error_message() {
+      let error = ModelError::AnimationError(AnimationError::AnimationNotQueued {
+        animation_name: "walk".to_string(),
+      });
+
+      let message = error.user_message();
+
+      assert_eq!(message, "The animation \"walk\" isn't queued.");
+    }
+
+    #[test]
+    fn validity_checks_are_joined() {
+      let error = ModelError::SpriteValidityChecks(vec![
+        ModelError::NoAnchor,
+        ModelError::NonRectangularShape,
+      ]);
+      let expected_message = "A sprite was found to be invalid. A model's sprite was missing an anchor point for world and hitbox placement. A model's sprite was found to be a non-rectangular shape.";
+
+      let message = error.user_message();
+
+      assert_eq!(message, expected_message);
+      assert!(!message.contains('\n'));
+    }
+  }
+}
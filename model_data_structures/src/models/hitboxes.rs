@@ -1,5 +1,9 @@
 use crate::errors::*;
-use engine_math::{prelude::usizeMethods, rectangle::*};
+use crate::models::model_appearance::sprites::Sprite;
+use engine_math::{
+  prelude::{usizeMethods, CoordinateMethods},
+  rectangle::*,
+};
 use serde::{Deserialize, Serialize};
 
 /// The hitbox will be how objects know the space they take up in the world.
@@ -11,18 +15,20 @@ pub struct Hitbox {
 }
 
 impl Hitbox {
-  /// Creates a new hitbox from the passed in data and anchor to the skin.
+  /// Creates a new hitbox from the passed in dimensions and anchor index.
   ///
-  /// NOTE
-  /// "skin_anchor_coordinates" is the internal coordinates of the anchor within the model's current appearance.
+  /// The anchor index is the index of the hitbox's anchor within its dimensions, counting left to
+  /// right, top to bottom.
   ///
-  /// That would mean if you had a skin like such:
+  /// That would mean if you had a hitbox like such:
   /// ```no_run,bash,ignore
   /// xxx
   /// xax
   /// xxx
   /// ```
-  /// you would pass in (1, 1).
+  /// you would pass in 3x3 dimensions and an index of 4.
+  ///
+  /// The anchor index isn't checked, use [`from_dimensions`](Hitbox::from_dimensions) for that.
   pub fn new(dimensions: Rectangle, hitbox_anchor_index: usize) -> Self {
     Self {
       hitbox_anchor_index,
@@ -31,6 +37,54 @@ impl Hitbox {
     }
   }
 
+  /// Creates a new hitbox with the given width and height, without building a shape to parse.
+  ///
+  /// The anchor index is the index of the hitbox's anchor within its dimensions, counting left to
+  /// right, top to bottom.
+  ///
+  /// A width or height of 0 creates an empty hitbox, which only accepts an anchor index of 0.
+  ///
+  /// # Errors
+  ///
+  /// - The anchor index is outside of the hitbox's area.
+  pub fn from_dimensions(
+    width: usize,
+    height: usize,
+    anchor_index: usize,
+  ) -> Result<Self, ModelError> {
+    let dimensions = Rectangle::new(width, height);
+
+    if !Rectangle::index_is_valid(&dimensions, anchor_index) {
+      return Err(ModelError::IndexLargerThanHitboxArea);
+    }
+
+    Ok(Self::new(dimensions, anchor_index))
+  }
+
+  /// Creates a new hitbox from a shape, where the anchor character marks the hitbox's anchor.
+  ///
+  /// The hitbox will take up the dimensions of the shape. Every other character in the shape is ignored.
+  ///
+  /// An empty shape creates an empty hitbox, which never collides with anything.
+  ///
+  /// # Errors
+  ///
+  /// - The shape isn't rectangular.
+  /// - The shape doesn't have an anchor.
+  /// - The shape has multiple anchors.
+  pub fn from_shape(shape: &str, anchor_character: char) -> Result<Self, ModelError> {
+    if shape.is_empty() {
+      return Ok(Self::new(Rectangle::default(), 0));
+    }
+
+    let Some(dimensions) = Rectangle::get_string_dimensions(shape) else {
+      return Err(ModelError::NonRectangularShape);
+    };
+    let anchor_index = Sprite::calculate_anchor_index(shape, anchor_character)?;
+
+    Ok(Self::new(dimensions, anchor_index))
+  }
+
   // TODO: List the errors.
   pub fn assign_anchor_index(&mut self, new_index: usize) -> Result<(), ModelError> {
     if !Rectangle::index_is_valid(&self.dimensions, new_index) {
@@ -66,6 +120,85 @@ impl Hitbox {
       .hitbox_anchor_index
       .index_to_coordinates(self.dimensions.x)
   }
+
+  /// Returns the smallest hitbox that encloses both self and the other hitbox.
+  ///
+  /// `other_offset` is the position of the other hitbox's top left relative to self's top left.
+  /// The anchor of the combined hitbox is placed at its top left.
+  ///
+  /// Empty hitboxes are ignored, meaning combining with an empty hitbox returns a copy of the other
+  /// one with its anchor moved to the top left.
+  pub fn combined(&self, other: &Hitbox, other_offset: (isize, isize)) -> Hitbox {
+    if other.is_empty() {
+      return Hitbox::new(self.dimensions, 0);
+    } else if self.is_empty() {
+      return Hitbox::new(other.dimensions, 0);
+    }
+
+    let left = 0.min(other_offset.0);
+    let top = 0.min(other_offset.1);
+    let right = (self.dimensions.x as isize).max(other_offset.0 + other.dimensions.x as isize);
+    let bottom = (self.dimensions.y as isize).max(other_offset.1 + other.dimensions.y as isize);
+
+    let combined_dimensions = Rectangle::new((right - left) as usize, (bottom - top) as usize);
+
+    Hitbox::new(combined_dimensions, 0)
+  }
+
+  /// Scales the width and height of the hitbox by the given factors.
+  ///
+  /// Each side is rounded to the nearest cell, and can't become smaller than 1.
+  /// The anchor is moved to the cell matching its old relative position within the hitbox,
+  /// meaning the hitbox grows and shrinks around the anchor instead of from its top left.
+  ///
+  /// Empty hitboxes stay empty.
+  pub fn scale(&mut self, width_factor: f32, height_factor: f32) {
+    if self.is_empty() {
+      return;
+    }
+
+    let (anchor_x, anchor_y) = self.get_anchor_as_coordinates();
+    let new_width = Self::scale_side(self.dimensions.x, width_factor);
+    let new_height = Self::scale_side(self.dimensions.y, height_factor);
+    let new_anchor = (
+      Self::scale_anchor_coordinate(anchor_x, width_factor, new_width),
+      Self::scale_anchor_coordinate(anchor_y, height_factor, new_height),
+    );
+
+    self.dimensions = Rectangle::new(new_width, new_height);
+    self.hitbox_anchor_index = new_anchor.coordinates_to_index(new_width);
+  }
+
+  /// Returns the length of a side after scaling, rounded to the nearest cell with a minimum of 1.
+  fn scale_side(side: usize, factor: f32) -> usize {
+    (side as f32 * factor).round().max(1.0) as usize
+  }
+
+  /// Maps the center of the anchor's cell into the scaled side, keeping it within the new length.
+  fn scale_anchor_coordinate(coordinate: usize, factor: f32, new_side: usize) -> usize {
+    let scaled_coordinate = ((coordinate as f32 + 0.5) * factor - 0.5).round().max(0.0) as usize;
+
+    scaled_coordinate.min(new_side - 1)
+  }
+
+  /// Returns true if the area of the hitbox is 0.
+  pub fn is_empty(&self) -> bool {
+    self.empty_hitbox
+  }
+}
+
+/// Everything that decides where a model's hitbox is placed in the world.
+///
+/// Created through [`ModelData::hitbox_placement`](crate::models::model_data::ModelData::hitbox_placement).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct HitboxPlacement {
+  /// The top left of the model's sprite in the frame.
+  pub frame_position: usize,
+  pub hitbox: Hitbox,
+  /// The offset assigned through [`set_hitbox_anchor_offset`](crate::models::model_data::ModelData::set_hitbox_anchor_offset).
+  pub hitbox_anchor_offset: (isize, isize),
+  /// The anchor of the sprite currently displayed, which changes with animation frames and sprite swaps.
+  pub sprite_anchor: (usize, usize),
 }
 
 #[cfg(test)]
@@ -92,4 +225,163 @@ mod tests {
 
     hitbox.assign_anchor_index(100).unwrap();
   }
+
+  #[cfg(test)]
+  mod from_dimensions_logic {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+      let hitbox = Hitbox::from_dimensions(5, 3, 7).unwrap();
+
+      assert_eq!(hitbox, Hitbox::new(Rectangle::new(5, 3), 7));
+      assert_eq!(hitbox.get_anchor_as_coordinates(), (2, 1));
+    }
+
+    #[test]
+    fn anchor_outside_of_area() {
+      let result = Hitbox::from_dimensions(5, 3, 15);
+
+      assert_eq!(result, Err(ModelError::IndexLargerThanHitboxArea));
+    }
+  }
+
+  #[cfg(test)]
+  mod combined_logic {
+    use super::*;
+
+    #[test]
+    fn separated_hitboxes() {
+      let hitbox_one = Hitbox::new(Rectangle::new(3, 2), 4);
+      let hitbox_two = Hitbox::new(Rectangle::new(2, 4), 1);
+      // Places the second hitbox 5 spaces right and 3 spaces below the first.
+      let offset = (5, 3);
+
+      let expected_hitbox = Hitbox::new(Rectangle::new(7, 7), 0);
+
+      let combined_hitbox = hitbox_one.combined(&hitbox_two, offset);
+
+      assert_eq!(combined_hitbox, expected_hitbox);
+    }
+
+    #[test]
+    fn negative_offset() {
+      let hitbox_one = Hitbox::new(Rectangle::new(3, 2), 4);
+      let hitbox_two = Hitbox::new(Rectangle::new(2, 2), 1);
+      let offset = (-4, -1);
+
+      let expected_hitbox = Hitbox::new(Rectangle::new(7, 3), 0);
+
+      let combined_hitbox = hitbox_one.combined(&hitbox_two, offset);
+
+      assert_eq!(combined_hitbox, expected_hitbox);
+    }
+
+    #[test]
+    fn empty_hitbox_is_ignored() {
+      let hitbox = Hitbox::new(Rectangle::new(3, 2), 4);
+      let empty_hitbox = Hitbox::new(Rectangle::default(), 0);
+
+      let expected_hitbox = Hitbox::new(Rectangle::new(3, 2), 0);
+
+      assert_eq!(hitbox.combined(&empty_hitbox, (10, 10)), expected_hitbox);
+      assert_eq!(empty_hitbox.combined(&hitbox, (10, 10)), expected_hitbox);
+    }
+  }
+
+  #[cfg(test)]
+  mod scale_logic {
+    use super::*;
+
+    #[test]
+    fn grow_around_centered_anchor() {
+      let mut hitbox = Hitbox::new(Rectangle::new(5, 3), 7);
+
+      hitbox.scale(2.0, 2.0);
+
+      assert_eq!(hitbox.get_hitbox_dimensions(), &Rectangle::new(10, 6));
+      assert_eq!(hitbox.get_anchor_as_coordinates(), (5, 3));
+    }
+
+    #[test]
+    fn shrink_around_centered_anchor() {
+      let mut hitbox = Hitbox::new(Rectangle::new(5, 3), 7);
+
+      hitbox.scale(0.5, 1.0);
+
+      assert_eq!(hitbox.get_hitbox_dimensions(), &Rectangle::new(3, 3));
+      assert_eq!(hitbox.get_anchor_as_coordinates(), (1, 1));
+    }
+
+    #[test]
+    fn top_left_anchor_moves_to_the_center_of_its_scaled_cell() {
+      let mut hitbox = Hitbox::new(Rectangle::new(3, 2), 0);
+      // The top left cell grows to cover columns 0 to 2, and rows 0 to 1.5.
+
+      hitbox.scale(3.0, 1.5);
+
+      assert_eq!(hitbox.get_hitbox_dimensions(), &Rectangle::new(9, 3));
+      assert_eq!(hitbox.get_anchor_as_coordinates(), (1, 0));
+    }
+
+    #[test]
+    fn sides_dont_go_below_one() {
+      let mut hitbox = Hitbox::new(Rectangle::new(5, 3), 14);
+
+      hitbox.scale(0.0, -2.0);
+
+      assert_eq!(hitbox.get_hitbox_dimensions(), &Rectangle::new(1, 1));
+      assert_eq!(hitbox.get_anchor_index(), 0);
+    }
+
+    #[test]
+    fn empty_hitbox_stays_empty() {
+      let mut hitbox = Hitbox::new(Rectangle::default(), 0);
+
+      hitbox.scale(4.0, 4.0);
+
+      assert!(hitbox.is_empty());
+      assert_eq!(hitbox.get_hitbox_dimensions(), &Rectangle::default());
+    }
+  }
+
+  #[cfg(test)]
+  mod from_shape_logic {
+    use super::*;
+
+    #[test]
+    fn valid_shape() {
+      let hitbox = Hitbox::from_shape("xxx\nxax", 'a').unwrap();
+
+      let expected_hitbox = Hitbox::new(Rectangle::new(3, 2), 4);
+
+      assert_eq!(hitbox, expected_hitbox);
+    }
+
+    #[test]
+    fn empty_shape() {
+      let hitbox = Hitbox::from_shape("", 'a').unwrap();
+
+      assert!(hitbox.is_empty());
+    }
+
+    #[test]
+    fn anchor_errors() {
+      let no_anchor = Hitbox::from_shape("xxx\nxxx", 'a').unwrap_err();
+      let multiple_anchors = Hitbox::from_shape("axx\nxxa", 'a').unwrap_err();
+
+      assert_eq!(no_anchor, ModelError::NoAnchor);
+      assert_eq!(
+        multiple_anchors,
+        ModelError::MultipleAnchorsFound(vec![0, 5])
+      );
+    }
+
+    #[test]
+    fn non_rectangular_shape() {
+      let result = Hitbox::from_shape("xxx\nxa", 'a').unwrap_err();
+
+      assert_eq!(result, ModelError::NonRectangularShape);
+    }
+  }
 }
@@ -13,6 +13,36 @@ pub struct ModelAppearance {
   ///
   /// None if there was no `.animate` file in the same path of the model, or there was no alternative path given.
   animation_data: Option<ModelAnimationData>,
+  /// The last appearance string built through
+  /// [`get_current_appearance_string`](ModelAppearance::get_current_appearance_string).
+  #[serde(skip)]
+  appearance_cache: Option<AppearanceCache>,
+}
+
+/// The appearance string of a displayed frame, saving the string from being rebuilt every frame.
+///
+/// The frame is stored as the parts of a [`DisplayedFrame`], and the cache is cleared whenever the
+/// sprites it could've been built from are changed.
+#[derive(Debug, Clone)]
+struct AppearanceCache {
+  animation_name: Option<String>,
+  frame_index: Option<u64>,
+  appearance: String,
+}
+
+impl AppearanceCache {
+  fn new(displayed_frame: DisplayedFrame, sprite: &Sprite) -> Self {
+    Self {
+      animation_name: displayed_frame.animation_name().map(str::to_string),
+      frame_index: displayed_frame.frame_index(),
+      appearance: sprite.get_appearance(),
+    }
+  }
+
+  fn is_built_from(&self, displayed_frame: DisplayedFrame) -> bool {
+    self.animation_name.as_deref() == displayed_frame.animation_name()
+      && self.frame_index == displayed_frame.frame_index()
+  }
 }
 
 impl ModelAppearance {
@@ -20,6 +50,7 @@ impl ModelAppearance {
     Self {
       default_sprite: sprite,
       animation_data,
+      appearance_cache: None,
     }
   }
 
@@ -28,7 +59,9 @@ impl ModelAppearance {
     &mut self,
     new_animation_data: ModelAnimationData,
   ) -> Option<ModelAnimationData> {
-    std::mem::replace(&mut self.animation_data, Some(new_animation_data))
+    self.appearance_cache = None;
+
+    self.animation_data.replace(new_animation_data)
   }
 
   pub fn get_appearance(&self) -> &Sprite {
@@ -41,6 +74,36 @@ impl ModelAppearance {
     &self.default_sprite
   }
 
+  /// Returns the current appearance as a string, with the anchor replaced.
+  ///
+  /// The string is cached, and is only rebuilt when the displayed frame changes, or the default
+  /// sprite or animations are replaced. This means a model that never animates only builds its
+  /// appearance string once, and an animated model only rebuilds it when the animation advances
+  /// to a new frame.
+  pub fn get_current_appearance_string(&mut self) -> &str {
+    let displayed_frame = self.animation_data.as_ref().map_or(
+      DisplayedFrame::Default,
+      ModelAnimationData::get_displayed_frame,
+    );
+
+    let appearance_cache = match self.appearance_cache.take() {
+      Some(appearance_cache) if appearance_cache.is_built_from(displayed_frame) => appearance_cache,
+      _ => {
+        let sprite = self
+          .animation_data
+          .as_ref()
+          .and_then(|animation_data| {
+            displayed_frame.get_sprite(animation_data.get_animation_list())
+          })
+          .unwrap_or(&self.default_sprite);
+
+        AppearanceCache::new(displayed_frame, sprite)
+      }
+    };
+
+    &self.appearance_cache.insert(appearance_cache).appearance
+  }
+
   /// Gets the default sprite, ignoring all animation data that may or may not be running.
   ///
   /// If there is no animation data, the default will be returned from [`get_appearance`](ModelAppearance::get_appearance).
@@ -55,6 +118,8 @@ impl ModelAppearance {
   ///   - No animations have been run since creation of the animation_data.
   ///   - The model_animator has been refreshed, clearing all data about previously run animations.
   pub fn update_default_sprite(&mut self, new_sprite: Sprite) -> Sprite {
+    self.appearance_cache = None;
+
     std::mem::replace(&mut self.default_sprite, new_sprite)
   }
 
@@ -78,12 +143,88 @@ impl ModelAppearance {
       .overwrite_current_model_animation(new_animation_name)
   }
 
+  /// Moves the first instance of the animation in the queue to the given index.
+  ///
+  /// Indices past the back of the queue will move the animation to the back.
+  /// The currently running animation isn't disrupted unless the front of the queue changes.
+  ///
+  /// # Errors
+  ///
+  /// - The animation wasn't in the queue.
+  pub fn move_queued_animation(
+    &mut self,
+    animation_name: &str,
+    to_index: usize,
+  ) -> Result<(), ModelError> {
+    self
+      .get_mut_animation_data()
+      .move_queued_animation(animation_name, to_index)
+      .map_err(Into::into)
+  }
+
+  /// Freezes the model's running animation in place until the animations are resumed.
+  ///
+  /// Animations queued while paused also stay frozen until resumed.
+  ///
+  /// Nothing happens if the model has no animation data.
+  pub fn pause_animations(&mut self) {
+    if let Some(animation_data) = self.animation_data.as_mut() {
+      animation_data.pause_animations();
+    }
+  }
+
+  /// Continues running the model's animations from where they were paused.
+  ///
+  /// Nothing happens if the model has no animation data.
+  pub fn resume_animations(&mut self) {
+    if let Some(animation_data) = self.animation_data.as_mut() {
+      animation_data.resume_animations();
+    }
+  }
+
+  /// Changes how fast the model's animations are played, a multiplier of 2.0 plays them twice as fast.
+  ///
+  /// The multiplier is applied when converting the ticks an animation has been running for into
+  /// the frame to display, so the frame durations stored in each animation are left untouched.
+  /// The multiplier is clamped between [`MINIMUM_ANIMATION_SPEED`](MINIMUM_ANIMATION_SPEED) and
+  /// [`MAXIMUM_ANIMATION_SPEED`](MAXIMUM_ANIMATION_SPEED), and ignored if it's NaN or infinite.
+  ///
+  /// Animations with a `Limited` loop count still play the same amount of loops, meaning a
+  /// multiplier of 2.0 makes them finish in half the time.
+  ///
+  /// Nothing happens if the model has no animation data.
+  pub fn set_animation_speed(&mut self, multiplier: f32) {
+    if let Some(animation_data) = self.animation_data.as_mut() {
+      animation_data.set_animation_speed(multiplier);
+    }
+  }
+
+  /// Returns the multiplier for how fast the model's animations are played.
+  ///
+  /// 1.0 is returned if the model has no animation data.
+  pub fn get_animation_speed(&self) -> f32 {
+    self
+      .animation_data
+      .as_ref()
+      .map_or(1.0, ModelAnimationData::get_animation_speed)
+  }
+
+  /// Returns true if the model's animations are paused.
+  pub fn animations_are_paused(&self) -> bool {
+    self
+      .animation_data
+      .as_ref()
+      .is_some_and(ModelAnimationData::animations_are_paused)
+  }
+
   /// If an animation with that name already exists, it is returned.
   pub fn add_animation_to_model(
     &mut self,
     animation_name: String,
     new_animation: AnimationFrames,
   ) -> Option<AnimationFrames> {
+    self.appearance_cache = None;
+
     self
       .get_mut_animation_data()
       .add_new_animation_to_list(animation_name, new_animation)
@@ -91,6 +232,8 @@ impl ModelAppearance {
 
   /// Removes the animation of the given name and returns it if it existed.
   pub fn remove_animation_from_list(&mut self, animation_name: &str) -> Option<AnimationFrames> {
+    self.appearance_cache = None;
+
     self
       .get_mut_animation_data()
       .remove_animation_from_list(animation_name)
@@ -126,13 +269,7 @@ impl ModelAppearance {
       let mut errors: Vec<AnimationValidityErrorData> = animation_data
         .get_animation_list()
         .iter()
-        .filter_map(|(animation_name, animation)| {
-          if let Err(error_data) = animation.validity_check(animation_name) {
-            Some(error_data)
-          } else {
-            None
-          }
-        })
+        .filter_map(|(animation_name, animation)| animation.validity_check(animation_name).err())
         .collect();
 
       if let Err(ModelError::SpriteValidityChecks(error_list)) =
@@ -161,7 +298,7 @@ impl ModelAppearance {
   pub fn current_animation_name(&self) -> Option<String> {
     let animation_data = self.animation_data.as_ref()?;
 
-    animation_data.get_animation_queue(|queue| queue.get(0).cloned())
+    animation_data.get_animation_queue(|queue| queue.front().cloned())
   }
 
   /// Returns the loop count and the duration of each loop in the current animation.
@@ -172,7 +309,7 @@ impl ModelAppearance {
     let animation_data = self.animation_data.as_ref()?;
     let animation_list = animation_data.get_animation_list();
     let current_running_animation =
-      animation_data.get_animation_queue(|queue| queue.get(0).cloned())?;
+      animation_data.get_animation_queue(|queue| queue.front().cloned())?;
 
     let animation = animation_list.get(&current_running_animation)?;
 
@@ -182,6 +319,72 @@ impl ModelAppearance {
     Some((loop_count, loop_duration as u32))
   }
 
+  /// Returns true if the model has animations queued or currently running.
+  ///
+  /// If the model has no animation data, false is returned.
+  pub fn has_animations_to_run(&self) -> bool {
+    self
+      .animation_data
+      .as_ref()
+      .is_some_and(ModelAnimationData::has_animations_to_run)
+  }
+
+  /// Returns how many ticks have passed since the model's last animation finished running.
+  ///
+  /// 0 is returned if there are animations currently running.
+  /// None is returned if the model has no animation data, or no animation has finished running yet.
+  pub fn ticks_since_last_animation(&self) -> Option<u64> {
+    self.animation_data.as_ref()?.ticks_since_last_animation()
+  }
+
+  /// Returns true if one of the model's animations finished running since the last time this was called.
+  ///
+  /// Animations that run forever never finish.
+  /// If the model has no animation data, false is returned.
+  pub fn animation_just_finished(&self) -> bool {
+    self
+      .animation_data
+      .as_ref()
+      .is_some_and(ModelAnimationData::animation_just_finished)
+  }
+
+  /// Returns the index of the frame currently being displayed in the model's running animation.
+  ///
+  /// None is returned if the model has no animation data, or there's no animation running.
+  pub fn current_frame_index(&self) -> Option<u64> {
+    self.animation_data.as_ref()?.current_frame_index()
+  }
+
+  /// Returns how many loops of the model's running animation haven't been completed yet.
+  ///
+  /// The loop currently being displayed counts as remaining until it finishes,
+  /// so a `Limited(3)` animation starts at 3 and reaches 1 during its final loop.
+  ///
+  /// None is returned if the model has no animation data, there's no animation running,
+  /// or the running animation loops forever.
+  pub fn animation_loops_remaining(&self) -> Option<u64> {
+    self.animation_data.as_ref()?.animation_loops_remaining()
+  }
+
+  /// Returns the anchor offset of the frame currently being displayed in the model's running animation.
+  ///
+  /// None is returned if the model has no animation data, or there's no animation running.
+  pub fn current_anchor_offset(&self) -> Option<(isize, isize)> {
+    self.animation_data.as_ref()?.current_anchor_offset()
+  }
+
+  /// Returns the names of every animation in the model's queue, in the order they'll be run.
+  ///
+  /// The first name is the currently running animation.
+  /// An empty list is returned if the model has no animation data.
+  pub fn queued_animation_names(&self) -> Vec<String> {
+    let Some(animation_data) = self.animation_data.as_ref() else {
+      return vec![];
+    };
+
+    animation_data.get_animation_queue(|queue| queue.iter().cloned().collect())
+  }
+
   /// Returns the index of the animation if it was running.
   ///
   /// If the model has no animation data, None is returned.
@@ -209,14 +412,113 @@ impl ModelAppearance {
 
 #[cfg(test)]
 mod tests {
-  // use super::*;
-  // use crate::models::testing_data::*;
-  //
-  // #[test]
-  // fn api_testing() {
-  // let sprite_appearance = TestingData::get_frame_appearance('x');
-  // let sprite = Sprite::new(sprite_appearance, 'a', 'x', '-').unwrap();
-  // let animation_data = TestingData::get_test_model_animation_data();
-  // let mut model_appearance = ModelAppearance::new(sprite, Some(animation_data));
-  // }
+  use super::*;
+  use crate::models::testing_data::*;
+  use crate::CONFIG;
+  use event_sync::EventSync;
+
+  #[cfg(test)]
+  mod get_current_appearance_string_logic {
+    use super::*;
+
+    #[test]
+    fn matches_sprite_appearance() {
+      let sprite = Sprite::new(TestingData::get_frame_appearance('x'), 'a', 'x', '-').unwrap();
+      let expected_appearance = sprite.get_appearance();
+      let mut model_appearance = ModelAppearance::new(sprite, None);
+
+      assert_eq!(
+        model_appearance.get_current_appearance_string(),
+        expected_appearance
+      );
+      // The second call is read from the cache.
+      assert_eq!(
+        model_appearance.get_current_appearance_string(),
+        expected_appearance
+      );
+    }
+
+    #[test]
+    fn sprite_change_rebuilds_string() {
+      let sprite = Sprite::new(TestingData::get_frame_appearance('x'), 'a', 'x', '-').unwrap();
+      let new_sprite = Sprite::new(TestingData::get_frame_appearance('y'), 'a', 'y', '-').unwrap();
+      let expected_appearance = new_sprite.get_appearance();
+      let mut model_appearance = ModelAppearance::new(sprite, None);
+      model_appearance.get_current_appearance_string();
+
+      model_appearance.update_default_sprite(new_sprite);
+
+      assert_eq!(
+        model_appearance.get_current_appearance_string(),
+        expected_appearance
+      );
+    }
+    #[test]
+    fn frame_change_rebuilds_string() {
+      let sprite = Sprite::new(TestingData::get_frame_appearance('x'), 'a', 'x', '-').unwrap();
+      let animation_data = TestingData::get_test_model_animation_data();
+      let mut model_appearance = ModelAppearance::new(sprite, Some(animation_data));
+      model_appearance
+        .queue_model_animation(TestingData::ANIMATION_NAME)
+        .unwrap();
+      let first_appearance = model_appearance.get_current_appearance_string().to_string();
+
+      EventSync::new(CONFIG.tick_duration)
+        .wait_for_tick()
+        .unwrap();
+      model_appearance.pause_animations();
+
+      let expected_appearance = model_appearance.get_appearance().get_appearance();
+
+      assert_ne!(expected_appearance, first_appearance);
+      assert_eq!(
+        model_appearance.get_current_appearance_string(),
+        expected_appearance
+      );
+    }
+
+    #[test]
+    fn replaced_animation_rebuilds_string() {
+      let sprite = Sprite::new(TestingData::get_frame_appearance('x'), 'a', 'x', '-').unwrap();
+      let animation_data = TestingData::get_test_model_animation_data();
+      let new_animation =
+        TestingData::get_test_animation(['q', 'r', 's'], AnimationLoopCount::Limited(2));
+      let expected_appearance = new_animation
+        .get_frame(0)
+        .unwrap()
+        .get_appearance()
+        .get_appearance();
+      let mut model_appearance = ModelAppearance::new(sprite, Some(animation_data));
+      model_appearance
+        .queue_model_animation(TestingData::ANIMATION_NAME)
+        .unwrap();
+      model_appearance.pause_animations();
+      model_appearance.get_current_appearance_string();
+
+      model_appearance
+        .add_animation_to_model(TestingData::ANIMATION_NAME.to_string(), new_animation);
+
+      assert_eq!(
+        model_appearance.get_current_appearance_string(),
+        expected_appearance
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod pause_animations_logic {
+    use super::*;
+
+    #[test]
+    fn static_model_gains_no_animation_data() {
+      let sprite = Sprite::new(TestingData::get_frame_appearance('x'), 'a', 'x', '-').unwrap();
+      let mut model_appearance = ModelAppearance::new(sprite, None);
+
+      model_appearance.pause_animations();
+      model_appearance.resume_animations();
+      model_appearance.set_animation_speed(2.0);
+
+      assert!(model_appearance.animation_data.is_none());
+    }
+  }
 }
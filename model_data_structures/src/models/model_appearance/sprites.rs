@@ -3,6 +3,13 @@ use engine_math::{prelude::usizeMethods, rectangle::Rectangle};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
+/// Pairs of characters that are swapped when a sprite is mirrored horizontally.
+const HORIZONTAL_MIRROR_PAIRS: [(char, char); 5] =
+  [('/', '\\'), ('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Pairs of characters that are swapped when a sprite is mirrored vertically.
+const VERTICAL_MIRROR_PAIRS: [(char, char); 1] = [('/', '\\')];
+
 /// The ``Sprite`` contains the data for how a model looks on the screen.
 ///
 /// Also holds the anchor for how the appearance in placed on the screen and where the hitbox is placed
@@ -53,6 +60,49 @@ impl Sprite {
     Ok(sprite)
   }
 
+  /// Creates a new sprite from a shape that doesn't need to be rectangular.
+  ///
+  /// Every row shorter than the widest row is padded with the air character on its right side,
+  /// meaning a shape of "x\nxxx\nx" would become "x--\nxxx\nx--" with an air character of '-'.
+  ///
+  /// Use [`Sprite::new`](Sprite::new) if the shape should be validated exactly as is.
+  ///
+  /// # Errors
+  ///
+  /// - The shape doesn't have an anchor.
+  /// - The shape has multiple anchors.
+  /// - The anchor and air characters are the same.
+  pub fn from_ragged(
+    shape: impl AsRef<str>,
+    anchor_character: char,
+    anchor_replacement_character: char,
+    air_character: char,
+  ) -> Result<Self, ModelError> {
+    let rows: Vec<&str> = shape.as_ref().split('\n').collect();
+    let widest_row = rows
+      .iter()
+      .map(|row| row.chars().count())
+      .max()
+      .unwrap_or(0);
+
+    let padded_shape = rows
+      .iter()
+      .map(|row| {
+        let padding = widest_row - row.chars().count();
+
+        format!("{row}{}", air_character.to_string().repeat(padding))
+      })
+      .collect::<Vec<String>>()
+      .join("\n");
+
+    Self::new(
+      padded_shape,
+      anchor_character,
+      anchor_replacement_character,
+      air_character,
+    )
+  }
+
   /// Changes the internally stored shape and recalculates the anchor's index.
   ///
   /// # Errors
@@ -114,6 +164,33 @@ impl Sprite {
     Ok(())
   }
 
+  /// Replaces every instance of a character in the shape with another one.
+  ///
+  /// The dimensions and anchor index of the sprite are left unchanged.
+  ///
+  /// # Errors
+  ///
+  /// - The character being replaced is the anchor character.
+  /// - The new character is the anchor character.
+  /// - Either character is a new line.
+  pub fn replace_character(&mut self, from: char, to: char) -> Result<(), ModelError> {
+    if from == self.anchor_character {
+      return Err(ModelError::NoAnchor);
+    }
+
+    if to == self.anchor_character {
+      return Err(ModelError::ModelSpriteContainsNewAnchorCharacter);
+    }
+
+    if from == '\n' || to == '\n' {
+      return Err(ModelError::NonRectangularShape);
+    }
+
+    self.shape = self.shape.replace(from, &to.to_string());
+
+    Ok(())
+  }
+
   /// Replaces the currently stored air character.
   ///
   /// The air character is basically a transparency layer on the shape of a sprite.
@@ -151,7 +228,16 @@ impl Sprite {
   /// This is not based on World coordinates, but rather it's coordinates internal to the sprite's appearance.
   /// That means if the model is 3x3 in size, and the index is 4, this method will return (1, 1).
   pub fn get_anchor_as_coordinates(&self) -> (usize, usize) {
-    let sprite_width = self.get_dimensions().x;
+    // The shape is always rectangular, so only the first row is needed for the width.
+    let sprite_width = self
+      .shape
+      .lines()
+      .next()
+      .map_or(0, |row| row.chars().count());
+
+    if sprite_width == 0 {
+      return (0, 0);
+    }
 
     self
       .anchor_character_index
@@ -243,6 +329,304 @@ impl Sprite {
     self.air_character
   }
 
+  /// Returns a copy of the character the anchor is replaced with when the sprite is displayed.
+  pub fn anchor_replacement_character(&self) -> char {
+    self.anchor_replacement_character
+  }
+
+  /// Returns true if every character in the sprite's appearance is the air character.
+  pub fn is_fully_transparent(&self) -> bool {
+    self
+      .get_appearance()
+      .chars()
+      .filter(|character| character != &'\n')
+      .all(|character| character == self.air_character)
+  }
+
+  /// Flips the sprite's shape left to right.
+  ///
+  /// Characters with a mirrored counterpart, such as '/' and '\\', are swapped so the art stays coherent.
+  /// The anchor and air characters are left as they are.
+  ///
+  /// # Errors
+  ///
+  /// - The stored shape isn't rectangular.
+  pub fn mirror_horizontal(&mut self) -> Result<(), ModelError> {
+    let mirrored_shape = self.mirror_shape(&HORIZONTAL_MIRROR_PAIRS, |rows| {
+      rows
+        .into_iter()
+        .map(|row| row.chars().rev().collect())
+        .collect()
+    })?;
+
+    self.change_shape(mirrored_shape, None, None)
+  }
+
+  /// Flips the sprite's shape top to bottom.
+  ///
+  /// Characters with a mirrored counterpart, such as '/' and '\\', are swapped so the art stays coherent.
+  /// The anchor and air characters are left as they are.
+  ///
+  /// # Errors
+  ///
+  /// - The stored shape isn't rectangular.
+  pub fn mirror_vertical(&mut self) -> Result<(), ModelError> {
+    let mirrored_shape = self.mirror_shape(&VERTICAL_MIRROR_PAIRS, |rows| {
+      rows.into_iter().rev().map(str::to_string).collect()
+    })?;
+
+    self.change_shape(mirrored_shape, None, None)
+  }
+
+  /// Rotates the sprite's shape a quarter turn, swapping its width and height.
+  ///
+  /// Characters are only repositioned, they aren't swapped for a rotated counterpart.
+  /// The anchor index is recalculated from the rotated shape.
+  ///
+  /// # Errors
+  ///
+  /// - The stored shape isn't rectangular.
+  pub fn rotate_90(&mut self, clockwise: bool) -> Result<(), ModelError> {
+    if !Rectangle::string_is_valid_rectangle(&self.shape) {
+      return Err(ModelError::NonRectangularShape);
+    }
+
+    let rows: Vec<Vec<char>> = self
+      .shape
+      .split('\n')
+      .map(|row| row.chars().collect())
+      .collect();
+    let width = rows.first().map_or(0, Vec::len);
+
+    let rotated_row = |column: usize| -> String {
+      if clockwise {
+        rows.iter().rev().map(|row| row[column]).collect()
+      } else {
+        rows.iter().map(|row| row[column]).collect()
+      }
+    };
+    let rotated_rows: Vec<String> = if clockwise {
+      (0..width).map(rotated_row).collect()
+    } else {
+      (0..width).rev().map(rotated_row).collect()
+    };
+
+    self.change_shape(rotated_rows.join("\n"), None, None)
+  }
+
+  /// Rearranges the rows of the shape with the given method, then swaps any characters in the list of pairs.
+  ///
+  /// # Errors
+  ///
+  /// - The stored shape isn't rectangular.
+  fn mirror_shape<F>(
+    &self,
+    mirror_pairs: &[(char, char)],
+    rearrange_rows: F,
+  ) -> Result<String, ModelError>
+  where
+    F: FnOnce(Vec<&str>) -> Vec<String>,
+  {
+    if !Rectangle::string_is_valid_rectangle(&self.shape) {
+      return Err(ModelError::NonRectangularShape);
+    }
+
+    let mirror_character = |character: char| {
+      let mirrored_character = mirror_pairs.iter().find_map(|(left, right)| {
+        if character == *left {
+          Some(*right)
+        } else if character == *right {
+          Some(*left)
+        } else {
+          None
+        }
+      });
+
+      match mirrored_character {
+        Some(mirrored_character)
+          if ![self.anchor_character, self.air_character].contains(&character)
+            && ![self.anchor_character, self.air_character].contains(&mirrored_character) =>
+        {
+          mirrored_character
+        }
+        _ => character,
+      }
+    };
+
+    let rows = rearrange_rows(self.shape.split('\n').collect());
+
+    Ok(
+      rows
+        .iter()
+        .map(|row| row.chars().map(mirror_character).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n"),
+    )
+  }
+
+  /// Returns the top left and bottom right coordinates of the smallest rectangle containing every
+  /// non-air character in the sprite's appearance.
+  ///
+  /// Both coordinates are inclusive and internal to the sprite, meaning a sprite with no air would
+  /// return ((0, 0), (width - 1, height - 1)).
+  ///
+  /// Returns None if the sprite is entirely air.
+  pub fn content_bounds(&self) -> Option<((usize, usize), (usize, usize))> {
+    let mut bounds: Option<((usize, usize), (usize, usize))> = None;
+
+    for (y, row) in self.get_appearance().lines().enumerate() {
+      for (x, character) in row.chars().enumerate() {
+        if character == self.air_character {
+          continue;
+        }
+
+        bounds = match bounds {
+          None => Some(((x, y), (x, y))),
+          Some((top_left, bottom_right)) => Some((
+            (top_left.0.min(x), top_left.1.min(y)),
+            (bottom_right.0.max(x), bottom_right.1.max(y)),
+          )),
+        };
+      }
+    }
+
+    bounds
+  }
+
+  /// Removes every leading and trailing row and column that consists entirely of the air character.
+  ///
+  /// Rows and columns containing the anchor are always kept, so an edge is only trimmed up to the
+  /// anchor. The anchor index is recalculated for the new dimensions.
+  ///
+  /// A model using this sprite will need its position updated, as the top left of the sprite moves.
+  pub fn trim_air_border(&mut self) {
+    if self.get_dimensions().area() == 0 {
+      return;
+    }
+
+    let anchor = self.get_anchor_as_coordinates();
+    let (top_left, bottom_right) = match self.content_bounds() {
+      Some((top_left, bottom_right)) => (
+        (top_left.0.min(anchor.0), top_left.1.min(anchor.1)),
+        (bottom_right.0.max(anchor.0), bottom_right.1.max(anchor.1)),
+      ),
+      None => (anchor, anchor),
+    };
+    let dimensions = Rectangle::new(
+      bottom_right.0 - top_left.0 + 1,
+      bottom_right.1 - top_left.1 + 1,
+    );
+
+    match self.crop(top_left, dimensions) {
+      Ok(trimmed_sprite) => *self = trimmed_sprite,
+      Err(error) => log::error!("Failed to trim the air border of a sprite: {error:?}"),
+    }
+  }
+
+  /// Returns a copy of this sprite with the top sprite drawn over it.
+  ///
+  /// The offset is where the top left of the top sprite is placed, relative to the top left of this sprite.
+  /// Air in the top sprite is skipped, and anything placed outside of this sprite is clipped.
+  ///
+  /// The anchor of this sprite is kept. Should the top sprite cover the anchor, the character
+  /// covering it becomes the anchor's replacement character.
+  ///
+  /// # Errors
+  ///
+  /// - The stored shape isn't rectangular.
+  /// - The top sprite places this sprite's anchor character, creating multiple anchors.
+  pub fn overlay(&self, top: &Sprite, offset: (isize, isize)) -> Result<Sprite, ModelError> {
+    let Some(dimensions) = Rectangle::get_string_dimensions(&self.shape) else {
+      return Err(ModelError::NonRectangularShape);
+    };
+
+    if dimensions.area() == 0 {
+      return Ok(self.clone());
+    }
+
+    let mut overlaid_sprite = self.clone();
+    let mut cells: Vec<char> = self.shape.replace('\n', "").chars().collect();
+
+    for (y, row) in top.get_appearance().lines().enumerate() {
+      for (x, character) in row.chars().enumerate() {
+        let (x, y) = (x as isize + offset.0, y as isize + offset.1);
+
+        if character == top.air_character
+          || x < 0
+          || y < 0
+          || x >= dimensions.x as isize
+          || y >= dimensions.y as isize
+        {
+          continue;
+        }
+
+        let index = (y as usize * dimensions.x) + x as usize;
+
+        if index == self.anchor_character_index {
+          overlaid_sprite.anchor_replacement_character = character;
+        } else {
+          cells[index] = character;
+        }
+      }
+    }
+
+    let overlaid_shape = cells
+      .chunks(dimensions.x)
+      .map(|row| row.iter().collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n");
+
+    overlaid_sprite.change_shape(overlaid_shape, None, None)?;
+
+    Ok(overlaid_sprite)
+  }
+
+  /// Returns a new sprite containing the region of this sprite starting at the given top left
+  /// coordinates, with the given dimensions.
+  ///
+  /// The anchor, anchor replacement, and air characters are carried over to the cropped sprite.
+  /// This allows slicing a single sprite sheet into multiple sprites, as long as each region
+  /// contains its own anchor.
+  ///
+  /// # Errors
+  ///
+  /// - The region extends past the edges of the sprite.
+  /// - The region doesn't contain an anchor.
+  /// - The region contains multiple anchors.
+  pub fn crop(
+    &self,
+    top_left: (usize, usize),
+    dimensions: Rectangle,
+  ) -> Result<Sprite, ModelError> {
+    let sprite_dimensions = self.get_dimensions();
+
+    if top_left.0 + dimensions.x > sprite_dimensions.x
+      || top_left.1 + dimensions.y > sprite_dimensions.y
+    {
+      return Err(ModelError::SpriteCropOutOfBounds);
+    }
+
+    let cropped_shape = self
+      .shape
+      .split('\n')
+      .skip(top_left.1)
+      .take(dimensions.y)
+      .map(|row| {
+        row
+          .chars()
+          .skip(top_left.0)
+          .take(dimensions.x)
+          .collect::<String>()
+      })
+      .collect::<Vec<String>>()
+      .join("\n");
+
+    let mut cropped_sprite = self.clone();
+    cropped_sprite.change_shape(cropped_shape, None, None)?;
+
+    Ok(cropped_sprite)
+  }
+
   /// Creates a new Sprite with the data as is. Does not check for any errors that may make the Sprite invalid.
   /// When passing in the index, it is exclusive to any newlines, meaning an appearance of "xxx\nxcx" would have
   /// an anchor index of 4, because the newline is ignored.
@@ -318,6 +702,54 @@ mod tests {
     assert_eq!(sprite.shape, expected_appearance);
   }
 
+  #[cfg(test)]
+  mod replace_character_logic {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+      let mut sprite = Sprite::new("#-#\n#a#", 'a', '#', '-').unwrap();
+      let anchor_index = sprite.get_anchor_index();
+
+      sprite.replace_character('#', '@').unwrap();
+
+      assert_eq!(sprite.shape, "@-@\n@a@");
+      assert_eq!(sprite.get_anchor_index(), anchor_index);
+    }
+
+    #[test]
+    fn replacing_the_anchor() {
+      let mut sprite = Sprite::new("#-#\n#a#", 'a', '#', '-').unwrap();
+
+      let result = sprite.replace_character('a', '#');
+
+      assert_eq!(result, Err(ModelError::NoAnchor));
+      assert_eq!(sprite.shape, "#-#\n#a#");
+    }
+
+    #[test]
+    fn replacing_with_the_anchor() {
+      let mut sprite = Sprite::new("#-#\n#a#", 'a', '#', '-').unwrap();
+
+      let result = sprite.replace_character('#', 'a');
+
+      assert_eq!(
+        result,
+        Err(ModelError::ModelSpriteContainsNewAnchorCharacter)
+      );
+      assert_eq!(sprite.shape, "#-#\n#a#");
+    }
+
+    #[test]
+    fn replacing_new_lines() {
+      let mut sprite = Sprite::new("#-#\n#a#", 'a', '#', '-').unwrap();
+
+      let result = sprite.replace_character('\n', '#');
+
+      assert_eq!(result, Err(ModelError::NonRectangularShape));
+    }
+  }
+
   #[test]
   fn change_air_character_matching_anchor() {
     let mut sprite = Sprite::new("-x-\n-a-", 'a', '-', '-').unwrap();
@@ -402,4 +834,337 @@ mod tests {
 
     assert_eq!(result, expected_error_list);
   }
+
+  #[cfg(test)]
+  mod content_bounds_logic {
+    use super::*;
+
+    #[test]
+    fn padded_sprite() {
+      let shape = "------\n--xx--\n--ax--\n------";
+      let sprite = Sprite::new(shape, 'a', 'x', '-').unwrap();
+
+      let expected_bounds = Some(((2, 1), (3, 2)));
+
+      let bounds = sprite.content_bounds();
+
+      assert_eq!(bounds, expected_bounds);
+    }
+
+    #[test]
+    fn no_padding() {
+      let sprite = Sprite::new("xxx\nxax", 'a', 'x', '-').unwrap();
+
+      let expected_bounds = Some(((0, 0), (2, 1)));
+
+      let bounds = sprite.content_bounds();
+
+      assert_eq!(bounds, expected_bounds);
+    }
+
+    #[test]
+    fn entirely_air() {
+      let sprite = Sprite::new("---\n-a-", 'a', '-', '-').unwrap();
+
+      let bounds = sprite.content_bounds();
+
+      assert!(bounds.is_none());
+    }
+  }
+
+  #[cfg(test)]
+  mod mirror_logic {
+    use super::*;
+
+    #[test]
+    fn mirror_horizontal_logic() {
+      let mut sprite = Sprite::new("ab/-\n(x--", 'a', 'x', '-').unwrap();
+
+      let expected_shape = "-\\ba\n--x)";
+      let expected_anchor_index = 3;
+
+      sprite.mirror_horizontal().unwrap();
+
+      assert_eq!(sprite.shape, expected_shape);
+      assert_eq!(sprite.get_anchor_index(), expected_anchor_index);
+      assert_eq!(sprite.air_character(), '-');
+      assert_eq!(sprite.anchor_character, 'a');
+      assert_eq!(sprite.anchor_replacement_character, 'x');
+    }
+
+    #[test]
+    fn mirror_vertical_logic() {
+      let mut sprite = Sprite::new("a/-\nx(-", 'a', 'x', '-').unwrap();
+
+      let expected_shape = "x(-\na\\-";
+      let expected_anchor_index = 3;
+
+      sprite.mirror_vertical().unwrap();
+
+      assert_eq!(sprite.shape, expected_shape);
+      assert_eq!(sprite.get_anchor_index(), expected_anchor_index);
+    }
+
+    #[test]
+    fn mirroring_twice_restores_the_sprite() {
+      let original_sprite = Sprite::new("ab/-\n(x-<", 'a', 'x', '-').unwrap();
+      let mut sprite = original_sprite.clone();
+
+      sprite.mirror_horizontal().unwrap();
+      sprite.mirror_horizontal().unwrap();
+      sprite.mirror_vertical().unwrap();
+      sprite.mirror_vertical().unwrap();
+
+      assert_eq!(sprite, original_sprite);
+    }
+
+    #[test]
+    fn invalid_shape() {
+      let mut sprite = Sprite::new_unchecked("xa\nx", 'a', 'x', '-', 1);
+
+      let expected_error = ModelError::NonRectangularShape;
+
+      assert_eq!(sprite.mirror_horizontal().unwrap_err(), expected_error);
+      assert_eq!(sprite.mirror_vertical().unwrap_err(), expected_error);
+    }
+  }
+
+  #[test]
+  fn is_fully_transparent_logic() {
+    let air_sprite = Sprite::new("---\n-a-", 'a', '-', '-').unwrap();
+    let visible_sprite = Sprite::new("---\n-a-", 'a', 'x', '-').unwrap();
+
+    assert!(air_sprite.is_fully_transparent());
+    assert!(!visible_sprite.is_fully_transparent());
+  }
+
+  #[cfg(test)]
+  mod from_ragged_logic {
+    use super::*;
+
+    #[test]
+    fn short_rows_are_padded() {
+      let sprite = Sprite::from_ragged("x\nxax\nx", 'a', 'x', '-').unwrap();
+
+      let expected_shape = "x--\nxax\nx--";
+      let expected_anchor_index = 4;
+
+      assert_eq!(sprite.shape, expected_shape);
+      assert_eq!(sprite.get_anchor_index(), expected_anchor_index);
+      assert_eq!(sprite.get_dimensions(), Rectangle::new(3, 3));
+    }
+
+    #[test]
+    fn anchor_index_is_after_padding() {
+      let sprite = Sprite::from_ragged("x\nxxa", 'a', 'x', '-').unwrap();
+
+      assert_eq!(sprite.get_anchor_index(), 5);
+      assert_eq!(sprite.get_anchor_as_coordinates(), (2, 1));
+    }
+
+    #[test]
+    fn new_still_rejects_ragged_shapes() {
+      let result = Sprite::new("x\nxax\nx", 'a', 'x', '-').unwrap_err();
+
+      assert_eq!(result, ModelError::NonRectangularShape);
+    }
+  }
+
+  #[cfg(test)]
+  mod overlay_logic {
+    use super::*;
+
+    #[test]
+    fn air_is_skipped() {
+      let body = Sprite::new("ooo\noao\nooo", 'a', 'o', '-').unwrap();
+      let hat = Sprite::new("a-^", 'a', '^', '-').unwrap();
+
+      let overlaid_sprite = body.overlay(&hat, (0, 0)).unwrap();
+
+      assert_eq!(overlaid_sprite.get_appearance(), "^o^\nooo\nooo");
+      assert_eq!(overlaid_sprite.get_anchor_index(), body.get_anchor_index());
+    }
+
+    #[test]
+    fn out_of_bounds_is_clipped() {
+      let body = Sprite::new("ooo\noao\nooo", 'a', 'o', '-').unwrap();
+      let sword = Sprite::new("a==", 'a', '+', '-').unwrap();
+
+      let overlaid_sprite = body.overlay(&sword, (1, 2)).unwrap();
+      let negative_overlaid_sprite = body.overlay(&sword, (-2, 0)).unwrap();
+
+      assert_eq!(overlaid_sprite.get_appearance(), "ooo\nooo\no+=");
+      assert_eq!(negative_overlaid_sprite.get_appearance(), "=oo\nooo\nooo");
+    }
+
+    #[test]
+    fn covering_the_anchor_replaces_its_replacement() {
+      let body = Sprite::new("ooo\noao\nooo", 'a', 'o', '-').unwrap();
+      let gem = Sprite::new("a", 'a', '*', '-').unwrap();
+
+      let overlaid_sprite = body.overlay(&gem, (1, 1)).unwrap();
+
+      assert_eq!(overlaid_sprite.get_appearance(), "ooo\no*o\nooo");
+      assert_eq!(overlaid_sprite.get_anchor_index(), 4);
+    }
+
+    #[test]
+    fn placing_an_anchor_character() {
+      let body = Sprite::new("ooo\noao\nooo", 'a', 'o', '-').unwrap();
+      let letter = Sprite::new("ba", 'b', 'x', '-').unwrap();
+
+      let result = body.overlay(&letter, (0, 0));
+
+      assert!(result.is_err());
+    }
+  }
+
+  #[cfg(test)]
+  mod crop_logic {
+    use super::*;
+
+    const SPRITE_SHEET: &str = "xax-x\nxxx-x\n-----\nxx-ax\nxx-xx";
+
+    #[test]
+    fn valid_input() {
+      let sprite_sheet = Sprite::new_unchecked(SPRITE_SHEET, 'a', 'o', '-', 1);
+
+      let first_sprite = sprite_sheet.crop((0, 0), Rectangle::new(3, 2)).unwrap();
+      let second_sprite = sprite_sheet.crop((3, 3), Rectangle::new(2, 2)).unwrap();
+
+      assert_eq!(first_sprite.get_appearance(), "xox\nxxx");
+      assert_eq!(first_sprite.get_anchor_index(), 1);
+      assert_eq!(first_sprite.air_character(), '-');
+      assert_eq!(second_sprite.get_appearance(), "ox\nxx");
+      assert_eq!(second_sprite.get_anchor_index(), 0);
+    }
+
+    #[test]
+    fn region_without_anchor() {
+      let sprite_sheet = Sprite::new_unchecked(SPRITE_SHEET, 'a', 'o', '-', 1);
+
+      let result = sprite_sheet.crop((0, 1), Rectangle::new(3, 2));
+
+      assert_eq!(result, Err(ModelError::NoAnchor));
+    }
+
+    #[test]
+    fn region_with_multiple_anchors() {
+      let sprite_sheet = Sprite::new_unchecked(SPRITE_SHEET, 'a', 'o', '-', 1);
+
+      let result = sprite_sheet.crop((0, 0), Rectangle::new(5, 5));
+
+      assert_eq!(result, Err(ModelError::MultipleAnchorsFound(vec![1, 18])));
+    }
+
+    #[test]
+    fn region_out_of_bounds() {
+      let sprite = Sprite::new("xxx\nxax\nxxx", 'a', 'o', '-').unwrap();
+
+      let wide_result = sprite.crop((1, 0), Rectangle::new(3, 1));
+      let tall_result = sprite.crop((0, 2), Rectangle::new(1, 2));
+
+      assert_eq!(wide_result, Err(ModelError::SpriteCropOutOfBounds));
+      assert_eq!(tall_result, Err(ModelError::SpriteCropOutOfBounds));
+    }
+  }
+
+  #[cfg(test)]
+  mod rotate_90_logic {
+    use super::*;
+
+    #[test]
+    fn clockwise() {
+      let mut sprite = Sprite::new("a1\n23\n45", 'a', 'x', '-').unwrap();
+
+      let expected_shape = "42a\n531";
+
+      sprite.rotate_90(true).unwrap();
+
+      assert_eq!(sprite.shape, expected_shape);
+      assert_eq!(sprite.get_dimensions(), Rectangle::new(3, 2));
+      assert_eq!(sprite.get_anchor_index(), 2);
+    }
+
+    #[test]
+    fn counter_clockwise() {
+      let mut sprite = Sprite::new("a1\n23\n45", 'a', 'x', '-').unwrap();
+
+      let expected_shape = "135\na24";
+
+      sprite.rotate_90(false).unwrap();
+
+      assert_eq!(sprite.shape, expected_shape);
+      assert_eq!(sprite.get_dimensions(), Rectangle::new(3, 2));
+      assert_eq!(sprite.get_anchor_index(), 3);
+    }
+
+    #[test]
+    fn full_turn_restores_the_sprite() {
+      let original_sprite = Sprite::new("a1\n23\n45", 'a', 'x', '-').unwrap();
+      let mut sprite = original_sprite.clone();
+
+      (0..4).for_each(|_| sprite.rotate_90(true).unwrap());
+      sprite.rotate_90(false).unwrap();
+      sprite.rotate_90(true).unwrap();
+
+      assert_eq!(sprite, original_sprite);
+    }
+
+    #[test]
+    fn invalid_shape() {
+      let mut sprite = Sprite::new_unchecked("xa\nx", 'a', 'x', '-', 1);
+
+      assert_eq!(
+        sprite.rotate_90(true).unwrap_err(),
+        ModelError::NonRectangularShape
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod trim_air_border_logic {
+    use super::*;
+
+    #[test]
+    fn air_is_trimmed() {
+      let mut sprite = Sprite::new("-----\n-xa--\n--x--\n-----", 'a', 'o', '-').unwrap();
+
+      sprite.trim_air_border();
+
+      assert_eq!(sprite.get_appearance(), "xo\n-x");
+      assert_eq!(sprite.get_anchor_index(), 1);
+    }
+
+    #[test]
+    fn anchor_edges_are_kept() {
+      // The anchor is replaced with air, making it the only thing in its row and column.
+      let mut sprite = Sprite::new("a---\n----\n--xx", 'a', '-', '-').unwrap();
+
+      sprite.trim_air_border();
+
+      assert_eq!(sprite.get_appearance(), "----\n----\n--xx");
+      assert_eq!(sprite.get_anchor_index(), 0);
+    }
+
+    #[test]
+    fn fully_transparent_sprite() {
+      let mut sprite = Sprite::new("---\n-a-\n---", 'a', '-', '-').unwrap();
+
+      sprite.trim_air_border();
+
+      assert_eq!(sprite.get_appearance(), "-");
+      assert_eq!(sprite.get_anchor_index(), 0);
+    }
+
+    #[test]
+    fn nothing_to_trim() {
+      let mut sprite = Sprite::new("xxx\nxax\nxxx", 'a', 'o', '-').unwrap();
+      let original_sprite = sprite.clone();
+
+      sprite.trim_air_border();
+
+      assert_eq!(sprite, original_sprite);
+    }
+  }
 }
@@ -10,9 +10,21 @@ use engine_math::{coordinates::*, hasher, rectangle::*};
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs::File;
-use std::path::Path;
-use std::sync::{Arc, Mutex};
-
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The path of every model file that failed to load, along with the reason it failed.
+pub type FailedModelFiles = Vec<(PathBuf, ModelError)>;
+
+/// A shared handle to a model's data.
+///
+/// The getters lock the model and panic if the lock was poisoned by a thread that panicked while
+/// holding it.
+/// The ``try_`` getters, built on [`with_inner_timeout`](ModelData::with_inner_timeout), instead
+/// give up after a timeout and recover from a poisoned lock.
 #[derive(Debug, Clone)]
 pub struct ModelData {
   inner: Arc<Mutex<InternalModelData>>,
@@ -28,6 +40,18 @@ pub struct InternalModelData {
   strata: Strata,
   appearance: Arc<Mutex<ModelAppearance>>,
   hitbox: Hitbox,
+  /// Shifts the hitbox's position relative to the sprite's anchor.
+  hitbox_anchor_offset: (isize, isize),
+  /// The layers this model exists on when checking for collisions.
+  collision_layer: u32,
+  /// The layers this model can collide with.
+  collision_mask: u32,
+  /// When assigned, the printer draws this character in place of the sprite's air.
+  render_air_override: Option<char>,
+  /// When false, the printer skips this model when building a frame.
+  visible: bool,
+  /// The fraction of a cell the model has built up from fractional movements.
+  movement_remainder: (f32, f32),
   tags: HashSet<String>,
 }
 
@@ -73,26 +97,93 @@ impl ModelData {
     model_file_path: &Path,
     frame_position: (usize, usize),
   ) -> Result<Self, ModelError> {
+    let model_file = Self::open_model_file(model_file_path)?;
+    let model_directory = model_file_path.parent().unwrap_or(Path::new(""));
+
+    ModelParser::parse_relative_to(model_file, frame_position, model_directory)
+  }
+
+  /// Creates a model from every ``.model`` file in the given directory, placing each of them at the
+  /// given position.
+  ///
+  /// Subdirectories and files without the ``.model`` extension are skipped.
+  /// A model file failing to load doesn't stop the rest from loading. Instead, the path of the file
+  /// is returned alongside the error it caused.
+  ///
+  /// Returns the loaded models and the list of files that failed to load, both ordered by path.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the directory couldn't be read.
+  pub fn from_directory(
+    directory_path: &Path,
+    frame_position: (usize, usize),
+  ) -> Result<(Vec<Self>, FailedModelFiles), ModelError> {
+    let directory_entries = match std::fs::read_dir(directory_path) {
+      Ok(directory_entries) => directory_entries,
+      Err(error) => return Err(ModelError::Other(error.to_string())),
+    };
+
+    let mut model_file_paths: Vec<PathBuf> = directory_entries
+      .filter_map(|entry| Some(entry.ok()?.path()))
+      .filter(|path| path.is_file() && path.extension() == Some(OsStr::new("model")))
+      .collect();
+    model_file_paths.sort();
+
+    let mut models = vec![];
+    let mut failed_models = vec![];
+
+    for model_file_path in model_file_paths {
+      match Self::from_file(&model_file_path, frame_position) {
+        Ok(model) => models.push(model),
+        Err(error) => failed_models.push((model_file_path, error)),
+      }
+    }
+
+    Ok((models, failed_models))
+  }
+
+  /// Opens the given ``.model`` file.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the path doesn't lead to a ``.model`` file.
+  /// - Returns an error when the file doesn't exist.
+  fn open_model_file(model_file_path: &Path) -> Result<File, ModelError> {
     if model_file_path.extension() != Some(OsStr::new("model")) {
       return Err(ModelCreationError::NonModelFile.into());
     }
-    let model_file = File::open(model_file_path);
 
-    match model_file {
-      Ok(file) => ModelParser::parse(file, frame_position),
-      Err(_) => {
-        let file_path = model_file_path
-          .file_name()
-          // Unwrap and convert the OsStr to an OsString.
-          .map(|path_string| path_string.to_owned());
+    File::open(model_file_path).map_err(|_| {
+      let file_path = model_file_path
+        .file_name()
+        // Unwrap and convert the OsStr to an OsString.
+        .map(|path_string| path_string.to_owned());
 
-        let error = ModelCreationError::ModelFileDoesntExist(file_path);
+      ModelError::ModelCreationError(ModelCreationError::ModelFileDoesntExist(file_path))
+    })
+  }
 
-        Err(ModelError::ModelCreationError(error))
-      }
-    }
+  /// Creates a model from the contents of a model file, read from the given reader.
+  ///
+  /// This allows models to be created from files embedded in a binary with `include_str!()`,
+  /// or from any other source that isn't the filesystem.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the reader failed to be read.
+  /// - Returns an error when the model file was build incorrectly. [`Errors when parsing model files`](crate::models::errors::ModelCreationError).
+  pub fn from_reader<R: Read>(
+    reader: R,
+    frame_position: (usize, usize),
+  ) -> Result<Self, ModelError> {
+    ModelParser::parse(reader, frame_position)
   }
 
+  /// Creates a model from its saved form.
+  ///
+  /// Runtime-only state, such as visibility and collision layers, isn't saved and starts at its
+  /// default. Refer to [`StoredDisplayModel`](StoredDisplayModel) for the full list.
   // TODO: List the errors.
   pub fn from_stored(mut stored_model: StoredDisplayModel) -> Result<Self, ModelError> {
     if !stored_model.repair_missing_fields() {
@@ -131,6 +222,12 @@ impl ModelData {
       strata: stored_model.strata.unwrap_or(Strata(0)),
       appearance: Arc::new(Mutex::new(stored_model.appearance_data.unwrap())),
       hitbox: stored_model.hitbox.unwrap(),
+      hitbox_anchor_offset: (0, 0),
+      collision_layer: u32::MAX,
+      collision_mask: u32::MAX,
+      render_air_override: None,
+      visible: true,
+      movement_remainder: (0.0, 0.0),
       tags: stored_model.tags.unwrap(),
     };
 
@@ -139,10 +236,102 @@ impl ModelData {
     })
   }
 
+  /// Converts the model into its saved form, dropping any runtime-only state.
   pub fn to_stored(self) -> StoredDisplayModel {
     StoredDisplayModel::new(self)
   }
 
+  /// Creates an independent copy of this model with a new unique hash.
+  ///
+  /// The derived `Clone` only copies the handle to the model, meaning both copies share the same data
+  /// and changing one changes the other.
+  /// This instead copies everything about the model, including its appearance, into a new model
+  /// that can be changed separately, such as when spawning many models from one template.
+  pub fn clone_deep(&self) -> ModelData {
+    let mut internal_data = self.inner.lock().unwrap().clone();
+    let appearance = internal_data.appearance.lock().unwrap().clone();
+
+    internal_data.appearance = Arc::new(Mutex::new(appearance));
+    internal_data.unique_hash = hasher::get_unique_hash();
+
+    Self {
+      inner: Arc::new(Mutex::new(internal_data)),
+    }
+  }
+
+  /// Runs the given function on the model's internal data, without blocking past the timeout.
+  ///
+  /// The lock is retried until it's acquired or the timeout has passed, meaning a lock held by
+  /// another thread for too long can't deadlock the caller.
+  /// A lock poisoned by a thread that panicked while holding it is recovered instead of panicking.
+  ///
+  /// None is returned if the lock couldn't be acquired before the timeout.
+  pub fn with_inner_timeout<T>(
+    &self,
+    timeout: Duration,
+    function: impl FnOnce(&mut InternalModelData) -> T,
+  ) -> Option<T> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+      match self.inner.try_lock() {
+        Ok(mut internal_data) => return Some(function(&mut internal_data)),
+        Err(TryLockError::Poisoned(poisoned)) => return Some(function(&mut poisoned.into_inner())),
+        Err(TryLockError::WouldBlock) if Instant::now() >= deadline => return None,
+        Err(TryLockError::WouldBlock) => thread::yield_now(),
+      }
+    }
+  }
+
+  /// Returns a copy of the model's stored unique hash.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_hash(&self, timeout: Duration) -> Option<u64> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_hash())
+  }
+
+  /// Returns a copy of the model's assigned name.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_name(&self, timeout: Duration) -> Option<String> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_name().to_owned())
+  }
+
+  /// Returns a copy of the current top left position of the model in the world.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_frame_position(&self, timeout: Duration) -> Option<usize> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_frame_position())
+  }
+
+  /// Returns a copy of the currently stored strata for the model.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_strata(&self, timeout: Duration) -> Option<Strata> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_strata())
+  }
+
+  /// Returns a copy of the current hitbox.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_hitbox(&self, timeout: Duration) -> Option<Hitbox> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_hitbox().clone())
+  }
+
+  /// Returns true if the model will be drawn when rendering.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_is_visible(&self, timeout: Duration) -> Option<bool> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.is_visible())
+  }
+
+  /// Returns a copy of the tags for this model.
+  ///
+  /// None is returned if the model couldn't be accessed before the timeout.
+  pub fn try_get_tags(&self, timeout: Duration) -> Option<HashSet<String>> {
+    self.with_inner_timeout(timeout, |internal_data| internal_data.get_tags().clone())
+  }
+
   /// Returns a copy of the model's stored unique hash.
   pub fn get_hash(&self) -> u64 {
     self.inner.lock().unwrap().unique_hash
@@ -166,7 +355,7 @@ impl ModelData {
   /// Returns the world position of the model, this is where the model's sprite anchor is located.
   pub fn get_world_position(&self) -> (isize, isize) {
     let frame_position = self.get_frame_position();
-    let screen_width = CONFIG.grid_width as usize + 1;
+    let screen_width = CONFIG.frame_width();
     let model_sprite_coordiates = self.get_sprite().get_anchor_as_coordinates();
 
     frame_position
@@ -175,6 +364,35 @@ impl ModelData {
       .subtract((1, 0)) // Remove 1 from the x-axis to stop accounting for new lines.
   }
 
+  /// Returns the top left world coordinate of the model's sprite, along with the sprite's dimensions.
+  ///
+  /// This is the visual extent of the model, which can differ from the bounds of its hitbox.
+  pub fn bounding_box(&self) -> ((isize, isize), Rectangle) {
+    let sprite = self.get_sprite();
+    let (world_x, world_y) = self.get_world_position();
+    let (anchor_x, anchor_y) = sprite.get_anchor_as_coordinates();
+
+    (
+      (world_x - anchor_x as isize, world_y - anchor_y as isize),
+      sprite.get_dimensions(),
+    )
+  }
+
+  /// Returns true if any part of the model's sprite is within the grid defined in the config.
+  ///
+  /// This is a cheap check for skipping work on models that can't be seen, such as rendering or AI updates.
+  /// Models with an empty sprite are never on screen.
+  pub fn on_screen(&self) -> bool {
+    let ((x, y), dimensions) = self.bounding_box();
+    let (grid_width, grid_height) = (CONFIG.grid_width as isize, CONFIG.grid_height as isize);
+
+    dimensions.area() != 0
+      && x < grid_width
+      && y < grid_height
+      && x + dimensions.x as isize > 0
+      && y + dimensions.y as isize > 0
+  }
+
   /// Returns a copy of the currently stored strata for the model.
   pub fn get_strata(&self) -> Strata {
     self.inner.lock().unwrap().strata
@@ -182,6 +400,9 @@ impl ModelData {
 
   /// Replaces the strata with the new one passed in.
   ///
+  /// For models that are already in a world, use ``ModelManager::change_model_strata`` instead,
+  /// which also moves the model to its new strata in the world.
+  ///
   /// # Errors
   ///
   /// - When the new strata passed in was in an impossible range
@@ -215,6 +436,55 @@ impl ModelData {
       .clone()
   }
 
+  /// Returns the current appearance of this model as a string, with the anchor replaced.
+  ///
+  /// Unlike calling `get_sprite().get_appearance()`, this doesn't copy the whole
+  /// [`Sprite`](crate::models::model_appearance::sprites::Sprite).
+  pub fn get_appearance_string(&self) -> String {
+    self
+      .get_appearance_immutably()
+      .lock()
+      .unwrap()
+      .get_appearance()
+      .get_appearance()
+  }
+
+  /// Replaces the default sprite of this model with the appearance from the given ``.model`` file.
+  ///
+  /// Only the ``Skin`` and ``Appearance`` sections of the file are used, everything else about the
+  /// model such as its hash, position, hitbox, and tags are kept as they are.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the path doesn't lead to an existing ``.model`` file.
+  /// - Returns an error when the sprite data within the file is missing or invalid.
+  /// - Returns an error when the anchor of the new sprite isn't in the same position as the current one.
+  pub fn reload_appearance_from_file(&mut self, model_file_path: &Path) -> Result<(), ModelError> {
+    let model_file = Self::open_model_file(model_file_path)?;
+    let model_directory = model_file_path.parent().unwrap_or(Path::new(""));
+    let new_sprite = ModelParser::parse_sprite_relative_to(model_file, model_directory)?;
+
+    let appearance = self.get_appearance_data();
+    let mut appearance = appearance.lock().unwrap();
+
+    if appearance
+      .get_default_appearance()
+      .get_anchor_as_coordinates()
+      != new_sprite.get_anchor_as_coordinates()
+    {
+      return Err(ModelError::SpriteAnchorMoved);
+    }
+
+    appearance.update_default_sprite(new_sprite);
+
+    Ok(())
+  }
+
+  /// Returns true if the model's current appearance is made up entirely of air.
+  pub fn is_invisible(&self) -> bool {
+    self.get_sprite().is_fully_transparent()
+  }
+
   /// Returns the current dimensions of the hitbox.
   pub fn get_hitbox_dimensions(&self) -> Rectangle {
     *self.inner.lock().unwrap().hitbox.get_hitbox_dimensions()
@@ -230,6 +500,18 @@ impl ModelData {
     std::mem::replace(&mut self.inner.lock().unwrap().hitbox, new_hitbox)
   }
 
+  /// Scales the width and height of the model's hitbox by the given factors, keeping its anchor in place.
+  ///
+  /// Refer to [`Hitbox::scale`] for how the hitbox is resized.
+  pub fn scale_hitbox(&mut self, width_factor: f32, height_factor: f32) {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .hitbox
+      .scale(width_factor, height_factor);
+  }
+
   /// Returns a reference to the [`model's appearance]`(crate::model_data::model_appearance::ModelAppearance).
   // TODO: mention how to animate a model through the screen or a model_manager.
   pub fn get_appearance_data(&mut self) -> Arc<Mutex<ModelAppearance>> {
@@ -252,18 +534,134 @@ impl ModelData {
     internal_data.hitbox.get_hitbox_dimensions().area() == 0
   }
 
+  /// Returns the difference between the hitbox's anchor and the sprite's anchor.
+  ///
+  /// This includes the offset assigned through [`set_hitbox_anchor_offset`](ModelData::set_hitbox_anchor_offset).
   pub fn sprite_to_hitbox_anchor_difference(&self) -> (isize, isize) {
     let sprite = self.get_sprite();
     let sprite_anchor = sprite.get_anchor_as_coordinates();
     drop(sprite);
-    let hitbox_anchor = self
-      .inner
+    let internal_data = self.inner.lock().unwrap();
+    let hitbox_anchor = internal_data.hitbox.get_anchor_as_coordinates();
+    let (offset_x, offset_y) = internal_data.hitbox_anchor_offset;
+
+    let (difference_x, difference_y) = hitbox_anchor.subtract(sprite_anchor);
+
+    (difference_x + offset_x, difference_y + offset_y)
+  }
+
+  /// Returns everything that decides where the model's hitbox is placed in the world.
+  ///
+  /// Unlike calling each getter, this only locks the model once and doesn't copy the sprite.
+  pub fn hitbox_placement(&self) -> HitboxPlacement {
+    let internal_data = self.inner.lock().unwrap();
+    let frame_position = internal_data.position_in_frame;
+    let hitbox = internal_data.hitbox.clone();
+    let hitbox_anchor_offset = internal_data.hitbox_anchor_offset;
+    let appearance = internal_data.appearance.clone();
+    // The appearance is always locked after the model has been released.
+    drop(internal_data);
+
+    let sprite_anchor = appearance
       .lock()
       .unwrap()
-      .hitbox
+      .get_appearance()
       .get_anchor_as_coordinates();
 
-    hitbox_anchor.subtract(sprite_anchor)
+    HitboxPlacement {
+      frame_position,
+      hitbox,
+      hitbox_anchor_offset,
+      sprite_anchor,
+    }
+  }
+
+  /// Shifts the position of the hitbox relative to the sprite's anchor, without changing the sprite.
+  ///
+  /// An offset of (0, 0) places the hitbox where the model's sprite and hitbox anchors say it should be.
+  pub fn set_hitbox_anchor_offset(&mut self, offset: (isize, isize)) {
+    self.inner.lock().unwrap().hitbox_anchor_offset = offset;
+  }
+
+  /// Returns the offset of the hitbox relative to the sprite's anchor.
+  pub fn get_hitbox_anchor_offset(&self) -> (isize, isize) {
+    self.inner.lock().unwrap().hitbox_anchor_offset
+  }
+
+  /// Assigns the bitmask of layers this model exists on when checking for collisions.
+  ///
+  /// Defaults to every layer.
+  pub fn set_collision_layer(&mut self, layer: u32) {
+    self.inner.lock().unwrap().collision_layer = layer;
+  }
+
+  /// Returns the bitmask of layers this model exists on when checking for collisions.
+  pub fn get_collision_layer(&self) -> u32 {
+    self.inner.lock().unwrap().collision_layer
+  }
+
+  /// Assigns the bitmask of layers this model is able to collide with.
+  ///
+  /// Defaults to every layer.
+  pub fn set_collision_mask(&mut self, mask: u32) {
+    self.inner.lock().unwrap().collision_mask = mask;
+  }
+
+  /// Returns the bitmask of layers this model is able to collide with.
+  pub fn get_collision_mask(&self) -> u32 {
+    self.inner.lock().unwrap().collision_mask
+  }
+
+  /// Assigns a character to be drawn in place of the air in the model's sprite when rendering.
+  ///
+  /// This only changes how the model is displayed. Collisions and the model's appearance
+  /// still treat those spaces as air.
+  /// Passing in None restores the air's transparency.
+  pub fn set_render_air_override(&mut self, character: Option<char>) {
+    self.inner.lock().unwrap().render_air_override = character;
+  }
+
+  /// Returns the character drawn in place of the sprite's air when rendering, if one was assigned.
+  pub fn get_render_air_override(&self) -> Option<char> {
+    self.inner.lock().unwrap().render_air_override
+  }
+
+  /// Assigns the fraction of a cell the model has built up from fractional movements.
+  pub fn set_movement_remainder(&mut self, remainder: (f32, f32)) {
+    self.inner.lock().unwrap().movement_remainder = remainder;
+  }
+
+  /// Returns the fraction of a cell the model has built up from fractional movements.
+  pub fn get_movement_remainder(&self) -> (f32, f32) {
+    self.inner.lock().unwrap().movement_remainder
+  }
+
+  /// Shows or hides the model.
+  ///
+  /// A hidden model is skipped when rendering, but still exists in the world and can still collide.
+  pub fn set_visible(&mut self, visible: bool) {
+    self.inner.lock().unwrap().visible = visible;
+  }
+
+  /// Returns true if the model will be drawn when rendering.
+  pub fn is_visible(&self) -> bool {
+    self.inner.lock().unwrap().visible
+  }
+
+  /// Returns the straight line distance between the world positions of this model and the other.
+  pub fn distance_to(&self, other: &ModelData) -> f64 {
+    let (x, y) = self.get_world_position();
+    let (other_x, other_y) = other.get_world_position();
+
+    ((x - other_x) as f64).hypot((y - other_y) as f64)
+  }
+
+  /// Returns true if both models' layers are within the other model's collision mask.
+  pub fn collision_layers_overlap(&self, other: &ModelData) -> bool {
+    let (self_layer, self_mask) = (self.get_collision_layer(), self.get_collision_mask());
+    let (other_layer, other_mask) = (other.get_collision_layer(), other.get_collision_mask());
+
+    self_layer & other_mask != 0 && other_layer & self_mask != 0
   }
 
   /// Returns the top left of the model in the frame based on the given position.
@@ -282,7 +680,7 @@ impl ModelData {
   }
 
   fn caluculate_top_left_index(sprite: &Sprite, position: (usize, usize)) -> Option<usize> {
-    let screen_size = CONFIG.grid_width as usize + 1;
+    let screen_size = CONFIG.frame_width();
     let sprite_anchor = sprite.get_anchor_as_coordinates();
 
     let position_in_coordinates = position.subtract(sprite_anchor);
@@ -319,6 +717,20 @@ impl ModelData {
     });
   }
 
+  /// Removes each of the given tags from the model, ignoring any the model doesn't have.
+  pub fn remove_tags<S: AsRef<str>>(&mut self, tags: Vec<S>) {
+    let mut inner = self.inner.lock().unwrap();
+
+    tags.iter().for_each(|tag| {
+      inner.tags.remove(tag.as_ref());
+    });
+  }
+
+  /// Removes every tag from the model.
+  pub fn clear_tags(&mut self) {
+    self.inner.lock().unwrap().tags.clear();
+  }
+
   /// Returns a copy of the tags for this model.
   pub fn get_tags(&self) -> HashSet<String> {
     self.inner.lock().unwrap().tags.clone()
@@ -359,9 +771,60 @@ impl InternalModelData {
       appearance: model_appearance,
       position_in_frame,
       hitbox,
+      hitbox_anchor_offset: (0, 0),
+      collision_layer: u32::MAX,
+      collision_mask: u32::MAX,
+      render_air_override: None,
+      visible: true,
+      movement_remainder: (0.0, 0.0),
       tags: HashSet::new(),
     })
   }
+
+  /// Returns the model's unique hash.
+  pub fn get_hash(&self) -> u64 {
+    self.unique_hash
+  }
+
+  /// Returns the model's assigned name.
+  pub fn get_name(&self) -> &str {
+    &self.assigned_name
+  }
+
+  /// Returns the top left position of the model in the world.
+  pub fn get_frame_position(&self) -> usize {
+    self.position_in_frame
+  }
+
+  /// Changes the placement of the model to the new position.
+  pub fn change_position(&mut self, new_position: usize) {
+    self.position_in_frame = new_position;
+  }
+
+  /// Returns the strata the model is on.
+  pub fn get_strata(&self) -> Strata {
+    self.strata
+  }
+
+  /// Returns a reference to the model's hitbox.
+  pub fn get_hitbox(&self) -> &Hitbox {
+    &self.hitbox
+  }
+
+  /// Returns true if the model will be drawn when rendering.
+  pub fn is_visible(&self) -> bool {
+    self.visible
+  }
+
+  /// Changes whether the model will be drawn when rendering.
+  pub fn set_visible(&mut self, visible: bool) {
+    self.visible = visible;
+  }
+
+  /// Returns a reference to the model's tags.
+  pub fn get_tags(&self) -> &HashSet<String> {
+    &self.tags
+  }
 }
 
 impl PartialEq for ModelData {
@@ -406,7 +869,7 @@ mod tests {
       let mut model = TestingData::new_test_model(WORLD_POSITION);
       let new_position = Coordinates::from_isize(WORLD_POSITION.subtract((1, 1)))
         .unwrap()
-        .coordinates_to_index(CONFIG.grid_width as usize + 1);
+        .coordinates_to_index(CONFIG.frame_width());
 
       model.change_position(new_position);
 
@@ -431,6 +894,138 @@ mod tests {
     assert_eq!(model.get_world_position(), WORLD_POSITION.to_isize());
   }
 
+  #[test]
+  fn bounding_box_is_sprite_extent() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let expected_bounding_box = ((8, 9), Rectangle::new(5, 3));
+
+    assert_eq!(model.bounding_box(), expected_bounding_box);
+  }
+
+  #[test]
+  fn scale_hitbox_keeps_anchor_in_place() {
+    let mut model = TestingData::new_test_model(WORLD_POSITION);
+
+    model.scale_hitbox(2.0, 1.0);
+
+    assert_eq!(model.get_hitbox_dimensions(), Rectangle::new(10, 3));
+    assert_eq!(model.get_hitbox().get_anchor_as_coordinates(), (5, 1));
+    assert_eq!(model.sprite_to_hitbox_anchor_difference(), (3, 0));
+  }
+
+  #[cfg(test)]
+  mod with_inner_timeout_logic {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_millis(20);
+
+    #[test]
+    fn unlocked_model() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+
+      assert_eq!(model.try_get_hash(TIMEOUT), Some(model.get_hash()));
+      assert_eq!(
+        model.try_get_frame_position(TIMEOUT),
+        Some(model.get_frame_position())
+      );
+      assert_eq!(model.try_get_strata(TIMEOUT), Some(model.get_strata()));
+      assert_eq!(model.try_get_hitbox(TIMEOUT), Some(model.get_hitbox()));
+    }
+
+    #[test]
+    fn model_locked_past_timeout() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let _lock = model.inner.lock().unwrap();
+
+      let start = Instant::now();
+      let result = model.try_get_frame_position(TIMEOUT);
+
+      assert!(result.is_none());
+      assert!(start.elapsed() >= TIMEOUT);
+    }
+
+    #[test]
+    fn lock_released_before_timeout() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let locked_model = model.clone();
+      let (locked_sender, locked_receiver) = std::sync::mpsc::channel();
+
+      let handle = thread::spawn(move || {
+        let _lock = locked_model.inner.lock().unwrap();
+        locked_sender.send(()).unwrap();
+        thread::sleep(Duration::from_millis(10));
+      });
+      locked_receiver.recv().unwrap();
+
+      let result = model.try_get_hash(Duration::from_secs(5));
+
+      handle.join().unwrap();
+      assert_eq!(result, Some(model.get_hash()));
+    }
+
+    #[test]
+    fn model_is_changed_through_the_function() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+
+      let result = model.with_inner_timeout(TIMEOUT, |internal_data| {
+        internal_data.change_position(0);
+        internal_data.set_visible(false);
+      });
+
+      assert!(result.is_some());
+      assert_eq!(model.get_frame_position(), 0);
+      assert!(!model.is_visible());
+    }
+
+    #[test]
+    fn poisoned_lock_is_recovered() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let poisoning_model = model.clone();
+
+      let _ = thread::spawn(move || {
+        let _lock = poisoning_model.inner.lock().unwrap();
+        panic!("Poisoning the lock.");
+      })
+      .join();
+
+      assert!(model.inner.is_poisoned());
+      assert_eq!(model.try_is_visible(TIMEOUT), Some(true));
+    }
+  }
+
+  #[cfg(test)]
+  mod on_screen_logic {
+    use super::*;
+
+    #[test]
+    fn model_in_grid() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+
+      assert!(model.on_screen());
+    }
+
+    #[test]
+    fn model_past_the_bottom_edge() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      let below_grid = (CONFIG.grid_height as usize + 5) * CONFIG.frame_width();
+
+      model.change_position(below_grid);
+
+      assert!(!model.on_screen());
+    }
+
+    #[test]
+    fn model_partially_on_the_bottom_edge() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      // Only the top row of the model is within the grid.
+      let last_row = (CONFIG.grid_height as usize - 1) * CONFIG.frame_width() + 10;
+
+      model.change_position(last_row);
+
+      assert!(model.on_screen());
+    }
+  }
+
   #[cfg(test)]
   mod calculate_top_left_index_from_logic {
     use super::*;
@@ -440,7 +1035,7 @@ mod tests {
       let model = TestingData::new_test_model(WORLD_POSITION);
       let position = (5, 5);
 
-      let screen_size = CONFIG.grid_width as usize + 1;
+      let screen_size = CONFIG.frame_width();
       let model_sprite_anchor_index = model.get_sprite().get_anchor_as_coordinates();
       // Add 1 to account for new lines
       let expected_index = 1
@@ -462,4 +1057,229 @@ mod tests {
       model.calculate_top_left_index_from(position).unwrap();
     }
   }
+
+  #[test]
+  fn from_reader_logic() {
+    let model_file_contents = include_str!("../../../tests/models/test_square.model");
+    let file_model = TestingData::new_test_model(WORLD_POSITION);
+
+    let reader_model =
+      ModelData::from_reader(model_file_contents.as_bytes(), WORLD_POSITION).unwrap();
+
+    assert_eq!(reader_model.get_name(), file_model.get_name());
+    assert_eq!(reader_model.get_sprite(), file_model.get_sprite());
+    assert_eq!(reader_model.get_hitbox(), file_model.get_hitbox());
+    assert_eq!(reader_model.get_world_position(), WORLD_POSITION.to_isize());
+  }
+
+  #[test]
+  fn from_file_appearance_path_is_relative_to_model() {
+    let model_path = Path::new("../tests/models/test_square_external_appearance.model");
+    let file_model = TestingData::new_test_model(WORLD_POSITION);
+
+    let external_model = ModelData::from_file(model_path, WORLD_POSITION).unwrap();
+
+    assert_eq!(external_model.get_sprite(), file_model.get_sprite());
+  }
+
+  #[test]
+  fn from_directory_logic() {
+    let directory_path = Path::new("../tests/models");
+    let model_file_count = std::fs::read_dir(directory_path)
+      .unwrap()
+      .filter(|entry| entry.as_ref().unwrap().path().extension() == Some(OsStr::new("model")))
+      .count();
+
+    let (models, failed_models) =
+      ModelData::from_directory(directory_path, WORLD_POSITION).unwrap();
+
+    assert_eq!(models.len() + failed_models.len(), model_file_count);
+    assert!(models.iter().any(|model| model.get_name() == "Test_Square"));
+    assert!(failed_models
+      .iter()
+      .any(|(path, _)| path.ends_with("empty_file.model")));
+  }
+
+  #[test]
+  fn from_directory_missing_directory() {
+    let result = ModelData::from_directory(Path::new("../tests/missing_directory"), WORLD_POSITION);
+
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn get_appearance_string_logic() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+
+    let appearance = model.get_appearance_string();
+
+    assert_eq!(appearance, model.get_sprite().get_appearance());
+  }
+
+  #[cfg(test)]
+  mod reload_appearance_from_file_logic {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      model.add_tags(vec!["Test".to_string()]);
+      let hash = model.get_hash();
+      let hitbox = model.get_hitbox();
+      let reskin_path = Path::new("../tests/models/test_square_reskin.model");
+
+      model.reload_appearance_from_file(reskin_path).unwrap();
+
+      assert_eq!(model.get_appearance_string(), "ooooo\nooxoo\nooooo");
+      assert_eq!(model.get_hash(), hash);
+      assert_eq!(model.get_hitbox(), hitbox);
+      assert_eq!(model.get_world_position(), WORLD_POSITION.to_isize());
+      assert!(model.contains_tag("Test"));
+    }
+
+    #[test]
+    fn anchor_moved() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      let expected_appearance = model.get_appearance_string();
+      let shifted_path = Path::new("../tests/models/test_square_shifted_anchor.model");
+
+      let result = model.reload_appearance_from_file(shifted_path);
+
+      assert_eq!(result, Err(ModelError::SpriteAnchorMoved));
+      assert_eq!(model.get_appearance_string(), expected_appearance);
+    }
+
+    #[test]
+    fn missing_file() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      let missing_path = Path::new("../tests/models/does_not_exist.model");
+
+      let result = model.reload_appearance_from_file(missing_path);
+
+      assert!(matches!(
+        result,
+        Err(ModelError::ModelCreationError(
+          ModelCreationError::ModelFileDoesntExist(_)
+        ))
+      ));
+    }
+  }
+
+  #[test]
+  fn distance_to_logic() {
+    let model = TestingData::new_test_model((10, 10));
+    let other_model = TestingData::new_test_model((13, 14));
+
+    assert_eq!(model.distance_to(&other_model), 5.0);
+    assert_eq!(other_model.distance_to(&model), 5.0);
+    assert_eq!(model.distance_to(&model), 0.0);
+  }
+
+  #[test]
+  fn clone_deep_has_separate_animation_clock() {
+    let (template, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+    template
+      .clone()
+      .get_appearance_data()
+      .lock()
+      .unwrap()
+      .queue_model_animation(TestingData::ANIMATION_NAME)
+      .unwrap();
+
+    let copy = template.clone_deep();
+    copy
+      .clone()
+      .get_appearance_data()
+      .lock()
+      .unwrap()
+      .pause_animations();
+    event_sync::EventSync::new(CONFIG.tick_duration)
+      .wait_for_x_ticks(2)
+      .unwrap();
+
+    let template_appearance = template.clone().get_appearance_data();
+    let template_appearance = template_appearance.lock().unwrap();
+
+    assert!(!template_appearance.animations_are_paused());
+    assert!(template_appearance.current_frame_index().unwrap() > 0);
+  }
+
+  #[test]
+  fn clone_deep_logic() {
+    let mut template = TestingData::new_test_model(WORLD_POSITION);
+    template.add_tags(vec!["Enemy".to_string()]);
+
+    let mut copy = template.clone_deep();
+    copy.change_position(0);
+    copy.add_tags(vec!["Copy".to_string()]);
+    copy
+      .get_appearance_data()
+      .lock()
+      .unwrap()
+      .update_default_sprite(Sprite::new("a", 'a', 'x', '-').unwrap());
+
+    assert_ne!(copy.get_hash(), template.get_hash());
+    assert_eq!(copy.get_name(), template.get_name());
+    assert!(copy.contains_tag("Enemy"));
+    assert!(!template.contains_tag("Copy"));
+    assert_eq!(template.get_world_position(), WORLD_POSITION.to_isize());
+    assert_eq!(
+      template.get_appearance_string(),
+      TestingData::new_test_model(WORLD_POSITION).get_appearance_string()
+    );
+  }
+
+  #[test]
+  fn set_visible_logic() {
+    let mut model = TestingData::new_test_model(WORLD_POSITION);
+
+    assert!(model.is_visible());
+
+    model.set_visible(false);
+
+    assert!(!model.is_visible());
+  }
+
+  #[test]
+  fn is_invisible_logic() {
+    let visible_model = TestingData::new_test_model(WORLD_POSITION);
+    let air_sprite = Sprite::new("---\n-a-", 'a', '-', '-').unwrap();
+    let hitbox = Hitbox::new(Rectangle::new(3, 2), 4);
+    let invisible_model = ModelData::new(
+      WORLD_POSITION,
+      air_sprite,
+      hitbox,
+      Strata(0),
+      "Invisible".to_string(),
+    )
+    .unwrap();
+
+    assert!(!visible_model.is_invisible());
+    assert!(invisible_model.is_invisible());
+  }
+
+  #[cfg(test)]
+  mod tag_logic {
+    use super::*;
+
+    #[test]
+    fn remove_tags() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      model.add_tags(vec!["Player".to_string(), "Burning".to_string()]);
+
+      model.remove_tags(vec!["Burning", "Frozen"]);
+
+      assert_eq!(model.get_tags(), HashSet::from(["Player".to_string()]));
+    }
+
+    #[test]
+    fn clear_tags() {
+      let mut model = TestingData::new_test_model(WORLD_POSITION);
+      model.add_tags(vec!["Player".to_string(), "Burning".to_string()]);
+
+      model.clear_tags();
+
+      assert!(model.get_tags().is_empty());
+    }
+  }
 }
@@ -1,7 +1,6 @@
 // use crate::models::animation::ModelAnimationData;
 use crate::models::errors::*;
 use crate::models::{hitboxes::*, model_appearance::sprites::*, model_data::*, strata::*};
-use engine_math::rectangle::Rectangle;
 use log::error;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -18,7 +17,13 @@ struct ModelDataBuilder {
   name: Option<String>,
   strata: Option<Strata>,
   appearance: Option<String>,
+  /// The path to a file containing the appearance, used in place of the ``Appearance`` section.
+  ///
+  /// The file is read into the appearance through [`load_appearance`](ModelDataBuilder::load_appearance).
+  appearance_path: Option<PathBuf>,
   hitbox_dimensions: Option<String>,
+  /// The tags from the ``Tags`` section, assigned to the model once it's built.
+  tags: Vec<String>,
 
   animation_file_path: Option<Box<PathBuf>>,
 }
@@ -29,6 +34,7 @@ enum Section {
   Skin,
   Appearance,
   HitboxDimensions,
+  Tags,
   Unknown,
 }
 
@@ -44,25 +50,39 @@ impl ModelDataBuilder {
   ///
   /// - Returns an error when the ModelDataBuilder is missing one or more fields of data.
   /// - Returns an error when the Appearance data had no anchor.
-  fn build(self, frame_position: (usize, usize)) -> Result<ModelData, ModelError> {
+  /// - Returns an error when the anchor was only in one of the Appearance and Hitbox_Dimensions sections.
+  fn build(mut self, frame_position: (usize, usize)) -> Result<ModelData, ModelError> {
     if let Err(error) = self.check_if_all_data_exists() {
       return Err(ModelError::ModelCreationError(error));
     }
 
+    if let Err(error) = self.load_appearance() {
+      return Err(ModelError::ModelCreationError(error));
+    }
+
+    if let Err(error) = self.check_anchor_in_both_sections() {
+      return Err(ModelError::ModelCreationError(error));
+    }
+
     let hitbox_data = self.build_hitbox_data()?;
     let sprite = self.build_sprite()?;
 
-    ModelData::new(
+    let mut model_data = ModelData::new(
       frame_position,
       sprite,
       hitbox_data,
       self.strata.unwrap(),
       self.name.unwrap(),
-    )
+    )?;
+    model_data.add_tags(self.tags);
+
+    Ok(model_data)
   }
 
   /// Creates a [`Sprite`](crate::models::sprites::Sprite) with the data inside of self.
   ///
+  /// The appearance has to be loaded through [`load_appearance`](ModelDataBuilder::load_appearance) first.
+  ///
   /// # Errors
   ///
   /// - Returns an error when no anchor was found on the appearance of the model.
@@ -75,20 +95,94 @@ impl ModelDataBuilder {
     Sprite::new(appearance, anchor, anchor_replacement, air)
   }
 
+  /// Reads the appearance file into the appearance if a path was given in place of the
+  /// ``Appearance`` section, meaning the file is only read once.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when both an appearance and an appearance path were given.
+  /// - Returns an error when the appearance file couldn't be read.
+  /// - Returns an error when neither an appearance or an appearance path were given.
+  fn load_appearance(&mut self) -> Result<(), ModelCreationError> {
+    match (&self.appearance, self.appearance_path.take()) {
+      (Some(_), Some(_)) => {
+        error!("A model was given both an Appearance section and an appearance_path.");
+
+        Err(ModelCreationError::AppearanceDefinedTwice)
+      }
+      (Some(_), None) => Ok(()),
+      (None, Some(appearance_path)) => match std::fs::read_to_string(&appearance_path) {
+        Ok(appearance) => {
+          self.appearance = Some(appearance.lines().collect::<Vec<&str>>().join("\n"));
+
+          Ok(())
+        }
+        Err(error) => {
+          error!("Failed to read the appearance file {appearance_path:?}: {error}");
+
+          Err(ModelCreationError::CouldntReadAppearanceFile(
+            appearance_path.into_os_string(),
+          ))
+        }
+      },
+      (None, None) => Err(ModelCreationError::MissingData(vec![
+        "Appearance".to_string()
+      ])),
+    }
+  }
+
+  /// Makes a relative appearance path relative to the given directory instead of the working directory.
+  fn resolve_appearance_path(&mut self, model_directory: &Path) {
+    if let Some(appearance_path) = self.appearance_path.as_mut() {
+      *appearance_path = model_directory.join(&*appearance_path);
+    }
+  }
+
   /// Creates [`HitboxData`](crate::models::hitboxes::Hitbox) from the data inside of self.
+  ///
+  /// An empty ``Hitbox_Dimensions`` section creates an empty hitbox.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the hitbox isn't rectangular.
+  /// - Returns an error when the hitbox has multiple anchors.
   fn build_hitbox_data(&self) -> Result<Hitbox, ModelError> {
     let hitbox_shape = self.hitbox_dimensions.as_ref().unwrap();
     let anchor_character = self.anchor.unwrap();
 
-    let hitbox_dimensions =
-      Rectangle::get_string_dimensions(hitbox_shape).unwrap_or(Rectangle::default());
-    let anchor_index = if hitbox_dimensions.area() != 0 {
-      Sprite::calculate_anchor_index(hitbox_shape, anchor_character)?
-    } else {
-      0
+    Hitbox::from_shape(hitbox_shape, anchor_character)
+  }
+
+  /// Checks that the anchor is in both the appearance and the hitbox, as the two are aligned by it.
+  ///
+  /// An empty hitbox has no anchor, and a model with no anchor at all is left for the sprite to
+  /// report, so neither of those cause an error here.
+  ///
+  /// # Errors
+  ///
+  /// - Returns the name of the section missing the anchor, when the anchor is only in one section.
+  fn check_anchor_in_both_sections(&self) -> Result<(), ModelCreationError> {
+    let Some(anchor) = self.anchor else {
+      return Ok(());
+    };
+    let appearance = self.appearance.as_deref().unwrap_or_default();
+    let hitbox_shape = self.hitbox_dimensions.as_deref().unwrap_or_default();
+
+    if hitbox_shape.is_empty() {
+      return Ok(());
+    }
+
+    let missing_section = match (appearance.contains(anchor), hitbox_shape.contains(anchor)) {
+      (true, false) => "Hitbox_Dimensions",
+      (false, true) => "Appearance",
+      _ => return Ok(()),
     };
 
-    Ok(Hitbox::new(hitbox_dimensions, anchor_index))
+    error!("A model's anchor was missing from the {missing_section} section.");
+
+    Err(ModelCreationError::AnchorMissingInSection(
+      missing_section.to_string(),
+    ))
   }
 
   /// Checks if every field in the given ModelDataBuilder exists.
@@ -123,7 +217,7 @@ impl ModelDataBuilder {
       error_list.push("Strata".to_string());
     }
 
-    if self.appearance.is_none() {
+    if self.appearance.is_none() && self.appearance_path.is_none() {
       error_list.push("Appearance".to_string());
     }
 
@@ -135,32 +229,73 @@ impl ModelDataBuilder {
       Err(ModelCreationError::MissingData(error_list))
     }
   }
+
+  /// Checks if every field required to build a sprite exists in the given ModelDataBuilder.
+  ///
+  /// # Errors
+  ///
+  /// Returns the ModelCreationError::MissingData() error, containing a list of every missing field.
+  fn check_if_sprite_data_exists(&self) -> Result<(), ModelCreationError> {
+    let mut error_list = vec![];
+
+    if self.anchor.is_none() {
+      error_list.push("Anchor Character".to_string());
+    }
+
+    if self.anchor_replacement.is_none() {
+      error_list.push("Anchor Replacement Character".to_string());
+    }
+
+    if self.air.is_none() {
+      error_list.push("Air Character".to_string());
+    }
+
+    if self.appearance.is_none() && self.appearance_path.is_none() {
+      error_list.push("Appearance".to_string());
+    }
+
+    if error_list.is_empty() {
+      Ok(())
+    } else {
+      error!("A sprite was attempted to be made with missing data: {error_list:?}");
+
+      Err(ModelCreationError::MissingData(error_list))
+    }
+  }
 }
 
 impl ModelParser {
-  /// Parses the passed in file of ``name.model``.
+  /// Parses the contents of a ``name.model`` file from the passed in reader.
   ///
   /// # Errors
   ///
+  /// - Returns an error when the reader failed to be read, or didn't contain valid UTF-8.
   /// - Returns an error when any data within the model file is invalid. (Yes that includes when it's empty.)
-  pub fn parse(
-    mut model_file: std::fs::File,
+  /// - Returns an error when the ``Hitbox_Dimensions`` section isn't rectangular.
+  pub fn parse<R: Read>(
+    model_reader: R,
     frame_position: (usize, usize),
   ) -> Result<ModelData, ModelError> {
-    let mut file_contents_buffer = String::new();
-    model_file
-      .read_to_string(&mut file_contents_buffer)
-      .unwrap();
+    Self::parse_relative_to(model_reader, frame_position, Path::new(""))
+  }
 
-    if file_contents_buffer.is_empty() {
-      return Err(ModelError::ModelCreationError(
-        ModelCreationError::ModelFileIsEmpty,
-      ));
-    }
+  /// Parses the contents of a ``name.model`` file the same as [`parse`](ModelParser::parse), with
+  /// a relative ``appearance_path`` being relative to the given directory.
+  ///
+  /// # Errors
+  ///
+  /// - Returns the same errors as [`parse`](ModelParser::parse).
+  pub(crate) fn parse_relative_to<R: Read>(
+    model_reader: R,
+    frame_position: (usize, usize),
+    model_directory: &Path,
+  ) -> Result<ModelData, ModelError> {
+    let file_contents_buffer = ModelParser::read_contents(model_reader)?;
 
     let file_rows: Vec<&str> = file_contents_buffer.split('\n').collect();
     // let mut model_data_builder = ModelParser::parse_rows(file_rows)?;
-    let model_data_builder = ModelParser::parse_rows(file_rows)?;
+    let mut model_data_builder = ModelParser::parse_rows(file_rows)?;
+    model_data_builder.resolve_appearance_path(model_directory);
 
     // let model_animation_file_path = model_data_builder.animation_file_path.take();
     // let mut model_data = model_data_builder.build(frame_position)?;
@@ -176,6 +311,64 @@ impl ModelParser {
     Ok(model_data)
   }
 
+  /// Parses only the sprite of a ``name.model`` file from the passed in reader.
+  ///
+  /// Only the ``Skin`` data used by the sprite and the ``Appearance`` section are required to exist.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the reader failed to be read, or didn't contain valid UTF-8.
+  /// - Returns an error when any data within the model file is invalid.
+  /// - Returns an error when the anchor, anchor replacement, air, or appearance is missing.
+  pub fn parse_sprite<R: Read>(model_reader: R) -> Result<Sprite, ModelError> {
+    Self::parse_sprite_relative_to(model_reader, Path::new(""))
+  }
+
+  /// Parses only the sprite of a ``name.model`` file the same as
+  /// [`parse_sprite`](ModelParser::parse_sprite), with a relative ``appearance_path`` being
+  /// relative to the given directory.
+  ///
+  /// # Errors
+  ///
+  /// - Returns the same errors as [`parse_sprite`](ModelParser::parse_sprite).
+  pub(crate) fn parse_sprite_relative_to<R: Read>(
+    model_reader: R,
+    model_directory: &Path,
+  ) -> Result<Sprite, ModelError> {
+    let file_contents_buffer = ModelParser::read_contents(model_reader)?;
+
+    let file_rows: Vec<&str> = file_contents_buffer.split('\n').collect();
+    let mut model_data_builder = ModelParser::parse_rows(file_rows)?;
+    model_data_builder.resolve_appearance_path(model_directory);
+
+    model_data_builder.check_if_sprite_data_exists()?;
+    model_data_builder.load_appearance()?;
+
+    model_data_builder.build_sprite()
+  }
+
+  /// Reads the full contents of the given reader.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the reader failed to be read, or didn't contain valid UTF-8.
+  /// - Returns an error when the reader was empty.
+  fn read_contents<R: Read>(mut model_reader: R) -> Result<String, ModelError> {
+    let mut file_contents_buffer = String::new();
+
+    if let Err(error) = model_reader.read_to_string(&mut file_contents_buffer) {
+      return Err(ModelError::Other(error.to_string()));
+    }
+
+    if file_contents_buffer.is_empty() {
+      return Err(ModelError::ModelCreationError(
+        ModelCreationError::ModelFileIsEmpty,
+      ));
+    }
+
+    Ok(file_contents_buffer)
+  }
+
   /// Takes the rows from the model file and adds the data to a ModelDataBuilder.
   ///
   /// Returns the ModelDataBuilder with all the data contained in the model file for creating a ModelData.
@@ -196,6 +389,11 @@ impl ModelParser {
         // Accounts for the fact that lines start at 1 not 0.
         let line_number = iteration + 1;
 
+        // Comments are only read in the Skin section and between sections, as rows of art can start with '#'.
+        if matches!(section, Section::Skin | Section::Unknown) && model_file_line.starts_with('#') {
+          return Ok(());
+        }
+
         match model_file_line.to_lowercase().trim() {
           "-=--=-" => section = Section::Unknown,
           "" => section = Section::Unknown,
@@ -215,6 +413,11 @@ impl ModelParser {
 
             return Ok(());
           }
+          "tags" => {
+            section = Section::Tags;
+
+            return Ok(());
+          }
           _ => {
             if model_file_line.contains("+- ") {
               return Ok(());
@@ -233,6 +436,9 @@ impl ModelParser {
 
           Section::Appearance => appearance_rows.push(model_file_line),
           Section::HitboxDimensions => hitbox_dimension_rows.push(model_file_line),
+          Section::Tags => model_data_builder
+            .tags
+            .push(model_file_line.trim().to_string()),
           Section::Unknown => return Ok(()),
         }
 
@@ -316,6 +522,16 @@ impl ModelParser {
         model_data_builder.strata = Some(strata);
       }
 
+      "appearance_path" => {
+        if line_contents.is_empty() {
+          error!("Attempted to build an object with an empty appearance path");
+
+          return Err(ModelCreationError::InvalidSyntax(line_number));
+        }
+
+        model_data_builder.appearance_path = Some(PathBuf::from(line_contents));
+      }
+
       // TODO Add a custom error for animation files that hold a path.
       "animation_path" => {
         if line_contents.is_empty() {
@@ -382,7 +598,7 @@ impl ModelParser {
 pub(crate) fn line_to_parts(
   model_file_row: &str,
   line_number: usize,
-) -> Result<LineComponents, ModelCreationError> {
+) -> Result<LineComponents<'_>, ModelCreationError> {
   let (data_type, contained_row_contents) = match model_file_row.split_once('=') {
     Some(split_row) => split_row,
     None => return Err(ModelCreationError::InvalidSyntax(line_number)),
@@ -415,6 +631,7 @@ impl<'a> LineComponents<'a> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use std::collections::HashSet;
   use std::fs::File;
   use std::path::Path;
 
@@ -442,6 +659,25 @@ mod tests {
     ]
   }
 
+  #[test]
+  fn comments_are_skipped() {
+    let model_file = File::open(Path::new("../tests/models/test_square.model")).unwrap();
+    let commented_model_file =
+      File::open(Path::new("../tests/models/test_square_commented.model")).unwrap();
+
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
+    let commented_model = ModelParser::parse(commented_model_file, (10, 10)).unwrap();
+
+    assert_eq!(commented_model.get_sprite(), model.get_sprite());
+    assert_eq!(commented_model.get_hitbox(), model.get_hitbox());
+    assert_eq!(commented_model.get_name(), model.get_name());
+    assert_eq!(commented_model.get_strata(), model.get_strata());
+    assert_eq!(
+      commented_model.get_frame_position(),
+      model.get_frame_position()
+    );
+  }
+
   #[test]
   fn equals_in_skin_field() {
     let file_path = Path::new("../tests/models/equals_in_skin_field.model");
@@ -464,16 +700,13 @@ mod tests {
   }
 
   #[test]
-  fn model_with_impossible_strata() {
+  fn model_with_strata_above_previous_range() {
     let file_path = Path::new("../tests/models/wrong_strata_range.model");
     let model_file = File::open(file_path).unwrap();
 
-    let error = ModelCreationError::InvalidStrataRange(1000);
-    let expected_result = Err(ModelError::ModelCreationError(error));
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
 
-    let result = ModelParser::parse(model_file, (0, 0));
-
-    assert_eq!(result, expected_result);
+    assert_eq!(model.get_strata(), Strata(1000));
   }
 
   #[test]
@@ -515,6 +748,97 @@ mod tests {
     assert_eq!(result, expected_result);
   }
 
+  #[test]
+  fn anchor_missing_in_hitbox() {
+    let file_path = Path::new("../tests/models/anchor_missing_in_hitbox.model");
+    let model_file = File::open(file_path).unwrap();
+
+    let error = ModelCreationError::AnchorMissingInSection("Hitbox_Dimensions".to_string());
+    let expected_result = Err(ModelError::ModelCreationError(error));
+
+    let result = ModelParser::parse(model_file, (10, 10));
+
+    assert_eq!(result, expected_result);
+  }
+
+  #[test]
+  fn non_rectangular_hitbox() {
+    let file_path = Path::new("../tests/models/non_rectangular_hitbox.model");
+    let model_file = File::open(file_path).unwrap();
+
+    let expected_result = Err(ModelError::NonRectangularShape);
+
+    let result = ModelParser::parse(model_file, (10, 10));
+
+    assert_eq!(result, expected_result);
+  }
+
+  #[test]
+  fn external_appearance_matches_inline() {
+    let model_file = File::open(Path::new("../tests/models/test_square.model")).unwrap();
+    let external_model_file = File::open(Path::new(
+      "../tests/models/test_square_external_appearance.model",
+    ))
+    .unwrap();
+
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
+    // The appearance path is relative to the model file rather than the working directory.
+    let external_model =
+      ModelParser::parse_relative_to(external_model_file, (10, 10), Path::new("../tests/models"))
+        .unwrap();
+
+    assert_eq!(external_model.get_sprite(), model.get_sprite());
+    assert_eq!(external_model.get_hitbox(), model.get_hitbox());
+  }
+
+  #[test]
+  fn art_starting_with_comment_character() {
+    let model_file = File::open(Path::new("../tests/models/hash_art.model")).unwrap();
+
+    let expected_appearance = "#####\n#---#\n  ###";
+
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
+
+    assert_eq!(model.get_sprite().get_appearance(), expected_appearance);
+    assert_eq!(
+      model.get_hitbox_dimensions(),
+      engine_math::rectangle::Rectangle::new(5, 3)
+    );
+  }
+
+  #[test]
+  fn tags_section_is_assigned() {
+    let model_file = File::open(Path::new("../tests/models/test_square_tagged.model")).unwrap();
+
+    let expected_tags: HashSet<String> = ["Wall", "Solid"].into_iter().map(String::from).collect();
+
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
+
+    assert_eq!(model.get_tags(), expected_tags);
+  }
+
+  #[test]
+  fn model_without_tags_section() {
+    let model_file = File::open(Path::new("../tests/models/test_square.model")).unwrap();
+
+    let model = ModelParser::parse(model_file, (10, 10)).unwrap();
+
+    assert!(model.get_tags().is_empty());
+  }
+
+  #[test]
+  fn appearance_defined_twice() {
+    let file_path = Path::new("../tests/models/appearance_defined_twice.model");
+    let model_file = File::open(file_path).unwrap();
+
+    let error = ModelCreationError::AppearanceDefinedTwice;
+    let expected_result = Err(ModelError::ModelCreationError(error));
+
+    let result = ModelParser::parse(model_file, (10, 10));
+
+    assert_eq!(result, expected_result);
+  }
+
   #[test]
   fn characters_in_strata_field() {
     let file_path = Path::new("../tests/models/characters_in_strata.model");
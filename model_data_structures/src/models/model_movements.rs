@@ -1,16 +1,27 @@
 use std::collections::VecDeque;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ModelCollisions {
   pub collider: u64,
   pub caused_movement: ModelMovement,
   pub collision_list: VecDeque<u64>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// The models collided with when a movement was checked along each axis separately.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AxisCollisions {
+  pub x_collisions: VecDeque<u64>,
+  pub y_collisions: VecDeque<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ModelMovement {
   Absolute((isize, isize)),
   Relative((isize, isize)),
+  /// A relative movement that can move by fractions of a cell.
+  ///
+  /// The fractions are accumulated on the model, and it only moves once a whole cell has been built up.
+  RelativeF((f32, f32)),
 }
 
 impl ModelCollisions {
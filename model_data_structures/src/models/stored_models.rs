@@ -3,6 +3,13 @@ use crate::models::{hitboxes::*, model_data::*, strata::*};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+/// The saved form of a model, as written to world files.
+///
+/// Only the model's position, name, strata, appearance, hitbox, and tags are stored.
+/// The hitbox anchor offset, collision layer and mask, air override, visibility, and movement
+/// remainder are runtime-only, and are reset to their defaults when the model is loaded.
+/// World files are encoded without field names, so adding fields here would stop existing worlds
+/// from loading.
 #[derive(Debug, Deserialize, Serialize)]
 #[allow(unused)]
 pub struct StoredDisplayModel {
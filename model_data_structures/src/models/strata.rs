@@ -4,14 +4,90 @@ use serde::{Deserialize, Serialize};
 /// The Strata will be the priority on the screen.
 /// That which has a lower Strata, will be behind those with a higher strata.
 ///
-/// The strata is a range from 0-100, any number outside of that range will
-/// not be accepted.
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
-pub struct Strata(pub usize);
+/// The strata acts as a z-index, meaning any i32 is accepted, negatives included.
+// Serialized as an i64, which keeps worlds saved while the strata was a usize loadable.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "i64", try_from = "i64")]
+pub struct Strata(pub i32);
 
 impl Strata {
-  /// Returns true if the given strata is withing 0-100.
+  /// Returns true if the given strata is valid.
+  ///
+  /// Every strata is currently valid. This stays until strata is replaced with a Z axis.
   pub fn correct_range(&self) -> bool {
-    self.0 <= 100
+    true
+  }
+
+  /// Returns true if models in this strata are drawn above those in the other strata.
+  pub fn above(&self, other: &Strata) -> bool {
+    self.0 > other.0
+  }
+
+  /// Returns true if models in this strata are drawn below those in the other strata.
+  pub fn below(&self, other: &Strata) -> bool {
+    self.0 < other.0
+  }
+
+  /// Returns every strata from 0 to 100, from the lowest to the highest.
+  ///
+  /// This was every valid strata before any i32 was accepted.
+  #[deprecated(note = "Strata is no longer bounded, any i32 is a valid strata.")]
+  pub fn range() -> impl Iterator<Item = Strata> {
+    (0..=100).map(Strata)
+  }
+}
+
+impl From<Strata> for i64 {
+  fn from(strata: Strata) -> Self {
+    strata.0 as i64
+  }
+}
+
+impl TryFrom<i64> for Strata {
+  type Error = std::num::TryFromIntError;
+
+  fn try_from(strata_number: i64) -> Result<Self, Self::Error> {
+    i32::try_from(strata_number).map(Strata)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn above_and_below_logic() {
+    let lower_strata = Strata(-10);
+    let higher_strata = Strata(20);
+
+    assert!(higher_strata.above(&lower_strata));
+    assert!(lower_strata.below(&higher_strata));
+    assert!(!lower_strata.above(&lower_strata));
+    assert!(!lower_strata.below(&lower_strata));
+  }
+
+  #[test]
+  fn any_strata_is_in_range() {
+    assert!(Strata(i32::MIN).correct_range());
+    assert!(Strata(1000).correct_range());
+  }
+
+  #[test]
+  fn deserialize_previous_usize_strata() {
+    let serialized_strata = bincode::serialize(&95_usize).unwrap();
+
+    let strata: Strata = bincode::deserialize(&serialized_strata).unwrap();
+
+    assert_eq!(strata, Strata(95));
+  }
+
+  #[test]
+  fn serialization_round_trip() {
+    let strata = Strata(-42);
+
+    let serialized_strata = bincode::serialize(&strata).unwrap();
+    let deserialized_strata: Strata = bincode::deserialize(&serialized_strata).unwrap();
+
+    assert_eq!(deserialized_strata, strata);
   }
 }
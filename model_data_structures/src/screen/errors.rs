@@ -1,29 +1,83 @@
 use crate::models::animation::errors::*;
 use crate::models::errors::*;
+use thiserror::Error;
 
 /// The list of errors that could happen when dealing with the screen.
 ///
 /// Includes wrappers for both
 /// [`ModelError`](crate::models::errors::ModelError) and
 /// [`PrintingError`](screen_printer::printer::PrintingError).
-#[derive(Debug, PartialEq, Eq)]
+///
+/// The wrapped errors are returned as the [`source`](std::error::Error::source) of the error.
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum ScreenError {
   /// A wrapper for [`ModelError`](crate::models::errors::ModelError).
-  ModelError(ModelError),
+  #[error("An error occurred while handling a model. Reason: {:?}", .0)]
+  ModelError(#[from] ModelError),
 
   /// A wrapper for [`PrintingError`](screen_printer::printer::PrintingError).
-  PrintingError(screen_printer::printer::PrintingError),
+  #[error("Failed to print to the screen. Reason: {:?}", .0)]
+  PrintingError(#[from] screen_printer::printer::PrintingError),
 
   /// A wrapper for [`AnimationError`](crate::models::animation::errors::AnimationError).
-  AnimationError(AnimationError),
+  #[error("An error occurred while animating a model. Reason: {:?}", .0)]
+  AnimationError(#[from] AnimationError),
 
   /// Generally a wrapper around other crate's error types.
+  #[error("{}", .0)]
   Other(String),
 
   /// Attempted to read a file that did not exist.
+  #[error("Attempted to read a file that doesn't exist.")]
   FileDoesNotExist,
 
   /// There was an error when attempting to serialize the data for a world.
   /// Contains the error that caused this.
+  #[error("Failed to load a world. Reason: {}", .0)]
   FailedToLoadWorld(String),
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::error::Error;
+
+  #[test]
+  fn wrapped_errors_are_the_source() {
+    let model_error = ModelError::ModelDoesntExist;
+    let screen_error = ScreenError::from(model_error.clone());
+
+    let source = screen_error.source().unwrap();
+
+    assert_eq!(source.to_string(), model_error.to_string());
+  }
+
+  #[test]
+  fn full_chain_is_walkable() {
+    let animation_error = AnimationError::NoAnimationHasFinished;
+    let screen_error = ScreenError::from(ModelError::from(animation_error.clone()));
+
+    let chain: Vec<String> =
+      std::iter::successors(Some(&screen_error as &dyn Error), |&error| error.source())
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain[2], animation_error.to_string());
+  }
+
+  #[test]
+  fn boxes_into_dyn_error() {
+    fn load() -> Result<(), Box<dyn Error>> {
+      Err(ScreenError::FileDoesNotExist)?
+    }
+
+    assert!(load().is_err());
+  }
+
+  #[test]
+  fn errors_without_a_wrapped_error_have_no_source() {
+    assert!(ScreenError::FileDoesNotExist.source().is_none());
+    assert!(ScreenError::Other("reason".to_string()).source().is_none());
+  }
+}
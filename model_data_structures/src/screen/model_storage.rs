@@ -2,7 +2,7 @@ use crate::errors::*;
 use crate::models::model_data::*;
 use crate::models::strata::Strata;
 use log::{error, info, warn};
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
 /// This is the struct that contains a reference to every model that exists in the world.
 #[derive(Debug, Default)]
@@ -20,8 +20,8 @@ impl ModelStorage {
   pub fn insert(&mut self, model: ModelData) -> Result<(), ModelError> {
     let key = model.get_hash();
 
-    if self.models.get(&key).is_none() {
-      self.models.insert(key, model);
+    if let Entry::Vacant(entry) = self.models.entry(key) {
+      entry.insert(model);
 
       self.insert_strata(&key)?;
     } else {
@@ -87,6 +87,14 @@ impl ModelStorage {
     self.model_stratas.get(key)
   }
 
+  /// Returns every strata that currently contains a model, from the lowest to the highest.
+  pub fn get_stratas(&self) -> Vec<Strata> {
+    let mut stratas: Vec<Strata> = self.model_stratas.keys().copied().collect();
+    stratas.sort();
+
+    stratas
+  }
+
   /// Returns a copy of the requested ModelData.
   ///
   /// Returns None when the model doesn't exist.
@@ -149,9 +157,7 @@ impl ModelStorage {
   ///
   /// - Returns an error when a model somehow has an impossible strata.
   pub fn fix_strata_list(&mut self) -> Result<(), ModelError> {
-    for strata_number in 0..=100 {
-      let current_strata = Strata(strata_number);
-
+    for current_strata in self.get_stratas() {
       let Some(strata_keys) = self.get_strata_keys(&current_strata) else {
         continue;
       };
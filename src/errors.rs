@@ -1,2 +1,14 @@
 pub use model_data_structures::models::errors::*;
 pub use model_data_structures::screen::errors::*;
+use thiserror::Error;
+
+/// The list of errors that could happen when measuring ticks with a
+/// [`TickMark`](crate::general_data::tick_marks::TickMark).
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TickMarkError {
+  /// The mark was created on a later tick than the current one.
+  ///
+  /// This happens when the EventSync was restarted after the mark was created.
+  #[error("The tick mark was created on a later tick than the current one.")]
+  MarkIsAfterCurrentTick,
+}
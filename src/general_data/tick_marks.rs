@@ -0,0 +1,77 @@
+use crate::errors::TickMarkError;
+use event_sync::EventSync;
+
+/// A tick of an [`EventSync`](event_sync::EventSync), created with [`mark`](EventSyncMethods::mark).
+///
+/// Used for measuring the amount of ticks between two points in time, such as a combo window.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TickMark {
+  tick: u64,
+}
+
+/// Methods for measuring ticks between points in time with an [`EventSync`](event_sync::EventSync).
+///
+/// Import this trait to call [`mark`](EventSyncMethods::mark) and
+/// [`ticks_since_mark`](EventSyncMethods::ticks_since_mark) on any EventSync.
+pub trait EventSyncMethods {
+  /// Returns a mark of the current tick.
+  fn mark(&self) -> TickMark;
+
+  /// Returns the amount of ticks that have occurred since the given mark was created.
+  ///
+  /// Uses the same clock as [`ticks_since_started`](event_sync::EventSync::ticks_since_started).
+  ///
+  /// # Errors
+  ///
+  /// - The mark is from a later tick than the current one, which happens when the EventSync was
+  ///   restarted after the mark was created.
+  fn ticks_since_mark(&self, mark: &TickMark) -> Result<u64, TickMarkError>;
+}
+
+impl<T> EventSyncMethods for EventSync<T> {
+  fn mark(&self) -> TickMark {
+    TickMark {
+      tick: self.ticks_since_started(),
+    }
+  }
+
+  fn ticks_since_mark(&self, mark: &TickMark) -> Result<u64, TickMarkError> {
+    self
+      .ticks_since_started()
+      .checked_sub(mark.tick)
+      .ok_or(TickMarkError::MarkIsAfterCurrentTick)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ticks_since_mark_logic() {
+    let event_sync = EventSync::new(10);
+    event_sync.wait_for_x_ticks(2).unwrap();
+
+    let mark = event_sync.mark();
+    event_sync.wait_for_x_ticks(3).unwrap();
+
+    let ticks_since_mark = event_sync.ticks_since_mark(&mark).unwrap();
+
+    // The thread may be scheduled late, letting an extra tick pass.
+    assert!((3..=4).contains(&ticks_since_mark), "{ticks_since_mark}");
+  }
+
+  #[test]
+  fn mark_from_before_restart() {
+    let mut event_sync = EventSync::new(10);
+    event_sync.wait_for_x_ticks(3).unwrap();
+    let mark = event_sync.mark();
+
+    event_sync.restart();
+
+    assert_eq!(
+      event_sync.ticks_since_mark(&mark),
+      Err(TickMarkError::MarkIsAfterCurrentTick)
+    );
+  }
+}
@@ -2,12 +2,15 @@
 
 use log::error;
 use log::info;
+use log::warn;
 use oneshot::Sender;
 use std::io;
 use std::io::{Read, Write};
-use std::sync::mpsc::{channel, Receiver};
-use std::thread;
-use termios::{tcsetattr, Termios, ECHO, ICANON, TCSANOW};
+use std::os::fd::AsRawFd;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use termios::{tcsetattr, Termios, ECHO, ICANON, TCSANOW, VMIN, VTIME};
 
 /// Gets a user's input without canonical mode.
 ///
@@ -57,3 +60,198 @@ pub fn spawn_input_thread() -> (Receiver<String>, Sender<()>) {
 
   (input_receiver, kill_sender)
 }
+
+/// Spawns a thread that sends every key the user presses as soon as it's pressed, without waiting for Enter.
+///
+/// The terminal is put into raw mode (no canonical mode and no echo) while the thread runs, and is
+/// restored once the thread stops.
+/// Reads from stdin time out every [`RAW_INPUT_POLL_INTERVAL`](RAW_INPUT_POLL_INTERVAL), so the thread
+/// stops without waiting for another key press.
+///
+/// If raw mode couldn't be enabled, such as when stdin isn't a terminal, the thread falls back to
+/// reading whole lines, and sends every character of the line. The terminal is left untouched in that
+/// case, and reads can't time out, meaning the thread only stops after the next line is read or stdin
+/// is closed. Refer to [`RawInputHandle`](RawInputHandle) for how this affects stopping the thread.
+///
+/// The Receiver for the user's input, and a handle to stop the input thread is returned.
+pub fn spawn_raw_input_thread() -> (Receiver<char>, RawInputHandle) {
+  let (input_sender, input_receiver) = channel();
+  let (kill_sender, kill_receiver) = channel::<()>();
+  let raw_mode_guard = match RawModeGuard::enable() {
+    Ok(raw_mode_guard) => Some(raw_mode_guard),
+    Err(error) => {
+      warn!("Failed to enable raw mode, falling back to line input. Reason: {error}");
+
+      None
+    }
+  };
+  let is_raw_mode = raw_mode_guard.is_some();
+
+  info!("Spawning raw input thread.");
+  let thread_handle = thread::spawn(move || {
+    info!("Raw input thread successfully spawned.");
+
+    // Either a kill signal was sent, or the handle was dropped.
+    while kill_receiver.try_recv() == Err(TryRecvError::Empty) {
+      let input = if raw_mode_guard.is_some() {
+        read_character()
+      } else {
+        read_line_characters()
+      };
+
+      let Some(input) = input else {
+        error!("The raw input thread stopped reading after stdin was closed or failed to be read.");
+
+        break;
+      };
+
+      if input
+        .into_iter()
+        .any(|character| input_sender.send(character).is_err())
+      {
+        break;
+      }
+    }
+
+    // Restores the terminal before the thread is reported as finished.
+    drop(raw_mode_guard);
+
+    info!("Raw input thread killed.");
+  });
+
+  let raw_input_handle = RawInputHandle {
+    thread_handle,
+    kill_sender,
+    is_raw_mode,
+  };
+
+  (input_receiver, raw_input_handle)
+}
+
+/// How long a read from stdin waits for a key press in raw mode, before checking if the raw input
+/// thread was killed.
+pub const RAW_INPUT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A handle to the thread created by [`spawn_raw_input_thread`](spawn_raw_input_thread).
+///
+/// In raw mode, dropping the handle stops the thread within
+/// [`RAW_INPUT_POLL_INTERVAL`](RAW_INPUT_POLL_INTERVAL).
+///
+/// When the thread fell back to line input, reading from stdin can't time out. Dropping or killing
+/// the handle then detaches the thread, which stops once the next line is read or stdin is closed.
+#[derive(Debug)]
+pub struct RawInputHandle {
+  thread_handle: JoinHandle<()>,
+  kill_sender: std::sync::mpsc::Sender<()>,
+  is_raw_mode: bool,
+}
+
+impl RawInputHandle {
+  /// Returns true if the raw input thread has stopped.
+  pub fn is_finished(&self) -> bool {
+    self.thread_handle.is_finished()
+  }
+
+  /// Returns true if the terminal was put into raw mode, and false if the thread fell back to
+  /// line input.
+  pub fn is_raw_mode(&self) -> bool {
+    self.is_raw_mode
+  }
+
+  /// Stops the raw input thread.
+  ///
+  /// In raw mode this waits for the thread to restore the terminal. Once this returns the terminal
+  /// is back in the mode it was in before the thread was spawned, meaning it's safe to exit the
+  /// program right after.
+  ///
+  /// In line input the thread could be blocked waiting for a line, so it's detached instead of
+  /// waited on. The terminal was never changed in that case.
+  ///
+  /// # Errors
+  ///
+  /// - Returns the panic of the raw input thread if it panicked, when in raw mode.
+  pub fn kill(self) -> thread::Result<()> {
+    // The thread has already stopped if the receiver is gone.
+    let _ = self.kill_sender.send(());
+
+    if !self.is_raw_mode {
+      return Ok(());
+    }
+
+    self.thread_handle.join()
+  }
+}
+
+/// Keeps stdin in raw mode until dropped, restoring the terminal's original settings.
+struct RawModeGuard {
+  original_termios: Termios,
+}
+
+impl RawModeGuard {
+  /// Disables canonical mode and echo on stdin, and makes reads time out after
+  /// [`RAW_INPUT_POLL_INTERVAL`](RAW_INPUT_POLL_INTERVAL).
+  ///
+  /// # Errors
+  ///
+  /// - Stdin isn't a terminal.
+  /// - The new terminal settings failed to be applied.
+  fn enable() -> io::Result<Self> {
+    let stdin = io::stdin().as_raw_fd();
+    let original_termios = Termios::from_fd(stdin)?;
+    let mut raw_termios = original_termios;
+
+    raw_termios.c_lflag &= !(ICANON | ECHO);
+    // Reads return with no bytes once the poll interval passes without a key press.
+    raw_termios.c_cc[VMIN] = 0;
+    raw_termios.c_cc[VTIME] = (RAW_INPUT_POLL_INTERVAL.as_millis() / 100) as u8;
+    tcsetattr(stdin, TCSANOW, &raw_termios)?;
+
+    Ok(Self { original_termios })
+  }
+}
+
+impl Drop for RawModeGuard {
+  fn drop(&mut self) {
+    if let Err(error) = tcsetattr(io::stdin().as_raw_fd(), TCSANOW, &self.original_termios) {
+      error!("Failed to restore the terminal after raw input. Reason: {error}");
+    }
+  }
+}
+
+/// Reads a single UTF-8 character from stdin.
+///
+/// An empty list is returned if the read timed out before a key was pressed.
+/// None is returned if stdin failed to be read.
+/// Bytes that don't form a valid character are skipped.
+fn read_character() -> Option<Vec<char>> {
+  let mut stdin = io::stdin().lock();
+  let mut buffer = Vec::with_capacity(4);
+
+  loop {
+    let mut byte = [0; 1];
+
+    // Raw mode reads return nothing once they time out.
+    if stdin.read(&mut byte).ok()? == 0 {
+      return Some(vec![]);
+    }
+    buffer.push(byte[0]);
+
+    match std::str::from_utf8(&buffer) {
+      Ok(character) => return Some(character.chars().collect()),
+      Err(error) if error.error_len().is_some() || buffer.len() == 4 => buffer.clear(),
+      Err(_) => continue,
+    }
+  }
+}
+
+/// Reads a line from stdin and returns its characters, without the new line.
+///
+/// None is returned if stdin was closed or failed to be read.
+fn read_line_characters() -> Option<Vec<char>> {
+  let mut line = String::new();
+
+  match io::stdin().read_line(&mut line) {
+    Ok(0) | Err(_) => None,
+    Ok(_) => Some(line.trim_end_matches(['\n', '\r']).chars().collect()),
+  }
+}
@@ -9,12 +9,18 @@ lazy_static! {
   pub static ref CONFIG: config_builder::ConfigData = config_builder::get_config().unwrap();
 }
 
+/// Returns the config the engine was started with.
+pub fn config() -> &'static config_builder::ConfigData {
+  &CONFIG
+}
+
 pub mod defaults;
 pub mod errors;
 pub mod prelude;
 
 pub mod general_data {
   pub mod file_logger;
+  pub mod tick_marks;
   pub mod user_input;
 }
 
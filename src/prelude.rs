@@ -1,3 +1,16 @@
+//! Everything needed to build a game with ascii_engine.
+//!
+//! The prelude exposes the model based API:
+//! - Models: [`ModelData`], [`Sprite`], [`Hitbox`], [`Strata`], and [`ModelAppearance`].
+//! - Animations: [`AnimationFrames`], [`AnimationFrame`], and [`AnimationLoopCount`].
+//! - The world: [`ModelManager`], [`ModelMovement`], [`ModelCollisions`], and [`StoredWorld`].
+//! - The screen: [`ScreenData`] and the [`EventSync`] it ticks with.
+//! - Every error type that can show up when using ascii_engine.
+//!
+//! The object based API from older versions was removed, and isn't exported anymore.
+//! `Skin` and `HitboxCreationData` are replaced by [`Sprite`] and [`Hitbox`],
+//! and `ObjectData` is replaced by [`ModelData`].
+
 // Includes all error types that can show up when using ascii_engine.
 pub use crate::errors::*;
 
@@ -7,11 +20,15 @@ pub use model_data_structures::prelude::*;
 
 pub use engine_math::rectangle::*;
 
+// Includes the clock the screen and animations run on.
+pub use event_sync::{EventSync, Immutable, Mutable};
+
 // Includes all the data required to handle the screen.
 pub use crate::screen::screen_data::*;
 // Includes the config.
+pub use crate::general_data::tick_marks::*;
 pub use crate::general_data::user_input::*;
 pub use crate::models::traits::*;
 pub use crate::screen::model_manager::*;
 pub use crate::screen::stored_worlds::*;
-pub use crate::CONFIG;
+pub use crate::{config, CONFIG};
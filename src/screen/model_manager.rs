@@ -1,12 +1,17 @@
 use crate::screen::model_storage::*;
 use crate::CONFIG;
 use engine_math::coordinates::*;
+use engine_math::rectangle::Rectangle;
+use event_sync::{EventSync, Immutable};
+use model_data_structures::models::hitboxes::{Hitbox, HitboxPlacement};
 use model_data_structures::models::{
   errors::*, model_appearance::*, model_data::ModelData, model_movements::*,
 };
-use model_data_structures::prelude::AnimationFrames;
+use model_data_structures::prelude::{AnimationError, AnimationFrames, Sprite, Strata};
+use std::cmp::Reverse;
 use std::collections::HashSet;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use std::fmt::Write;
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Instant;
 
@@ -18,22 +23,52 @@ pub struct ModelManager {
   ///
   /// Order: push_back -> pop_front
   collision_events: Arc<RwLock<VecDeque<(Instant, ModelCollisions)>>>,
+  /// The most collision events that can be held at once, none if there's no limit.
+  collision_event_limit: Arc<RwLock<Option<usize>>>,
+  /// Maps the name of a tag group to the list of tags it expands to.
+  tag_groups: Arc<RwLock<HashMap<String, Vec<String>>>>,
+  /// Holds the data of every living particle, keyed by the hash of its model.
+  particles: Arc<RwLock<HashMap<u64, Particle>>>,
+  /// Maps the hash of every attached model to the hash of its parent.
+  model_parents: Arc<RwLock<HashMap<u64, u64>>>,
+  /// The handlers registered through [`on_collision`](ModelManager::on_collision), keyed by tag.
+  collision_handlers: Arc<Mutex<CollisionHandlers>>,
+  event_sync: EventSync<Immutable>,
+  hitbox_cache: Mutex<HitboxCache>,
 }
 
 impl ModelManager {
   pub(crate) fn new(
     model_storage: Arc<RwLock<ModelStorage>>,
-    collision_events: Arc<RwLock<VecDeque<(Instant, ModelCollisions)>>>,
+    collision_state: CollisionState,
+    tag_groups: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    particles: Arc<RwLock<HashMap<u64, Particle>>>,
+    model_parents: Arc<RwLock<HashMap<u64, u64>>>,
+    event_sync: EventSync<Immutable>,
   ) -> Self {
+    let CollisionState {
+      collision_events,
+      collision_event_limit,
+      collision_handlers,
+    } = collision_state;
+
     Self {
       model_storage,
       collision_events,
+      collision_event_limit,
+      tag_groups,
+      particles,
+      model_parents,
+      collision_handlers,
+      event_sync,
+      hitbox_cache: Mutex::new(HitboxCache::default()),
     }
   }
 
   /// # Errors
   ///
   /// - An error is returned when attempting to add a model that already exists.
+  /// - An error is returned when the world already holds the maximum amount of models.
   pub fn add_models_to_world(&mut self, list_of_models: Vec<ModelData>) -> Result<(), ModelError> {
     let mut model_storage = self.model_storage.write().unwrap();
 
@@ -42,6 +77,50 @@ impl ModelManager {
       .try_for_each(|model| model_storage.insert(model))
   }
 
+  /// Removes the model of the given hash from the world and returns it.
+  ///
+  /// The model is detached from its parent, and every model attached to it is left in the world
+  /// unattached.
+  /// Collision events that mention the model are kept as they are.
+  ///
+  /// Returns None if there was no model with the given hash.
+  pub fn remove_model(&mut self, model_hash: &u64) -> Option<ModelData> {
+    let mut model_storage = self.model_storage.write().unwrap();
+
+    self.remove_model_from(&mut model_storage, model_hash)
+  }
+
+  /// Removes the model from the given storage, detaching it from its parent and every model attached to it.
+  ///
+  /// If the model was a particle, it's no longer tracked as one.
+  fn remove_model_from(
+    &self,
+    model_storage: &mut ModelStorage,
+    model_hash: &u64,
+  ) -> Option<ModelData> {
+    self
+      .model_parents
+      .write()
+      .unwrap()
+      .retain(|child_hash, parent_hash| child_hash != model_hash && parent_hash != model_hash);
+    self.particles.write().unwrap().remove(model_hash);
+
+    model_storage.remove(model_hash)
+  }
+
+  /// Caps the amount of models that can exist in the world at once.
+  ///
+  /// Once the world is full, adding another model will return
+  /// [`ModelError::ModelLimitReached`](ModelError::ModelLimitReached).
+  /// By default there is no limit.
+  pub fn set_max_models(&mut self, max: usize) {
+    self
+      .model_storage
+      .write()
+      .unwrap()
+      .set_max_models(Some(max));
+  }
+
   /// Takes a closure that uses the internal list of models.
   ///
   /// Returns the value resulted within the closure.
@@ -54,6 +133,48 @@ impl ModelManager {
     closure(model_storage_read_guard.get_model_list())
   }
 
+  /// Runs the closure with the model storage locked, letting several changes be made to the world
+  /// without another thread seeing or changing the world part way through.
+  ///
+  /// The lock is only taken once, rather than once for every change.
+  /// Collision events caused within the batch are added once the batch has finished.
+  ///
+  /// Returns the value resulted within the closure.
+  ///
+  /// # Usage
+  ///
+  /// ```ignore
+  ///  use ascii_engine::prelude::*;
+  ///
+  ///  model_manager.batch(|batch| {
+  ///    batch.move_model(&player_hash, ModelMovement::Relative((1, 0)))?;
+  ///    batch.change_model_strata(&player_hash, Strata(50))
+  ///  })?;
+  /// ```
+  pub fn batch<F, T>(&mut self, closure: F) -> T
+  where
+    F: FnOnce(&mut BatchContext) -> T,
+  {
+    let model_storage = self.model_storage.clone();
+    let mut model_storage_write_guard = model_storage.write().unwrap();
+    let mut batch_context = BatchContext {
+      model_manager: self,
+      model_storage: &mut model_storage_write_guard,
+      collisions: vec![],
+    };
+
+    let result = closure(&mut batch_context);
+    let collisions = batch_context.collisions;
+
+    drop(model_storage_write_guard);
+
+    collisions
+      .into_iter()
+      .for_each(|collision| self.add_collision_to_list(collision));
+
+    result
+  }
+
   /// Returns a copy of the Model with the given hash.
   ///
   /// None is returned if there was no model in the world with the given hash.
@@ -61,6 +182,369 @@ impl ModelManager {
     self.model_storage.read().unwrap().get_model(model_hash)
   }
 
+  /// Returns the amount of models in the world.
+  pub fn count_models(&self) -> usize {
+    self.get_model_list(|model_list| model_list.len())
+  }
+
+  /// Returns the amount of models in the world that contain the given tag.
+  pub fn count_models_with_tag(&self, tag: &str) -> usize {
+    self.get_model_list(|model_list| {
+      model_list
+        .values()
+        .filter(|model| model.contains_tag(tag))
+        .count()
+    })
+  }
+
+  /// Returns the world position of every model in the world, keyed by their hashes.
+  ///
+  /// The positions are gathered under a single read of the model storage.
+  pub fn model_positions(&self) -> HashMap<u64, (isize, isize)> {
+    self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .map(|(hash, model)| (*hash, model.get_world_position()))
+        .collect()
+    })
+  }
+
+  /// Captures the position, strata, and tags of every model currently in the world.
+  ///
+  /// This is much cheaper than storing the entire world, and is intended for undoing changes
+  /// through [`restore`](ModelManager::restore).
+  pub fn snapshot(&self) -> WorldSnapshot {
+    self.get_model_list(|model_list| {
+      let models = model_list
+        .iter()
+        .map(|(hash, model)| {
+          let model_snapshot = ModelSnapshot {
+            frame_position: model.get_frame_position(),
+            strata: model.get_strata(),
+            tags: model.get_tags(),
+          };
+
+          (*hash, model_snapshot)
+        })
+        .collect();
+
+      WorldSnapshot { models }
+    })
+  }
+
+  /// Reapplies the positions, stratas, and tags stored in the snapshot to the models that still
+  /// exist in the world.
+  ///
+  /// Models that have been removed since the snapshot was taken are ignored, and models added
+  /// since then are left untouched.
+  ///
+  /// This is a raw change, no collision checks are performed and no collision events are created.
+  pub fn restore(&mut self, snapshot: WorldSnapshot) {
+    let changed_stratas: Vec<(u64, Strata)> = self.get_model_list(|model_list| {
+      snapshot
+        .models
+        .into_iter()
+        .filter_map(|(hash, model_snapshot)| {
+          let mut model = model_list.get(&hash)?.clone();
+
+          model.change_position(model_snapshot.frame_position);
+          model.clear_tags();
+          model.add_tags(model_snapshot.tags.into_iter().collect());
+
+          (model.get_strata() != model_snapshot.strata).then_some((hash, model_snapshot.strata))
+        })
+        .collect()
+    });
+
+    let mut model_storage = self.model_storage.write().unwrap();
+
+    for (hash, strata) in changed_stratas {
+      if let Err(error) = model_storage.change_model_strata(&hash, strata) {
+        log::error!("Failed to restore the strata of model {hash}: {error:?}");
+      }
+    }
+  }
+
+  /// Returns the hashes of every model whose world position is within the given radius of the center.
+  ///
+  /// The radius is measured in the width of a character. The y axis is scaled by the
+  /// aspect_ratio in the config, so the area searched appears as a circle on screen.
+  pub fn models_within_radius(&self, center: (isize, isize), radius: f32) -> Vec<u64> {
+    self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .filter(|(_, model)| {
+          is_within_radius(
+            center,
+            model.get_world_position(),
+            radius,
+            CONFIG.aspect_ratio,
+          )
+        })
+        .map(|(hash, _)| *hash)
+        .collect()
+    })
+  }
+
+  /// Returns the hash of the model with the given tag that's closest to the given model.
+  ///
+  /// The given model is never returned, even if it contains the tag.
+  ///
+  /// None is returned if the given model doesn't exist, or no other model has the tag.
+  pub fn nearest_model_with_tag(&self, from_hash: &u64, tag: &str) -> Option<u64> {
+    self.get_model_list(|model_list| {
+      let from_model = model_list.get(from_hash)?;
+
+      model_list
+        .iter()
+        .filter(|(hash, model)| *hash != from_hash && model.contains_tag(tag))
+        .map(|(hash, model)| (*hash, from_model.distance_to(model)))
+        .min_by(|(_, distance), (_, other_distance)| distance.total_cmp(other_distance))
+        .map(|(hash, _)| hash)
+    })
+  }
+
+  /// Returns the hashes of every model on the given strata.
+  ///
+  /// An empty list is returned when there are no models on that strata.
+  pub fn models_in_strata(&self, strata: Strata) -> Vec<u64> {
+    self
+      .model_storage
+      .read()
+      .unwrap()
+      .get_strata_keys(&strata)
+      .map(|strata_keys| strata_keys.iter().copied().collect())
+      .unwrap_or_default()
+  }
+
+  /// Returns the hashes of every model grouped by strata, from the lowest strata to the highest.
+  ///
+  /// This is the order the printer draws models in, so a custom renderer drawing each group in
+  /// turn matches the engine's layering. Within a strata, the hashes are in the same order the
+  /// printer would draw them for as long as the world is unchanged.
+  ///
+  /// Invisible models are included, which the printer skips.
+  pub fn models_sorted_by_strata(&self) -> Vec<(Strata, Vec<u64>)> {
+    let model_storage = self.model_storage.read().unwrap();
+
+    model_storage
+      .get_stratas()
+      .into_iter()
+      .filter_map(|strata| {
+        let strata_keys = model_storage.get_strata_keys(&strata)?;
+
+        Some((strata, strata_keys.iter().copied().collect()))
+      })
+      .collect()
+  }
+
+  /// Changes the strata of the model, updating the world so the model is drawn on its new strata
+  /// from the next frame onwards.
+  ///
+  /// This should be used over [`ModelData::change_strata`](ModelData::change_strata) for models
+  /// that are already in the world.
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  pub fn change_model_strata(
+    &mut self,
+    model_hash: &u64,
+    new_strata: Strata,
+  ) -> Result<(), ModelError> {
+    self
+      .model_storage
+      .write()
+      .unwrap()
+      .change_model_strata(model_hash, new_strata)
+  }
+
+  /// Returns the hashes of every model whose hitbox overlaps the given region of the world.
+  ///
+  /// Models with an empty hitbox are never within a region.
+  pub fn models_in_region(&self, top_left: (isize, isize), dimensions: Rectangle) -> Vec<u64> {
+    self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .filter(|(_, model)| {
+          let model_hitbox = WorldHitbox::derive(model, model.get_frame_position());
+
+          model_hitbox.dimensions.area() != 0
+            && model_hitbox.dimensions.is_colliding(
+              model_hitbox.world_position(),
+              &dimensions,
+              top_left,
+            )
+        })
+        .map(|(hash, _)| *hash)
+        .collect()
+    })
+  }
+
+  /// Marches a ray from the origin one cell at a time, returning the first model whose hitbox the
+  /// ray enters, along with how many steps it took to reach it.
+  ///
+  /// Each axis of the direction is reduced to its sign, meaning the ray moves horizontally,
+  /// vertically, or diagonally. Models with an empty hitbox, and models whose hitbox contains the
+  /// origin, are never hit. This means a ray cast from a model's world position ignores that model.
+  ///
+  /// When multiple hitboxes cover the first cell the ray enters, the model in the highest strata
+  /// is returned. Models in the same strata are decided by the lowest hash.
+  ///
+  /// None is returned if the direction is (0, 0), or nothing was hit within the max distance.
+  pub fn raycast(
+    &self,
+    origin: (isize, isize),
+    direction: (isize, isize),
+    max_distance: usize,
+  ) -> Option<(u64, usize)> {
+    let step = (direction.0.signum(), direction.1.signum());
+
+    if step == (0, 0) {
+      return None;
+    }
+
+    let cell = Rectangle::new(1, 1);
+
+    self.get_model_list(|model_list| {
+      let mut hitboxes: Vec<(u64, WorldHitbox)> = model_list
+        .iter()
+        .map(|(hash, model)| {
+          (
+            *hash,
+            WorldHitbox::derive(model, model.get_frame_position()),
+          )
+        })
+        .filter(|(_, hitbox)| {
+          hitbox.dimensions.area() != 0
+            && !hitbox
+              .dimensions
+              .is_colliding(hitbox.world_position(), &cell, origin)
+        })
+        .collect();
+      // Ordered so the first hitbox found in a cell is the one that breaks the tie.
+      hitboxes.sort_by_key(|(hash, _)| (std::cmp::Reverse(model_list[hash].get_strata()), *hash));
+
+      (1..=max_distance).find_map(|distance| {
+        let ray_position = (
+          origin.0 + (step.0 * distance as isize),
+          origin.1 + (step.1 * distance as isize),
+        );
+
+        hitboxes
+          .iter()
+          .find(|(_, hitbox)| {
+            hitbox
+              .dimensions
+              .is_colliding(hitbox.world_position(), &cell, ray_position)
+          })
+          .map(|(hash, _)| (*hash, distance))
+      })
+    })
+  }
+
+  /// Finds the shortest path between two cells in the world using A*, moving one cell up, down,
+  /// left, or right at a time.
+  ///
+  /// Every cell covered by a model's hitbox is treated as blocked, except for the hitboxes of
+  /// models in the ignore list. The start cell is never treated as blocked, so a model can path
+  /// out of its own hitbox.
+  ///
+  /// The returned path contains both the start and the goal.
+  /// None is returned if either cell is outside of the world, or the goal can't be reached.
+  pub fn find_path(
+    &self,
+    start: (usize, usize),
+    goal: (usize, usize),
+    ignore: &[u64],
+  ) -> Option<Vec<(usize, usize)>> {
+    let (grid_width, grid_height) = (CONFIG.grid_width as usize, CONFIG.grid_height as usize);
+    let is_in_bounds = |(x, y): (usize, usize)| x < grid_width && y < grid_height;
+
+    if !is_in_bounds(start) || !is_in_bounds(goal) {
+      return None;
+    }
+
+    let blocked_cells: HashSet<(usize, usize)> = self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .filter(|(hash, _)| !ignore.contains(hash))
+        .map(|(_, model)| WorldHitbox::derive(model, model.get_frame_position()))
+        .flat_map(|hitbox| {
+          let (x, y) = hitbox.world_position();
+          let (width, height) = (hitbox.dimensions.x, hitbox.dimensions.y);
+
+          (y..y + height as isize).flat_map(move |y| (x..x + width as isize).map(move |x| (x, y)))
+        })
+        .filter_map(|(x, y)| Some((usize::try_from(x).ok()?, usize::try_from(y).ok()?)))
+        .collect()
+    });
+
+    if blocked_cells.contains(&goal) && start != goal {
+      return None;
+    }
+
+    let heuristic = |(x, y): (usize, usize)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+    let mut open_cells = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+    let mut cheapest_costs = HashMap::from([(start, 0)]);
+    let mut previous_cells: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+    while let Some(Reverse((_, cost, cell))) = open_cells.pop() {
+      if cell == goal {
+        let mut path = vec![goal];
+
+        while let Some(previous_cell) = previous_cells.get(path.last()?) {
+          path.push(*previous_cell);
+        }
+
+        path.reverse();
+
+        return Some(path);
+      }
+
+      // This cell was already reached through a cheaper path.
+      if cheapest_costs
+        .get(&cell)
+        .is_some_and(|cheapest| cost > *cheapest)
+      {
+        continue;
+      }
+
+      let (x, y) = cell;
+      let neighbors = [
+        x.checked_sub(1).map(|x| (x, y)),
+        Some((x + 1, y)),
+        y.checked_sub(1).map(|y| (x, y)),
+        Some((x, y + 1)),
+      ];
+
+      for neighbor in neighbors.into_iter().flatten() {
+        if !is_in_bounds(neighbor) || blocked_cells.contains(&neighbor) {
+          continue;
+        }
+
+        let neighbor_cost = cost + 1;
+
+        if cheapest_costs
+          .get(&neighbor)
+          .is_some_and(|cheapest| neighbor_cost >= *cheapest)
+        {
+          continue;
+        }
+
+        cheapest_costs.insert(neighbor, neighbor_cost);
+        previous_cells.insert(neighbor, cell);
+        open_cells.push(Reverse((
+          neighbor_cost + heuristic(neighbor),
+          neighbor_cost,
+          neighbor,
+        )));
+      }
+    }
+
+    None
+  }
+
   /// Returns true if the model of the given hash exists in the world.
   pub fn model_exists(&self, model_hash: &u64) -> bool {
     self.model_storage.read().unwrap().model_exists(model_hash)
@@ -77,7 +561,34 @@ impl ModelManager {
     model_hash: &u64,
     movement: ModelMovement,
   ) -> Result<Option<ModelCollisions>, ModelError> {
-    let Some(mut model) = self.get_model(model_hash) else {
+    let collision = {
+      let model_storage = self.model_storage.read().unwrap();
+
+      self.move_model_in(&model_storage, model_hash, movement)?
+    };
+
+    if let Some(collision) = &collision {
+      self.add_collision_to_list(collision.clone());
+    }
+
+    Ok(collision)
+  }
+
+  /// Moves the model within the given storage, returning the collisions the movement caused.
+  ///
+  /// The collisions aren't added to the list of collision events, that's left to the caller.
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  /// - When the movement would move the model out of bounds.
+  fn move_model_in(
+    &self,
+    model_storage: &ModelStorage,
+    model_hash: &u64,
+    movement: ModelMovement,
+  ) -> Result<Option<ModelCollisions>, ModelError> {
+    let Some(mut model) = model_storage.get_model(model_hash) else {
       return Err(ModelError::ModelDoesntExist);
     };
 
@@ -85,22 +596,303 @@ impl ModelManager {
       return Err(ModelError::ModelOutOfBounds);
     };
 
+    if let ModelMovement::RelativeF(fractional_movement) = movement {
+      let (_, remainder) = split_fractional_movement(&model, fractional_movement);
+      model.set_movement_remainder(remainder);
+    }
+
     model.change_position(new_position);
-    let collision_list = self.check_collisions_against_all_models(model, None);
+    let collision_list = self.check_collisions_within(model_storage.get_model_list(), &model, None);
+
+    if collision_list.is_empty() {
+      return Ok(None);
+    }
+
+    Ok(Some(ModelCollisions {
+      collider: *model_hash,
+      caused_movement: movement,
+      collision_list,
+    }))
+  }
+
+  /// Moves the model only if the movement wouldn't cause any collisions.
+  ///
+  /// Unlike [`move_model`](ModelManager::move_model), a movement that would collide is cancelled entirely,
+  /// leaving the model where it was. The collisions the movement would've caused are returned, but
+  /// aren't added to the list of collision events as nothing collided.
+  ///
+  /// The world is locked for the entire check and movement, so another thread can't move a model
+  /// into the way in between.
+  ///
+  /// Returns none if the model was moved.
+  ///
+  /// # Errors
+  ///
+  /// - When the movement would move the model out of bounds in the negative direction.
+  /// - When the passed in model doesn't exist.
+  pub fn move_model_checked(
+    &mut self,
+    model_hash: &u64,
+    movement: ModelMovement,
+  ) -> Result<Option<ModelCollisions>, ModelError> {
+    let model_storage = self.model_storage.write().unwrap();
+
+    let Some(model) = model_storage.get_model(model_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    let Some(new_position) = calculate_movement_of_model(&movement, &model) else {
+      return Err(ModelError::ModelOutOfBounds);
+    };
+
+    let collision_list =
+      self.check_collisions_within(model_storage.get_model_list(), &model, Some(new_position));
 
     if !collision_list.is_empty() {
-      let collision = ModelCollisions {
+      return Ok(Some(ModelCollisions {
         collider: *model_hash,
         caused_movement: movement,
         collision_list,
-      };
+      }));
+    }
+
+    self.move_model_in(&model_storage, model_hash, movement)
+  }
+
+  /// Moves every model containing the given tag by the same movement.
+  ///
+  /// The model storage is only locked once for the entire batch. Each model is moved in turn, so
+  /// models moved later will collide with the new positions of models moved before them.
+  ///
+  /// Models that would be moved out of bounds in the negative direction are left where they are.
+  ///
+  /// Returns the hash and collisions of every moved model that collided with something.
+  pub fn move_all_with_tag(
+    &mut self,
+    tag: &str,
+    movement: ModelMovement,
+  ) -> Vec<(u64, ModelCollisions)> {
+    let collisions: Vec<(u64, ModelCollisions)> = self.get_model_list(|model_list| {
+      model_list
+        .values()
+        .filter(|model| model.contains_tag(tag))
+        .filter_map(|model| {
+          let mut model = model.clone();
+          let new_position = calculate_movement_of_model(&movement, &model)?;
+
+          if let ModelMovement::RelativeF(fractional_movement) = movement {
+            let (_, remainder) = split_fractional_movement(&model, fractional_movement);
+            model.set_movement_remainder(remainder);
+          }
+
+          model.change_position(new_position);
+          let collision_list = self.check_collisions_within(model_list, &model, None);
 
+          if collision_list.is_empty() {
+            return None;
+          }
+
+          let collision = ModelCollisions {
+            collider: model.get_hash(),
+            caused_movement: movement,
+            collision_list,
+          };
+
+          Some((model.get_hash(), collision))
+        })
+        .collect()
+    });
+
+    for (_, collision) in &collisions {
       self.add_collision_to_list(collision.clone());
+    }
+
+    collisions
+  }
 
-      Ok(Some(collision))
+  /// Moves the mover so its world position is the target's world position plus the given offset.
+  ///
+  /// Returns the list of model collisions, none if the list was empty.
+  ///
+  /// # Errors
+  ///
+  /// - When either of the passed in models don't exist.
+  /// - When the movement caused the model to move out of bounds in the negative direction.
+  pub fn teleport_model_to_model(
+    &mut self,
+    mover_hash: &u64,
+    target_hash: &u64,
+    offset: (isize, isize),
+  ) -> Result<Option<ModelCollisions>, ModelError> {
+    let Some(target) = self.get_model(target_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    if !self.model_exists(mover_hash) {
+      return Err(ModelError::ModelDoesntExist);
+    }
+
+    let (target_x, target_y) = target.get_world_position();
+    let new_position = (target_x + offset.0, target_y + offset.1);
+
+    if new_position.0 < 0 || new_position.1 < 0 {
+      return Err(ModelError::ModelOutOfBounds);
+    }
+
+    self.move_model(mover_hash, ModelMovement::Absolute(new_position))
+  }
+
+  /// Exchanges the frame positions of the two given models.
+  ///
+  /// Both positions are read and changed under a single lock of the model storage.
+  ///
+  /// This is a raw swap, no collision checks are performed and no collision events are created.
+  /// Should the models differ in size, the caller may want to check for collisions afterwards
+  /// with [`check_if_movement_causes_collisions`](ModelManager::check_if_movement_causes_collisions).
+  ///
+  /// # Errors
+  ///
+  /// - When either of the passed in models don't exist.
+  pub fn swap_positions(&mut self, first_hash: &u64, second_hash: &u64) -> Result<(), ModelError> {
+    self.get_model_list(|model_list| {
+      let (Some(first_model), Some(second_model)) =
+        (model_list.get(first_hash), model_list.get(second_hash))
+      else {
+        return Err(ModelError::ModelDoesntExist);
+      };
+
+      let first_position = first_model.get_frame_position();
+      let second_position = second_model.get_frame_position();
+
+      first_model.clone().change_position(second_position);
+      second_model.clone().change_position(first_position);
+
+      Ok(())
+    })
+  }
+
+  /// Moves the model by the given step, checking the X and Y movement separately.
+  ///
+  /// The X movement is checked first, and is only applied if it caused no collisions.
+  /// The Y movement is then checked from wherever the model ended up, and is also only applied if
+  /// it caused no collisions. This allows a model to slide along anything it runs into.
+  ///
+  /// Returns the models that were collided with on each axis.
+  /// A collision event is created for each axis that collided.
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  /// - When the movement would move the model out of bounds.
+  pub fn move_model_axis_separated(
+    &mut self,
+    model_hash: &u64,
+    movement: (isize, isize),
+  ) -> Result<AxisCollisions, ModelError> {
+    let Some(mut model) = self.get_model(model_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    if calculate_relative_movement_frame_position(&model, &movement).is_none() {
+      return Err(ModelError::ModelOutOfBounds);
+    }
+
+    let mut axis_collisions = AxisCollisions::default();
+    let axis_steps = [(movement.0, 0), (0, movement.1)];
+
+    for (axis_index, axis_step) in axis_steps.into_iter().enumerate() {
+      if axis_step == (0, 0) {
+        continue;
+      }
+
+      let Some(new_position) = calculate_relative_movement_frame_position(&model, &axis_step)
+      else {
+        return Err(ModelError::ModelOutOfBounds);
+      };
+      let collision_list =
+        self.check_collisions_against_all_models(model.clone(), Some(new_position));
+
+      if collision_list.is_empty() {
+        model.change_position(new_position);
+
+        continue;
+      }
+
+      self.add_collision_to_list(ModelCollisions {
+        collider: *model_hash,
+        caused_movement: ModelMovement::Relative(axis_step),
+        collision_list: collision_list.clone(),
+      });
+
+      if axis_index == 0 {
+        axis_collisions.x_collisions = collision_list;
+      } else {
+        axis_collisions.y_collisions = collision_list;
+      }
+    }
+
+    Ok(axis_collisions)
+  }
+
+  /// Pushes the model away from the given world position by the given distance.
+  ///
+  /// The model is moved one space at a time along the axis it's furthest from the given position on.
+  /// Should the next space cause a collision, or move the model out of bounds, the model is stopped
+  /// before moving into that space.
+  ///
+  /// Returns the collision that stopped the model, none if the model moved the full distance.
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  pub fn apply_knockback(
+    &mut self,
+    model_hash: &u64,
+    from: (isize, isize),
+    distance: usize,
+  ) -> Result<Option<ModelCollisions>, ModelError> {
+    let Some(mut model) = self.get_model(model_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    let model_position = model.get_world_position();
+    let direction = (model_position.0 - from.0, model_position.1 - from.1);
+    let step = if direction.0.abs() >= direction.1.abs() {
+      (direction.0.signum(), 0)
     } else {
-      Ok(None)
+      (0, direction.1.signum())
+    };
+
+    if step == (0, 0) {
+      return Ok(None);
+    }
+
+    let movement = ModelMovement::Relative(step);
+
+    for _ in 0..distance {
+      let Some(new_position) = calculate_movement_of_model(&movement, &model) else {
+        break;
+      };
+
+      let collision_list =
+        self.check_collisions_against_all_models(model.clone(), Some(new_position));
+
+      if !collision_list.is_empty() {
+        let collision = ModelCollisions {
+          collider: *model_hash,
+          caused_movement: movement,
+          collision_list,
+        };
+
+        self.add_collision_to_list(collision.clone());
+
+        return Ok(Some(collision));
+      }
+
+      model.change_position(new_position);
     }
+
+    Ok(None)
   }
 
   // TODO: List the errors.
@@ -127,6 +919,73 @@ impl ModelManager {
     }
   }
 
+  /// Returns a human readable report on what would happen if the given model were moved.
+  ///
+  /// The report contains the start and end world positions of the model, along with every other
+  /// model in the world and why it did or didn't register a collision with the moved model.
+  /// The model is not moved, and no collision events are created.
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  /// - When the movement caused the model to move out of bounds in the negative direction.
+  pub fn explain_move(
+    &self,
+    model_hash: &u64,
+    movement: ModelMovement,
+  ) -> Result<String, ModelError> {
+    let Some(model) = self.get_model(model_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    let Some(new_position) = calculate_movement_of_model(&movement, &model) else {
+      return Err(ModelError::ModelOutOfBounds);
+    };
+
+    let start_position = model.get_world_position();
+    let (new_x, new_y) = add_index_to_coordinates((0, 0), new_position);
+    let (old_x, old_y) = add_index_to_coordinates((0, 0), model.get_frame_position());
+    let end_position = (
+      start_position.0 + (new_x - old_x),
+      start_position.1 + (new_y - old_y),
+    );
+
+    let mut report = String::new();
+    let _ = writeln!(
+      report,
+      "Model \"{}\" ({model_hash}) moved with {movement:?}",
+      model.get_name()
+    );
+    let _ = writeln!(report, "Start position: {start_position:?}");
+    let _ = writeln!(report, "End position: {end_position:?}");
+
+    let mut other_models: Vec<ModelData> = self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .filter(|(hash, _)| *hash != model_hash)
+        .map(|(_, model)| model.clone())
+        .collect()
+    });
+    other_models.sort();
+
+    // Hitboxes are derived rather than cached, so the report reflects the current state of every model.
+    let model_hitbox = WorldHitbox::derive(&model, new_position);
+
+    for other_model in other_models {
+      let other_model_hitbox = WorldHitbox::derive(&other_model, other_model.get_frame_position());
+      let result = check_model_collision(&model, &model_hitbox, &other_model, &other_model_hitbox);
+
+      let _ = writeln!(
+        report,
+        "- \"{}\" ({}): {result}",
+        other_model.get_name(),
+        other_model.get_hash()
+      );
+    }
+
+    Ok(report)
+  }
+
   /// Returns a list of all models that the passed in model is colliding with.
   ///
   /// Takes an optional new position for the model for simulated collisions if the model was in that new
@@ -137,27 +996,241 @@ impl ModelManager {
     // Will be changed to coordinates once the world becomes infinite.
     new_model_position: Option<usize>,
   ) -> VecDeque<u64> {
-    let model_id = moving_model.get_hash();
+    if !self.model_exists(&moving_model.get_hash()) {
+      return VecDeque::new();
+    }
+
+    self.get_model_list(|model_list| {
+      self.check_collisions_within(model_list, &moving_model, new_model_position)
+    })
+  }
 
+  /// Returns a list of all models within the given model list that the passed in model is colliding with.
+  ///
+  /// Takes an optional new position for the model for simulated collisions if the model was in that new
+  /// position.
+  fn check_collisions_within(
+    &self,
+    model_list: &HashMap<u64, ModelData>,
+    moving_model: &ModelData,
+    // Will be changed to coordinates once the world becomes infinite.
+    new_model_position: Option<usize>,
+  ) -> VecDeque<u64> {
+    let model_id = moving_model.get_hash();
+    let current_tick = self.event_sync.ticks_since_started();
+    let mut hitbox_cache = self.hitbox_cache.lock().unwrap();
     let mut collision_list = VecDeque::new();
 
-    if self.model_exists(&model_id) {
-      self.get_model_list(|model_list| {
-        for (hash, model_data) in model_list {
-          if hash == &model_id {
-            continue;
-          }
+    let mut moving_model_hitbox = hitbox_cache.get_or_derive(model_id, moving_model, current_tick);
+    if let Some(new_model_position) = new_model_position {
+      moving_model_hitbox =
+        moving_model_hitbox.moved(moving_model.get_frame_position(), new_model_position);
+    }
 
-          if models_are_colliding(&moving_model, new_model_position, model_data) {
-            collision_list.push_front(model_data.get_hash());
-          }
-        }
-      });
+    for (hash, model_data) in model_list {
+      if hash == &model_id {
+        continue;
+      }
+
+      let model_hitbox = hitbox_cache.get_or_derive(*hash, model_data, current_tick);
+      let collision_check = check_model_collision(
+        moving_model,
+        &moving_model_hitbox,
+        model_data,
+        &model_hitbox,
+      );
+
+      if collision_check == CollisionCheck::Overlap {
+        collision_list.push_front(model_data.get_hash());
+      }
     }
 
     collision_list
   }
 
+  /// Spawns a particle, a model with no hitbox that never collides with anything.
+  ///
+  /// Every time [`step_particles`](ModelManager::step_particles) is called, the particle is moved by
+  /// its velocity. Once the particle has existed for its lifetime it is removed from the world on
+  /// the next step.
+  ///
+  /// # Stepping
+  ///
+  /// Particles are never stepped on their own. [`step_particles`](ModelManager::step_particles) has
+  /// to be called once every tick of the game loop, otherwise particles won't move and won't be
+  /// despawned once their lifetime is over.
+  ///
+  /// Particles are placed on the highest strata so they're drawn over every other model.
+  ///
+  /// Returns the hash of the particle's model.
+  ///
+  /// # Errors
+  ///
+  /// - When the particle was placed out of bounds.
+  /// - An error is returned when the world already holds the maximum amount of models.
+  pub fn spawn_particle(
+    &mut self,
+    sprite: Sprite,
+    position: (usize, usize),
+    lifetime_ticks: u64,
+    velocity: (isize, isize),
+  ) -> Result<u64, ModelError> {
+    let mut particle_model = ModelData::new(
+      position,
+      sprite,
+      Hitbox::new(Rectangle::default(), 0),
+      Strata(i32::MAX),
+      "Particle".to_string(),
+    )?;
+    particle_model.set_collision_layer(0);
+    particle_model.set_collision_mask(0);
+    let particle_hash = particle_model.get_hash();
+
+    self.add_models_to_world(vec![particle_model])?;
+
+    let particle = Particle {
+      spawn_tick: self.event_sync.ticks_since_started(),
+      lifetime_ticks,
+      velocity,
+    };
+    self
+      .particles
+      .write()
+      .unwrap()
+      .insert(particle_hash, particle);
+
+    Ok(particle_hash)
+  }
+
+  /// Moves every particle by its velocity, and removes any particle that has outlived its lifetime.
+  ///
+  /// Particles that would move out of bounds are also removed.
+  ///
+  /// This has to be called once every tick for particles to move and despawn.
+  pub fn step_particles(&mut self) {
+    let current_tick = self.event_sync.ticks_since_started();
+    // The storage is locked before the particles, the same as when removing a model.
+    let mut model_storage = self.model_storage.write().unwrap();
+    let mut particles = self.particles.write().unwrap();
+
+    particles.retain(|particle_hash, particle| {
+      let Some(mut particle_model) = model_storage.get_model(particle_hash) else {
+        return false;
+      };
+
+      let new_position =
+        calculate_movement_of_model(&ModelMovement::Relative(particle.velocity), &particle_model);
+
+      match new_position {
+        Some(new_position)
+          if current_tick.saturating_sub(particle.spawn_tick) < particle.lifetime_ticks =>
+        {
+          particle_model.change_position(new_position);
+
+          true
+        }
+        _ => {
+          model_storage.remove(particle_hash);
+
+          false
+        }
+      }
+    });
+  }
+
+  /// Returns true if the model of the given hash is a living particle.
+  pub fn is_particle(&self, model_hash: &u64) -> bool {
+    self.particles.read().unwrap().contains_key(model_hash)
+  }
+
+  /// Attaches the child model to the parent model.
+  ///
+  /// A model can only have one parent, attaching a model that already has a parent will replace it.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when either model doesn't exist.
+  /// - Returns an error when the child is the parent, or the parent is a descendant of the child.
+  pub fn attach_model(&mut self, child_hash: &u64, parent_hash: &u64) -> Result<(), ModelError> {
+    if !self.model_exists(child_hash) || !self.model_exists(parent_hash) {
+      return Err(ModelError::ModelDoesntExist);
+    }
+
+    let mut model_parents = self.model_parents.write().unwrap();
+    let mut ancestor = Some(*parent_hash);
+
+    while let Some(ancestor_hash) = ancestor {
+      if ancestor_hash == *child_hash {
+        return Err(ModelError::CircularAttachment);
+      }
+
+      ancestor = model_parents.get(&ancestor_hash).copied();
+    }
+
+    model_parents.insert(*child_hash, *parent_hash);
+
+    Ok(())
+  }
+
+  /// Detaches the model from its parent.
+  ///
+  /// Returns the hash of the parent the model was attached to, if it had one.
+  pub fn detach_model(&mut self, model_hash: &u64) -> Option<u64> {
+    self.model_parents.write().unwrap().remove(model_hash)
+  }
+
+  /// Returns the parent of the model, if it has one, and the list of models attached to it.
+  pub fn relatives_of(&self, model_hash: &u64) -> (Option<u64>, Vec<u64>) {
+    let model_parents = self.model_parents.read().unwrap();
+    let parent = model_parents.get(model_hash).copied();
+    let children = model_parents
+      .iter()
+      .filter(|(_, parent_hash)| *parent_hash == model_hash)
+      .map(|(child_hash, _)| *child_hash)
+      .collect();
+
+    (parent, children)
+  }
+
+  /// Removes the model, and every model attached to it or its children, from the world.
+  ///
+  /// Returns the list of models that were removed.
+  pub fn despawn_with_children(&mut self, model_hash: &u64) -> Vec<ModelData> {
+    let mut despawn_queue = vec![*model_hash];
+    let mut despawned_models = vec![];
+
+    while let Some(despawn_hash) = despawn_queue.pop() {
+      let (_, children) = self.relatives_of(&despawn_hash);
+      despawn_queue.extend(children);
+
+      if let Some(model) = self.remove_model(&despawn_hash) {
+        despawned_models.push(model);
+      }
+    }
+
+    despawned_models
+  }
+
+  /// Removes every model containing the given tag from the world.
+  ///
+  /// Models attached to a removed model are only removed if they contain the tag themselves.
+  ///
+  /// Returns the list of models that were removed.
+  pub fn despawn_all_with_tag(&mut self, tag: &str) -> Vec<ModelData> {
+    let tagged_hashes: Vec<u64> = self.get_model_list(|model_list| {
+      model_list
+        .iter()
+        .filter(|(_, model)| model.contains_tag(tag))
+        .map(|(hash, _)| *hash)
+        .collect()
+    });
+
+    tagged_hashes
+      .iter()
+      .filter_map(|model_hash| self.remove_model(model_hash))
+      .collect()
+  }
+
   /// Queues the animation of with the given name for the model.
   ///
   /// The animation will be run once all other animations added before it have finished running in the queue.
@@ -217,10 +1290,34 @@ impl ModelManager {
       .map_err(Into::into)
   }
 
+  /// Moves the first instance of the animation in the model's queue to the given index.
+  ///
+  /// Indices past the back of the queue will move the animation to the back.
+  /// The currently running animation isn't disrupted unless the front of the queue changes.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  /// - The animation wasn't in the model's queue
+  pub fn move_queued_animation(
+    &mut self,
+    model_hash: &u64,
+    animation_name: &str,
+    to_index: usize,
+  ) -> Result<(), ModelError> {
+    let model_appearance = self.get_model_appearance(model_hash)?;
+    let mut model_appearance = model_appearance.lock().unwrap();
+
+    model_appearance.move_queued_animation(animation_name, to_index)
+  }
+
   /// Adds the animation to the model's list of stored animations.
   ///
+  /// The animation is checked for invalid sprites before anything on the model is changed.
+  ///
   /// # Errors
   ///
+  /// - The animation contained an invalid frame or resting appearance
   /// - There was no model with that hash
   /// - The model already contains an animation with the given name
   pub fn add_animation_to_model(
@@ -229,6 +1326,10 @@ impl ModelManager {
     animation_name: String,
     animation: AnimationFrames,
   ) -> Result<(), ModelError> {
+    if let Err(error_data) = animation.validity_check(&animation_name) {
+      return Err(AnimationError::AnimationValidityCheckFailed(vec![error_data]).into());
+    }
+
     let model_appearance = self.get_model_appearance(model_hash)?;
     let mut model_appearance = model_appearance.lock().unwrap();
 
@@ -253,13 +1354,159 @@ impl ModelManager {
     Ok(())
   }
 
-  pub fn remove_current_model_animation(&mut self, model_hash: &u64) -> Result<(), ModelError> {
-    let model_appearance = self.get_model_appearance(model_hash)?;
-    let mut model_appearance = model_appearance.lock().unwrap();
-
-    model_appearance.remove_current_model_animation();
+  /// Returns the names of every animation in the model's queue, in the order they'll be run.
+  ///
+  /// The first name is the currently running animation.
+  /// An empty list is returned if the model has no animation data.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  pub fn get_animation_queue(&self, model_hash: &u64) -> Result<Vec<String>, ModelError> {
+    self.with_model_appearance(model_hash, |model_appearance| {
+      model_appearance.queued_animation_names()
+    })
+  }
+
+  pub fn remove_current_model_animation(&mut self, model_hash: &u64) -> Result<(), ModelError> {
+    let model_appearance = self.get_model_appearance(model_hash)?;
+    let mut model_appearance = model_appearance.lock().unwrap();
+
+    model_appearance.remove_current_model_animation();
+
+    Ok(())
+  }
+
+  /// Returns true if any model in the world has animations queued or currently running.
+  ///
+  /// This can be used to decide whether the world needs to keep ticking, as a world with no
+  /// running animations won't change appearance on its own.
+  pub fn has_active_animations(&self) -> bool {
+    self.get_model_list(|model_list| {
+      model_list.values().cloned().any(|mut model| {
+        with_appearance(&mut model, |appearance| appearance.has_animations_to_run())
+      })
+    })
+  }
+
+  /// Freezes the animations of every model in the world until
+  /// [`resume_animations`](ModelManager::resume_animations) is called.
+  ///
+  /// Models added to the world after pausing aren't paused.
+  pub fn pause_animations(&mut self) {
+    self.for_each_model_appearance(ModelAppearance::pause_animations);
+  }
+
+  /// Continues running the animations of every model in the world from where they were paused.
+  pub fn resume_animations(&mut self) {
+    self.for_each_model_appearance(ModelAppearance::resume_animations);
+  }
+
+  fn for_each_model_appearance(&self, mut closure: impl FnMut(&mut ModelAppearance)) {
+    self.get_model_list(|model_list| {
+      model_list
+        .values()
+        .cloned()
+        .for_each(|mut model| with_appearance(&mut model, &mut closure))
+    })
+  }
+
+  /// Returns how many ticks have passed since the model's last animation finished running.
+  ///
+  /// 0 is returned if the model currently has animations running.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  /// - The model has yet to finish running an animation
+  pub fn ticks_since_last_animation(&self, model_hash: &u64) -> Result<u64, ModelError> {
+    self
+      .with_model_appearance(model_hash, |model_appearance| {
+        model_appearance.ticks_since_last_animation()
+      })?
+      .ok_or(AnimationError::NoAnimationHasFinished.into())
+  }
+
+  /// Returns true if one of the model's animations finished running since the last time this was
+  /// called for that model.
+  ///
+  /// This can be polled every tick to act on an animation completing, such as despawning a model
+  /// once its death animation has played.
+  ///
+  /// Only animations that run out their duration count. Animations that loop forever will always
+  /// return false, as will animations that were cleared or overwritten before finishing.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  pub fn animation_just_finished(&self, model_hash: &u64) -> Result<bool, ModelError> {
+    self.with_model_appearance(model_hash, |model_appearance| {
+      model_appearance.animation_just_finished()
+    })
+  }
+
+  /// Returns the index of the frame currently being displayed in the model's running animation.
+  ///
+  /// None is returned if the model has no animation running.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  pub fn current_frame_index(&self, model_hash: &u64) -> Result<Option<u64>, ModelError> {
+    self.with_model_appearance(model_hash, |model_appearance| {
+      model_appearance.current_frame_index()
+    })
+  }
+
+  /// Returns how many loops of the model's running animation haven't been completed yet.
+  ///
+  /// The loop currently being displayed counts as remaining until it finishes.
+  ///
+  /// None is returned if the model has no animation running, or the running animation loops forever.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  pub fn animation_loops_remaining(&self, model_hash: &u64) -> Result<Option<u64>, ModelError> {
+    self.with_model_appearance(model_hash, |model_appearance| {
+      model_appearance.animation_loops_remaining()
+    })
+  }
+
+  /// Returns the anchor offset of the frame currently being displayed in the model's running animation.
+  ///
+  /// The offset can be used to temporarily shift the model while that frame is displayed.
+  /// It belongs to the current frame only, and isn't accumulated as frames change.
+  ///
+  /// None is returned if the model has no animation running.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  pub fn current_anchor_offset(
+    &self,
+    model_hash: &u64,
+  ) -> Result<Option<(isize, isize)>, ModelError> {
+    self.with_model_appearance(model_hash, |model_appearance| {
+      model_appearance.current_anchor_offset()
+    })
+  }
+
+  /// Runs the closure on the appearance of the model with the given hash.
+  ///
+  /// # Errors
+  ///
+  /// - There was no model with that hash
+  fn with_model_appearance<T>(
+    &self,
+    model_hash: &u64,
+    closure: impl FnOnce(&mut ModelAppearance) -> T,
+  ) -> Result<T, ModelError> {
+    let mut model = self
+      .get_model(model_hash)
+      .ok_or(ModelError::ModelDoesntExist)?;
 
-    Ok(())
+    Ok(with_appearance(&mut model, closure))
   }
 
   fn get_model_appearance(
@@ -274,20 +1521,94 @@ impl ModelManager {
     )
   }
 
-  /// Returns the keys to every model that exists in the world with any of the given tag(s).
+  /// Returns the keys to every model that exists in the world with all of the given tag(s).
+  ///
+  /// Shorthand for [`get_models_matching_tags`](ModelManager::get_models_matching_tags) with
+  /// [`TagMatch::All`].
   ///
-  /// Takes an option to get every model with exactly the given tags.
+  /// Tags starting with '@' are treated as the name of a tag group defined through
+  /// [`define_tag_group`](ModelManager::define_tag_group), which matches models that contain any of
+  /// the tags in that group.
+  /// If no group with that name exists, the tag is matched as is.
   pub fn get_models_with_tags<S: AsRef<str>>(&self, tags: Vec<S>) -> Vec<u64> {
+    self.get_models_matching_tags(tags, TagMatch::All)
+  }
+
+  /// Returns the keys to every model that exists in the world matching the given tag(s).
+  ///
+  /// With [`TagMatch::All`] a model must contain every given tag, with [`TagMatch::Any`] a model
+  /// only has to contain one of them.
+  ///
+  /// Tags starting with '@' are treated as the name of a tag group defined through
+  /// [`define_tag_group`](ModelManager::define_tag_group), which matches models that contain any of
+  /// the tags in that group.
+  /// If no group with that name exists, the tag is matched as is.
+  pub fn get_models_matching_tags<S: AsRef<str>>(
+    &self,
+    tags: Vec<S>,
+    tag_match: TagMatch,
+  ) -> Vec<u64> {
+    let tag_groups = self.tag_groups.read().unwrap();
+    let tag_options: Vec<Vec<&str>> = tags
+      .iter()
+      .map(AsRef::as_ref)
+      .map(|tag| {
+        tag
+          .strip_prefix('@')
+          .and_then(|group_name| tag_groups.get(group_name))
+          .map(|group| group.iter().map(String::as_str).collect())
+          .unwrap_or_else(|| vec![tag])
+      })
+      .collect();
+
     let model_storage = self.model_storage.read().unwrap();
     let model_list = model_storage.get_model_list();
 
     model_list
       .iter()
-      .filter(|(_, model)| model.contains_tags(&tags))
+      .filter(|(_, model)| {
+        let mut matches = tag_options
+          .iter()
+          .map(|options| options.iter().any(|tag| model.contains_tag(tag)));
+
+        match tag_match {
+          TagMatch::All => matches.all(|matched| matched),
+          TagMatch::Any => matches.any(|matched| matched),
+        }
+      })
       .map(|(hash, _)| *hash)
       .collect()
   }
 
+  /// Defines a group of tags under the given name, replacing any group that already had that name.
+  ///
+  /// When querying for models with [`get_models_with_tags`](ModelManager::get_models_with_tags),
+  /// the group can be referred to as "@group_name", matching models that contain any of its tags.
+  pub fn define_tag_group(&mut self, group: String, tags: Vec<String>) {
+    self.tag_groups.write().unwrap().insert(group, tags);
+  }
+
+  /// Removes each of the given tags from the model.
+  ///
+  /// Tags the model didn't have are ignored.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the model doesn't exist.
+  pub fn remove_tags_from_model<S: AsRef<str>>(
+    &mut self,
+    model_hash: &u64,
+    tags: Vec<S>,
+  ) -> Result<(), ModelError> {
+    let Some(mut model) = self.get_model(model_hash) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+
+    model.remove_tags(tags);
+
+    Ok(())
+  }
+
   /// Returns the list of tags tied to the given model.
   ///
   /// None is returned if the model didn't exist.
@@ -302,6 +1623,57 @@ impl ModelManager {
     std::mem::take(&mut self.collision_events.write().unwrap())
   }
 
+  /// Registers a handler to be run for every collision involving a model with the given tag.
+  ///
+  /// Handlers are only run when calling [`dispatch_collision_events`](ModelManager::dispatch_collision_events).
+  /// Multiple handlers can be registered under the same tag, and are run in the order they were added.
+  pub fn on_collision(&mut self, tag: String, handler: CollisionHandler) {
+    self
+      .collision_handlers
+      .lock()
+      .unwrap()
+      .0
+      .entry(tag)
+      .or_default()
+      .push(handler);
+  }
+
+  /// Drains the collisions that've occurred since the last time they were taken, and runs the
+  /// handlers registered through [`on_collision`](ModelManager::on_collision) on them.
+  ///
+  /// A collision runs the handlers for a tag if the collider, or any model it collided with, has that tag.
+  /// Models that no longer exist in the world are ignored when checking for tags.
+  ///
+  /// Tags are run in alphabetical order, and the handlers under a tag in the order they were added.
+  /// The handlers are taken out of the manager while they run, meaning a handler can register new
+  /// handlers or dispatch again without deadlocking. Handlers registered while dispatching are only
+  /// run on the next dispatch.
+  pub fn dispatch_collision_events(&mut self) {
+    let collision_events = self.take_collision_events();
+    let mut collision_handlers = std::mem::take(&mut *self.collision_handlers.lock().unwrap());
+
+    for (_, collision) in &collision_events {
+      let involved_models: Vec<ModelData> = self.get_model_list(|model_list| {
+        std::iter::once(&collision.collider)
+          .chain(&collision.collision_list)
+          .filter_map(|model_hash| model_list.get(model_hash).cloned())
+          .collect()
+      });
+
+      for (tag, handlers) in collision_handlers.0.iter_mut() {
+        if involved_models.iter().any(|model| model.contains_tag(tag)) {
+          handlers.iter_mut().for_each(|handler| handler(collision));
+        }
+      }
+    }
+
+    self
+      .collision_handlers
+      .lock()
+      .unwrap()
+      .restore(collision_handlers);
+  }
+
   /// Takes a copy of the current collisions that've occurred since the last time the list was drained.
   pub fn clone_collision_events(&self) -> VecDeque<(Instant, ModelCollisions)> {
     self.collision_events.read().unwrap().clone()
@@ -330,54 +1702,359 @@ impl ModelManager {
     (!collision_list.is_empty()).then_some(collision_list)
   }
 
+  /// Caps the amount of collision events that are held at once.
+  ///
+  /// Once the limit is reached, the oldest events are dropped to make room for new ones.
+  /// By default there is no limit.
+  pub fn set_collision_event_limit(&mut self, limit: usize) {
+    *self.collision_event_limit.write().unwrap() = Some(limit);
+  }
+
   /// Adds a collision to the back to the list and creates an Instant of the current time.
+  ///
+  /// If a limit was set through [`set_collision_event_limit`](ModelManager::set_collision_event_limit),
+  /// the oldest events past that limit are dropped.
   fn add_collision_to_list(&mut self, collision: ModelCollisions) {
+    let mut collision_events = self.collision_events.write().unwrap();
+
+    collision_events.push_back((Instant::now(), collision));
+
+    let Some(limit) = *self.collision_event_limit.read().unwrap() else {
+      return;
+    };
+
+    if collision_events.len() > limit {
+      let dropped_event_count = collision_events.len() - limit;
+      collision_events.drain(..dropped_event_count);
+
+      log::warn!(
+        "Dropped {dropped_event_count} collision event(s) after reaching the limit of {limit}."
+      );
+    }
+  }
+}
+
+/// How the tags passed to [`get_models_matching_tags`](ModelManager::get_models_matching_tags)
+/// are matched against a model's tags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagMatch {
+  /// The model must contain every given tag.
+  #[default]
+  All,
+  /// The model must contain at least one of the given tags.
+  Any,
+}
+
+/// The positions, stratas, and tags of every model in a world at a point in time.
+///
+/// Created with [`snapshot`](ModelManager::snapshot), and applied with [`restore`](ModelManager::restore).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorldSnapshot {
+  models: HashMap<u64, ModelSnapshot>,
+}
+
+impl WorldSnapshot {
+  /// Returns the amount of models that were captured in the snapshot.
+  pub fn len(&self) -> usize {
+    self.models.len()
+  }
+
+  /// Returns true if there were no models in the world when the snapshot was taken.
+  pub fn is_empty(&self) -> bool {
+    self.models.is_empty()
+  }
+
+  /// Returns true if the model was in the world when the snapshot was taken.
+  pub fn contains_model(&self, model_hash: &u64) -> bool {
+    self.models.contains_key(model_hash)
+  }
+}
+
+/// The changes that can be made to the world within [`batch`](ModelManager::batch).
+///
+/// Every method acts on the model storage that's already locked by the batch.
+#[derive(Debug)]
+pub struct BatchContext<'a> {
+  model_manager: &'a ModelManager,
+  model_storage: &'a mut ModelStorage,
+  /// The collisions caused within the batch, added to the collision events once it's finished.
+  collisions: Vec<ModelCollisions>,
+}
+
+impl BatchContext<'_> {
+  /// Returns a copy of the Model with the given hash.
+  ///
+  /// None is returned if there was no model in the world with the given hash.
+  pub fn get_model(&self, model_hash: &u64) -> Option<ModelData> {
+    self.model_storage.get_model(model_hash)
+  }
+
+  /// Moves the model, the same as [`ModelManager::move_model`](ModelManager::move_model).
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  /// - When the movement would move the model out of bounds.
+  pub fn move_model(
+    &mut self,
+    model_hash: &u64,
+    movement: ModelMovement,
+  ) -> Result<Option<ModelCollisions>, ModelError> {
+    let collision = self
+      .model_manager
+      .move_model_in(self.model_storage, model_hash, movement)?;
+
+    if let Some(collision) = &collision {
+      self.collisions.push(collision.clone());
+    }
+
+    Ok(collision)
+  }
+
+  /// Changes the strata of the model, the same as
+  /// [`ModelManager::change_model_strata`](ModelManager::change_model_strata).
+  ///
+  /// # Errors
+  ///
+  /// - When the passed in model doesn't exist.
+  pub fn change_model_strata(
+    &mut self,
+    model_hash: &u64,
+    new_strata: Strata,
+  ) -> Result<(), ModelError> {
     self
-      .collision_events
-      .write()
-      .unwrap()
-      .push_back((Instant::now(), collision))
+      .model_storage
+      .change_model_strata(model_hash, new_strata)
+  }
+
+  /// Removes the model from the world, the same as [`ModelManager::remove_model`](ModelManager::remove_model).
+  ///
+  /// Returns the model if it existed.
+  pub fn remove_model(&mut self, model_hash: &u64) -> Option<ModelData> {
+    self
+      .model_manager
+      .remove_model_from(self.model_storage, model_hash)
+  }
+}
+
+/// The data of a single model stored in a [`WorldSnapshot`](WorldSnapshot).
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ModelSnapshot {
+  frame_position: usize,
+  strata: Strata,
+  tags: HashSet<String>,
+}
+
+/// The reason two models did or didn't collide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollisionCheck {
+  EmptyHitbox,
+  LayerMismatch,
+  NoOverlap,
+  Overlap,
+}
+
+impl std::fmt::Display for CollisionCheck {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let reason = match self {
+      CollisionCheck::EmptyHitbox => "no collision, a hitbox is empty",
+      CollisionCheck::LayerMismatch => "no collision, collision layers don't match",
+      CollisionCheck::NoOverlap => "no collision, hitboxes don't overlap",
+      CollisionCheck::Overlap => "collision, hitboxes overlap",
+    };
+
+    write!(f, "{reason}")
+  }
+}
+
+/// The collision data shared between every [`ModelManager`](ModelManager) of a screen.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CollisionState {
+  pub(crate) collision_events: Arc<RwLock<VecDeque<(Instant, ModelCollisions)>>>,
+  pub(crate) collision_event_limit: Arc<RwLock<Option<usize>>>,
+  pub(crate) collision_handlers: Arc<Mutex<CollisionHandlers>>,
+}
+
+/// A function run on collisions, registered through [`on_collision`](ModelManager::on_collision).
+pub type CollisionHandler = Box<dyn FnMut(&ModelCollisions) + Send>;
+
+/// The handlers registered through [`on_collision`](ModelManager::on_collision), keyed by tag.
+///
+/// Tags are kept in alphabetical order so handlers are always dispatched in the same order.
+#[derive(Default)]
+pub(crate) struct CollisionHandlers(BTreeMap<String, Vec<CollisionHandler>>);
+
+impl CollisionHandlers {
+  /// Puts back the handlers that were taken out for dispatching.
+  ///
+  /// Any handlers registered while the taken ones were running are placed after them.
+  fn restore(&mut self, taken_handlers: CollisionHandlers) {
+    let registered_handlers = std::mem::replace(&mut self.0, taken_handlers.0);
+
+    for (tag, handlers) in registered_handlers {
+      self.0.entry(tag).or_default().extend(handlers);
+    }
+  }
+}
+
+impl std::fmt::Debug for CollisionHandlers {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let handler_counts: BTreeMap<&String, usize> = self
+      .0
+      .iter()
+      .map(|(tag, handlers)| (tag, handlers.len()))
+      .collect();
+
+    f.debug_tuple("CollisionHandlers")
+      .field(&handler_counts)
+      .finish()
+  }
+}
+
+/// The data of a particle spawned through [`spawn_particle`](ModelManager::spawn_particle).
+#[derive(Debug, Clone)]
+pub(crate) struct Particle {
+  spawn_tick: u64,
+  lifetime_ticks: u64,
+  velocity: (isize, isize),
+}
+
+/// A model's hitbox placed in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WorldHitbox {
+  position: (isize, isize),
+  dimensions: Rectangle,
+}
+
+impl WorldHitbox {
+  /// Derives the hitbox of the model as if its top left was at the given frame position.
+  fn derive(model: &ModelData, frame_position: usize) -> Self {
+    // An empty hitbox has no anchor to place it with.
+    if model.hitbox_is_empty() {
+      return Self {
+        position: add_index_to_coordinates((0, 0), frame_position),
+        dimensions: Rectangle::default(),
+      };
+    }
+
+    Self {
+      position: add_index_to_coordinates(
+        model.sprite_to_hitbox_anchor_difference(),
+        frame_position,
+      ),
+      dimensions: model.get_hitbox_dimensions(),
+    }
+  }
+
+  /// Returns the top left of the hitbox as a world position.
+  fn world_position(&self) -> (isize, isize) {
+    frame_to_world(self.position)
+  }
+
+  /// Returns a copy of the hitbox as if its model was moved between the given frame positions.
+  fn moved(&self, from_frame_position: usize, to_frame_position: usize) -> Self {
+    let (from_x, from_y) = add_index_to_coordinates((0, 0), from_frame_position);
+    let (to_x, to_y) = add_index_to_coordinates((0, 0), to_frame_position);
+
+    Self {
+      position: (
+        self.position.0 + (to_x - from_x),
+        self.position.1 + (to_y - from_y),
+      ),
+      dimensions: self.dimensions,
+    }
+  }
+}
+
+/// Holds the world hitbox of each model for the current tick.
+///
+/// A model's hitbox is derived again once the tick changes, or when the model has moved, had
+/// its hitbox changed, or had its sprite's anchor moved since it was cached.
+#[derive(Debug, Default)]
+struct HitboxCache {
+  tick: Option<u64>,
+  hitboxes: HashMap<u64, (HitboxPlacement, WorldHitbox)>,
+  #[cfg(test)]
+  derivation_count: usize,
+}
+
+impl HitboxCache {
+  /// Returns the cached hitbox of the model, deriving it if it was missing or outdated.
+  ///
+  /// Checking the cache only reads the model's [`HitboxPlacement`](HitboxPlacement), which is
+  /// cheaper than deriving the hitbox as it doesn't copy the sprite.
+  fn get_or_derive(
+    &mut self,
+    model_hash: u64,
+    model: &ModelData,
+    current_tick: u64,
+  ) -> WorldHitbox {
+    if self.tick != Some(current_tick) {
+      self.hitboxes.clear();
+      self.tick = Some(current_tick);
+    }
+
+    let placement = model.hitbox_placement();
+
+    if let Some((cached_placement, hitbox)) = self.hitboxes.get(&model_hash) {
+      if cached_placement == &placement {
+        return *hitbox;
+      }
+    }
+
+    let hitbox = WorldHitbox::derive(model, placement.frame_position);
+    self.hitboxes.insert(model_hash, (placement, hitbox));
+
+    #[cfg(test)]
+    {
+      self.derivation_count += 1;
+    }
+
+    hitbox
   }
 }
 
-fn models_are_colliding(
+/// Locks the model's appearance and runs the closure on it.
+fn with_appearance<T>(model: &mut ModelData, closure: impl FnOnce(&mut ModelAppearance) -> T) -> T {
+  let model_appearance = model.get_appearance_data();
+  let mut model_appearance = model_appearance.lock().unwrap();
+
+  closure(&mut model_appearance)
+}
+
+/// Checks if the two models' hitboxes are colliding, returning the reason they are or aren't.
+fn check_model_collision(
   model_one: &ModelData,
-  new_model_one_position: Option<usize>,
+  model_one_hitbox: &WorldHitbox,
   model_two: &ModelData,
-) -> bool {
-  if model_one.hitbox_is_empty() || model_two.hitbox_is_empty() {
-    return false;
+  model_two_hitbox: &WorldHitbox,
+) -> CollisionCheck {
+  if model_one_hitbox.dimensions.area() == 0 || model_two_hitbox.dimensions.area() == 0 {
+    return CollisionCheck::EmptyHitbox;
   }
 
-  let model_one_index = match new_model_one_position {
-    Some(index) => index,
-    None => model_one.get_frame_position(),
-  };
+  if !model_one.collision_layers_overlap(model_two) {
+    return CollisionCheck::LayerMismatch;
+  }
 
-  let model_one_hitbox_position = add_index_to_coordinates(
-    model_one.sprite_to_hitbox_anchor_difference(),
-    model_one_index,
-  );
-  let model_two_hitbox_position = add_index_to_coordinates(
-    model_two.sprite_to_hitbox_anchor_difference(),
-    model_two.get_frame_position(),
+  let is_colliding = model_one_hitbox.dimensions.is_colliding(
+    model_one_hitbox.position,
+    &model_two_hitbox.dimensions,
+    model_two_hitbox.position,
   );
 
-  let model_one_hitbox_dimensions = model_one.get_hitbox_dimensions();
-  let model_two_hitbox_dimensions = model_two.get_hitbox_dimensions();
-
-  model_one_hitbox_dimensions.is_colliding(
-    model_one_hitbox_position,
-    &model_two_hitbox_dimensions,
-    model_two_hitbox_position,
-  )
+  if is_colliding {
+    CollisionCheck::Overlap
+  } else {
+    CollisionCheck::NoOverlap
+  }
 }
 
 fn calculate_relative_movement_frame_position(
   model: &ModelData,
   added_position: &(isize, isize),
 ) -> Option<usize> {
-  let screen_width = CONFIG.grid_width as isize + 1;
+  let screen_width = CONFIG.frame_width() as isize;
   let model_frame_top_left = model.get_frame_position() as isize;
 
   let new_position = added_position.0 + (screen_width * added_position.1) + model_frame_top_left;
@@ -389,12 +2066,36 @@ fn calculate_relative_movement_frame_position(
   }
 }
 
+/// Converts coordinates in a frame into the world position drawn there.
+///
+/// Frames are one further along the x axis than world positions, as the x axis of a world position
+/// doesn't account for the new line at the end of each row.
+/// This can be used with the frames from [`ScreenData::capture_frame`](crate::screen::screen_data::ScreenData::capture_frame).
+pub fn frame_to_world((x, y): (isize, isize)) -> (isize, isize) {
+  (x - 1, y)
+}
+
 fn add_index_to_coordinates(coordinates: (isize, isize), index: usize) -> (isize, isize) {
-  let (x, y) = index.index_to_coordinates(CONFIG.grid_width as usize + 1);
+  let (x, y) = index.index_to_coordinates(CONFIG.frame_width());
 
   (x as isize + coordinates.0, y as isize + coordinates.1)
 }
 
+/// Returns true if the point is within the radius of the center.
+///
+/// The y axis is scaled by the aspect ratio to account for characters being taller than they are wide.
+fn is_within_radius(
+  center: (isize, isize),
+  point: (isize, isize),
+  radius: f32,
+  aspect_ratio: f32,
+) -> bool {
+  let x_distance = (point.0 - center.0) as f32;
+  let y_distance = (point.1 - center.1) as f32 * aspect_ratio;
+
+  x_distance.powi(2) + y_distance.powi(2) <= radius.powi(2)
+}
+
 /// Returns the new frame position of the model based on the movement.
 ///
 /// None is returned if the movement caused the top left of the model to go negative.
@@ -410,9 +2111,32 @@ fn calculate_movement_of_model(movement: &ModelMovement, model: &ModelData) -> O
     ModelMovement::Relative(movement) => {
       calculate_relative_movement_frame_position(model, movement)
     }
+
+    ModelMovement::RelativeF(movement) => {
+      let (whole_movement, _) = split_fractional_movement(model, *movement);
+
+      calculate_relative_movement_frame_position(model, &whole_movement)
+    }
   }
 }
 
+/// Adds the fractional movement to the model's movement remainder.
+///
+/// Returns the whole cells to move by, and the fraction of a cell left over.
+fn split_fractional_movement(
+  model: &ModelData,
+  movement: (f32, f32),
+) -> ((isize, isize), (f32, f32)) {
+  let (remainder_x, remainder_y) = model.get_movement_remainder();
+  let total_x = remainder_x + movement.0;
+  let total_y = remainder_y + movement.1;
+
+  (
+    (total_x.trunc() as isize, total_y.trunc() as isize),
+    (total_x.fract(), total_y.fract()),
+  )
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -427,4 +2151,174 @@ mod tests {
 
     assert!(result.is_none());
   }
+
+  #[cfg(test)]
+  mod is_within_radius_logic {
+    use super::*;
+
+    #[test]
+    fn naive_circle() {
+      let center = (20, 20);
+
+      assert!(is_within_radius(center, (26, 20), 6.0, 1.0));
+      assert!(!is_within_radius(center, (20, 28), 6.0, 1.0));
+    }
+
+    #[test]
+    fn scaled_circle_includes_points_a_naive_circle_excludes() {
+      let center = (20, 20);
+      // 8 rows away is 4 characters when rows are half as tall as a character is wide.
+      let point = (20, 28);
+
+      assert!(!is_within_radius(center, point, 6.0, 1.0));
+      assert!(is_within_radius(center, point, 6.0, 0.5));
+    }
+
+    #[test]
+    fn scaled_circle_excludes_points_a_naive_circle_includes() {
+      let center = (20, 20);
+      // 4 rows away is 8 characters when rows are twice as tall as a character is wide.
+      let point = (20, 24);
+
+      assert!(is_within_radius(center, point, 6.0, 1.0));
+      assert!(!is_within_radius(center, point, 6.0, 2.0));
+    }
+
+    #[test]
+    fn aspect_ratio_only_scales_the_y_axis() {
+      let center = (20, 20);
+      let aspect_ratio = 2.0;
+
+      assert!(is_within_radius(center, (26, 20), 6.0, aspect_ratio));
+      assert!(!is_within_radius(center, (27, 20), 6.0, aspect_ratio));
+      assert!(is_within_radius(center, (20, 23), 6.0, aspect_ratio));
+      assert!(!is_within_radius(center, (20, 24), 6.0, aspect_ratio));
+      assert!(!is_within_radius(center, (20, 8), 6.0, aspect_ratio));
+    }
+  }
+
+  #[cfg(test)]
+  mod hitbox_cache_logic {
+    use super::*;
+
+    /// Creates a ModelManager with a tick long enough for every check in a test to share it.
+    fn setup_model_manager(models: Vec<ModelData>) -> ModelManager {
+      let model_storage = Arc::new(RwLock::new(ModelStorage::from(models)));
+
+      ModelManager::new(
+        model_storage,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        EventSync::new(10_000).clone_immutable(),
+      )
+    }
+
+    #[test]
+    fn cached_results_match_uncached_results() {
+      let models = vec![
+        TestingData::new_test_model((10, 10)),
+        TestingData::new_test_model((13, 10)),
+        TestingData::new_test_model((10, 12)),
+        TestingData::new_test_model((30, 20)),
+      ];
+      let model_manager = setup_model_manager(models.clone());
+
+      for model in &models {
+        let mut expected_collisions: Vec<u64> = models
+          .iter()
+          .filter(|other_model| *other_model != model)
+          .filter(|other_model| {
+            let model_hitbox = WorldHitbox::derive(model, model.get_frame_position());
+            let other_hitbox = WorldHitbox::derive(other_model, other_model.get_frame_position());
+
+            check_model_collision(model, &model_hitbox, other_model, &other_hitbox)
+              == CollisionCheck::Overlap
+          })
+          .map(ModelData::get_hash)
+          .collect();
+        expected_collisions.sort();
+
+        let mut collisions: Vec<u64> = model_manager
+          .check_collisions_against_all_models(model.clone(), None)
+          .into();
+        collisions.sort();
+
+        assert_eq!(collisions, expected_collisions);
+      }
+    }
+
+    #[test]
+    fn hitboxes_derived_once_per_tick() {
+      let models = TestingData::get_multiple_test_models((10, 10), 4);
+      let mut model_manager = setup_model_manager(models.clone());
+      let no_movement = ModelMovement::Relative((0, 0));
+
+      for model in &models {
+        model_manager
+          .check_if_movement_causes_collisions(&model.get_hash(), no_movement)
+          .unwrap();
+      }
+      let derivations_after_checks = model_manager.hitbox_cache.lock().unwrap().derivation_count;
+
+      model_manager
+        .move_model(&models[0].get_hash(), ModelMovement::Relative((1, 0)))
+        .unwrap();
+      let derivations_after_movement = model_manager.hitbox_cache.lock().unwrap().derivation_count;
+
+      assert_eq!(derivations_after_checks, models.len());
+      // Only the moved model's hitbox is derived again.
+      assert_eq!(derivations_after_movement, models.len() + 1);
+    }
+
+    #[test]
+    fn cached_hitbox_is_cheaper_than_deriving() {
+      const LOOKUP_COUNT: usize = 50;
+      // Deriving a hitbox copies the model's sprite, which a large sprite makes stand out.
+      let mut sprite_rows = vec!["x".repeat(1000); 500];
+      sprite_rows[0].replace_range(0..1, "a");
+      let sprite = Sprite::new(sprite_rows.join("\n"), 'a', 'x', '-').unwrap();
+      let hitbox = Hitbox::from_dimensions(5, 3, 0).unwrap();
+      let model = ModelData::new((10, 10), sprite, hitbox, Strata(0), "large".into()).unwrap();
+      let model_hash = model.get_hash();
+      let mut hitbox_cache = HitboxCache::default();
+      hitbox_cache.get_or_derive(model_hash, &model, 0);
+
+      let uncached_start = Instant::now();
+      for _ in 0..LOOKUP_COUNT {
+        WorldHitbox::derive(&model, model.get_frame_position());
+      }
+      let uncached_duration = uncached_start.elapsed();
+
+      let cached_start = Instant::now();
+      for _ in 0..LOOKUP_COUNT {
+        hitbox_cache.get_or_derive(model_hash, &model, 0);
+      }
+      let cached_duration = cached_start.elapsed();
+
+      assert_eq!(hitbox_cache.derivation_count, 1);
+      assert!(cached_duration < uncached_duration);
+    }
+
+    #[test]
+    fn sprite_swap_derives_hitbox_again() {
+      let mut model = TestingData::new_test_model((10, 10));
+      let model_manager = setup_model_manager(vec![model.clone()]);
+      let mut hitbox_cache = model_manager.hitbox_cache.lock().unwrap();
+
+      hitbox_cache.get_or_derive(model.get_hash(), &model, 0);
+      model
+        .get_appearance_data()
+        .lock()
+        .unwrap()
+        .update_default_sprite(Sprite::new("axxxx\nxxxxx\nxxxxx", 'a', 'x', '-').unwrap());
+
+      let expected_hitbox = WorldHitbox::derive(&model, model.get_frame_position());
+
+      let hitbox = hitbox_cache.get_or_derive(model.get_hash(), &model, 0);
+
+      assert_eq!(hitbox, expected_hitbox);
+    }
+  }
 }
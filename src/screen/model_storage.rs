@@ -3,7 +3,7 @@ use crate::screen::stored_worlds::*;
 use log::{error, info, warn};
 use model_data_structures::models::model_data::*;
 use model_data_structures::models::strata::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 /// This is the struct that contains a reference to every model that exists in the world.
@@ -11,6 +11,8 @@ use std::sync::{Arc, RwLock, RwLockReadGuard};
 pub(crate) struct ModelStorage {
   model_stratas: HashMap<Strata, HashSet<u64>>,
   models: HashMap<u64, ModelData>,
+  /// The most models that can exist in the world at once. None means there's no limit.
+  max_models: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -24,11 +26,21 @@ impl ModelStorage {
   /// # Errors
   ///
   /// - An error is returned when attempting to add a model that already exists.
+  /// - An error is returned when the world already holds the maximum amount of models.
   pub fn insert(&mut self, model: ModelData) -> Result<(), ModelError> {
     let key = model.get_hash();
 
-    if self.models.get(&key).is_none() {
-      self.models.insert(key, model);
+    if self
+      .max_models
+      .is_some_and(|max_models| self.models.len() >= max_models)
+    {
+      warn!("Attempted insert of model {key}, but the model limit was reached.");
+
+      return Err(ModelError::ModelLimitReached);
+    }
+
+    if let Entry::Vacant(entry) = self.models.entry(key) {
+      entry.insert(model);
 
       self.insert_strata(&key)?;
     } else {
@@ -57,6 +69,18 @@ impl ModelStorage {
     }
   }
 
+  /// Assigns the most models that can exist in the world at once.
+  ///
+  /// Models that already exist are kept even if there's more than the new limit.
+  pub fn set_max_models(&mut self, max_models: Option<usize>) {
+    self.max_models = max_models;
+  }
+
+  /// Returns the most models that can exist in the world at once.
+  pub fn get_max_models(&self) -> Option<usize> {
+    self.max_models
+  }
+
   /// Returns true if the model exists in both the ModelData list,
   /// and the Strata list pertaining to it's currently assigned strata.
   ///
@@ -94,6 +118,14 @@ impl ModelStorage {
     self.model_stratas.get(key)
   }
 
+  /// Returns every strata that currently contains a model, from the lowest to the highest.
+  pub fn get_stratas(&self) -> Vec<Strata> {
+    let mut stratas: Vec<Strata> = self.model_stratas.keys().copied().collect();
+    stratas.sort();
+
+    stratas
+  }
+
   /// Returns a copy of the requested ModelData.
   ///
   /// Returns None when the model doesn't exist.
@@ -158,9 +190,7 @@ impl ModelStorage {
   ///
   /// - Returns an error when a model somehow has an impossible strata.
   pub fn fix_strata_list(&mut self) -> Result<(), ModelError> {
-    for strata_number in 0..=100 {
-      let current_strata = Strata(strata_number);
-
+    for current_strata in self.get_stratas() {
       let Some(strata_keys) = self.get_strata_keys(&current_strata) else {
         continue;
       };
@@ -192,6 +222,30 @@ impl ModelStorage {
     Ok(())
   }
 
+  /// Changes the strata of the model, moving it to the new strata's list right away.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error when the model doesn't exist.
+  /// - Returns an error when the new strata is in an impossible range.
+  pub fn change_model_strata(&mut self, key: &u64, new_strata: Strata) -> Result<(), ModelError> {
+    let Some(model) = self.models.get_mut(key) else {
+      return Err(ModelError::ModelDoesntExist);
+    };
+    let old_strata = model.get_strata();
+
+    model.change_strata(new_strata)?;
+
+    if old_strata == new_strata {
+      return Ok(());
+    }
+
+    // The model won't be in its old strata's list if its strata was changed without the storage.
+    self
+      .fix_model_strata(key, old_strata, new_strata)
+      .or_else(|_| self.fix_strata_list())
+  }
+
   /// Removes the given model_hash from 'old_strata' and moves it to 'new_strata'
   ///
   /// # Errors
@@ -229,7 +283,7 @@ impl ModelStorage {
 }
 
 impl ReadOnlyModelStorage {
-  pub fn read_model_storage(&self) -> RwLockReadGuard<ModelStorage> {
+  pub fn read_model_storage(&self) -> RwLockReadGuard<'_, ModelStorage> {
     self.model_storage.read().unwrap()
   }
 }
@@ -335,6 +389,21 @@ mod tests {
       assert!(strata_keys.contains(&model_data.get_hash()));
     }
 
+    #[test]
+    fn get_stratas_in_order() {
+      let mut model_list = ModelStorage::default();
+      let mut background_model = TestingData::new_test_model(WORLD_POSITION);
+      let foreground_model = TestingData::new_test_model(WORLD_POSITION);
+      background_model.change_strata(Strata(-5)).unwrap();
+
+      model_list.insert(foreground_model.clone()).unwrap();
+      model_list.insert(background_model).unwrap();
+
+      let stratas = model_list.get_stratas();
+
+      assert_eq!(stratas, vec![Strata(-5), foreground_model.get_strata()]);
+    }
+
     #[test]
     fn get_existing_model() {
       let mut model_list = ModelStorage::default();
@@ -433,6 +502,61 @@ mod tests {
     }
   }
 
+  #[cfg(test)]
+  mod change_model_strata_logic {
+    use super::*;
+
+    #[test]
+    fn model_is_moved() {
+      let mut model_list = ModelStorage::default();
+      let model_data = TestingData::new_test_model(WORLD_POSITION);
+      let model_hash = model_data.get_hash();
+      let old_strata = model_data.get_strata();
+      model_list.insert(model_data.clone()).unwrap();
+
+      model_list
+        .change_model_strata(&model_hash, Strata(-3))
+        .unwrap();
+
+      assert_eq!(model_data.get_strata(), Strata(-3));
+      assert!(model_list.get_strata_keys(&old_strata).is_none());
+      assert!(model_list
+        .get_strata_keys(&Strata(-3))
+        .unwrap()
+        .contains(&model_hash));
+    }
+
+    #[test]
+    fn strata_changed_outside_of_storage() {
+      let mut model_list = ModelStorage::default();
+      let mut model_data = TestingData::new_test_model(WORLD_POSITION);
+      let model_hash = model_data.get_hash();
+      let old_strata = model_data.get_strata();
+      model_list.insert(model_data.clone()).unwrap();
+      model_data.change_strata(Strata(50)).unwrap();
+
+      model_list
+        .change_model_strata(&model_hash, Strata(60))
+        .unwrap();
+
+      assert!(model_list.get_strata_keys(&old_strata).is_none());
+      assert!(model_list.get_strata_keys(&Strata(50)).is_none());
+      assert!(model_list
+        .get_strata_keys(&Strata(60))
+        .unwrap()
+        .contains(&model_hash));
+    }
+
+    #[test]
+    fn model_doesnt_exist() {
+      let mut model_list = ModelStorage::default();
+
+      let result = model_list.change_model_strata(&0, Strata(10));
+
+      assert_eq!(result, Err(ModelError::ModelDoesntExist));
+    }
+  }
+
   #[cfg(test)]
   mod fix_strata_list_logic {
     use super::*;
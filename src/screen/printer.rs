@@ -1,35 +1,158 @@
 use super::model_storage::ReadOnlyModelStorage;
 use crate::CONFIG;
-use model_data_structures::{
-  errors::*,
-  models::{model_data::*, strata::*},
-};
+use event_sync::{EventSync, Immutable};
+use model_data_structures::{errors::*, models::model_data::*};
 use screen_printer::printer::*;
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// The tick the screen was last painted on, and the frame that was painted.
+#[derive(Debug)]
+struct LastPaint {
+  tick: u64,
+  frame: String,
+}
 
 #[derive(Debug, Clone)]
 pub struct ScreenPrinter {
   printer: Arc<Mutex<Printer>>,
   model_storage: ReadOnlyModelStorage,
+  event_sync: EventSync<Immutable>,
+  /// The tick the screen was last painted on, along with the frame that was painted.
+  ///
+  /// Holding this lock is required to paint, which keeps concurrent prints from interleaving.
+  last_paint: Arc<Mutex<Option<LastPaint>>>,
+  /// The character placed in every cell that has no visible model in it.
+  empty_pixel: Arc<RwLock<char>>,
 }
 
 impl ScreenPrinter {
-  pub(crate) fn new(printer: Arc<Mutex<Printer>>, model_storage: ReadOnlyModelStorage) -> Self {
+  pub(crate) fn new(
+    printer: Arc<Mutex<Printer>>,
+    model_storage: ReadOnlyModelStorage,
+    event_sync: EventSync<Immutable>,
+  ) -> Self {
     Self {
       printer,
       model_storage,
+      event_sync,
+      last_paint: Arc::new(Mutex::new(None)),
+      empty_pixel: Arc::new(RwLock::new(Self::default_empty_pixel())),
     }
   }
 
-  // TODO: list the errors
+  /// Changes the character placed in every cell that has no visible model in it.
+  ///
+  /// This is shared between every copy of this printer, and applies from the next frame built.
+  /// The default is the empty pixel assigned in the config file.
+  pub fn set_empty_pixel(&mut self, empty_pixel: char) {
+    *self.empty_pixel.write().unwrap() = empty_pixel;
+  }
+
+  /// Returns a frame the size of the grid, filled with this printer's empty pixel.
+  pub(crate) fn blank_frame(&self) -> String {
+    Self::create_blank_frame(*self.empty_pixel.read().unwrap())
+  }
+
+  /// Prints the screen as it currently is.
+  ///
+  /// The first print draws the entire frame, every print after that only redraws the characters
+  /// that changed since the previous frame.
+  ///
+  /// Any calls made within the same tick, from any thread, are coalesced into a single repaint.
+  /// A call is only skipped if the screen was already painted this tick with the same frame, so
+  /// changes made after the first print in a tick still reach the screen.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error if the printer failed to print the frame.
   #[cfg(not(tarpaulin_include))]
   pub fn print_screen(&mut self) -> Result<(), ScreenError> {
-    let frame = self.display();
+    self.coalesced_paint(|frame| {
+      if let Err(error) = self.printer.lock().unwrap().dynamic_print(frame) {
+        return Err(ScreenError::PrintingError(error));
+      }
+
+      Ok(())
+    })
+  }
 
-    if let Err(error) = self.printer.lock().unwrap().dynamic_print(frame) {
-      return Err(ScreenError::PrintingError(error));
+  /// Prints the entire screen as it currently is, without diffing against the previous frame.
+  ///
+  /// The frame is printed as plain text without any cursor movement, for terminals that don't
+  /// support cursor addressing.
+  /// The next call to [`print_screen`](ScreenPrinter::print_screen) will draw the entire frame again
+  /// before it goes back to diffing.
+  ///
+  /// Unlike [`print_screen`](ScreenPrinter::print_screen), this always redraws the screen, even if
+  /// it was already painted this tick.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error if the frame failed to be built.
+  #[cfg(not(tarpaulin_include))]
+  pub fn print_screen_full(&mut self) -> Result<(), ScreenError> {
+    self.exclusive_paint(true, |frame| {
+      // The previous grid held by the printer no longer matches what's on the terminal.
+      self
+        .printer
+        .lock()
+        .unwrap()
+        .reset_and_retain_printing_position();
+
+      println!("{frame}");
+
+      Ok(())
+    })
+  }
+
+  /// Builds the frame that would be printed by [`print_screen`](ScreenPrinter::print_screen),
+  /// without printing it.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error if the frame failed to be built.
+  pub fn print_screen_to_string(&self) -> Result<String, ScreenError> {
+    Ok(self.display())
+  }
+
+  /// Builds the current frame and runs the paint method with it, unless the same frame was
+  /// already painted this tick.
+  fn coalesced_paint<F>(&self, paint: F) -> Result<(), ScreenError>
+  where
+    F: FnOnce(String) -> Result<(), ScreenError>,
+  {
+    self.exclusive_paint(false, paint)
+  }
+
+  /// Builds the current frame and runs the paint method with it.
+  ///
+  /// When not forced, the paint is skipped if the same frame was already painted this tick.
+  /// The lock on the last paint is held while painting, so only one thread can paint at a time.
+  fn exclusive_paint<F>(&self, force: bool, paint: F) -> Result<(), ScreenError>
+  where
+    F: FnOnce(String) -> Result<(), ScreenError>,
+  {
+    let mut last_paint = self.last_paint.lock().unwrap();
+    let current_tick = self.event_sync.ticks_since_started();
+    let frame = self.print_screen_to_string()?;
+
+    let already_painted = last_paint
+      .as_ref()
+      .is_some_and(|last_paint| last_paint.tick == current_tick && last_paint.frame == frame);
+
+    if already_painted && !force {
+      return Ok(());
     }
 
+    paint(frame.clone())?;
+    *last_paint = Some(LastPaint {
+      tick: current_tick,
+      frame,
+    });
+
     Ok(())
   }
 
@@ -44,24 +167,26 @@ impl ScreenPrinter {
   /// This could be used for when you don't want to use the built in printer and maybe want to
   /// send the data somewhere else other than a terminal.
   pub fn display(&self) -> String {
-    let mut frame = Self::create_blank_frame();
+    let mut frame = self.blank_frame();
 
-    for strata_number in 0..=100 {
-      let existing_models = self.model_storage.read_model_storage();
+    let existing_models = self.model_storage.read_model_storage();
 
-      let Some(strata_keys) = existing_models.get_strata_keys(&Strata(strata_number)) else {
+    for strata in existing_models.get_stratas() {
+      let Some(strata_keys) = existing_models.get_strata_keys(&strata) else {
         continue;
       };
 
       for model in strata_keys.iter().map(|key| existing_models.get_model(key)) {
         let Some(model) = model else {
-          log::error!(
-            "A model in strata {strata_number} that doesn't exist was attempted to be run."
-          );
+          log::error!("A model in strata {strata:?} that doesn't exist was attempted to be run.");
 
           continue;
         };
 
+        if !model.is_visible() {
+          continue;
+        }
+
         Self::apply_model_in_frame(model, &mut frame);
       }
     }
@@ -69,12 +194,12 @@ impl ScreenPrinter {
     frame
   }
 
-  /// Returns a 2D string of the assigned air character in the config file.
+  /// Returns a 2D string of the given empty pixel.
   ///
   /// 2D meaning, rows of characters separated by newlines "creating a second dimension.
-  fn create_blank_frame() -> String {
+  pub(crate) fn create_blank_frame(empty_pixel: char) -> String {
     // This was the fastest way I found to create a large 2-dimensional string of 1 character.
-    let pixel_row = CONFIG.empty_pixel.repeat(CONFIG.grid_width as usize) + "\n";
+    let pixel_row = empty_pixel.to_string().repeat(CONFIG.grid_width as usize) + "\n";
 
     let mut frame = pixel_row.repeat(CONFIG.grid_height as usize);
     frame.pop(); // Removes the new line left at the end.
@@ -82,21 +207,36 @@ impl ScreenPrinter {
     frame
   }
 
+  /// Returns the first character of the empty pixel assigned in the config file.
+  fn default_empty_pixel() -> char {
+    CONFIG.empty_pixel.chars().next().unwrap_or(' ')
+  }
+
   /// Places the appearance of the model in the given frame.
+  ///
+  /// Air in the model's sprite is skipped, unless the model has a render air override assigned.
   fn apply_model_in_frame(mut model: ModelData, current_frame: &mut String) {
     let model_frame_position = model.get_frame_position();
+    let render_air_override = model.get_render_air_override();
     let model_appearance = model.get_appearance_data();
-    let model_appearance = model_appearance.lock().unwrap();
+    let mut model_appearance = model_appearance.lock().unwrap();
     let model_sprite = model_appearance.get_appearance();
     let sprite_width = model_sprite.get_dimensions().x;
     let air_character = model_sprite.air_character();
 
-    let model_shape = model_sprite.get_appearance().replace('\n', "");
+    let model_shape = model_appearance
+      .get_current_appearance_string()
+      .replace('\n', "");
     let model_characters = model_shape.chars();
 
     drop(model_appearance);
 
     for (index, character) in model_characters.enumerate() {
+      let character = match render_air_override {
+        Some(override_character) if character == air_character => override_character,
+        _ => character,
+      };
+
       if character == air_character || !character.is_ascii() {
         continue;
       }
@@ -105,7 +245,7 @@ impl ScreenPrinter {
 
       // (top_left_index + (row_adder + column_adder)) - column_correction
       let character_index = (model_frame_position
-        + (((CONFIG.grid_width as usize + 1) * current_row_count) + index))
+        + ((CONFIG.frame_width() * current_row_count) + index))
         - (current_row_count * sprite_width);
 
       current_frame.replace_range(
@@ -116,10 +256,114 @@ impl ScreenPrinter {
   }
 }
 
+/// A handle to a thread printing the screen at a target framerate, created through
+/// [`ScreenData::spawn_printing_thread`](crate::screen::screen_data::ScreenData::spawn_printing_thread).
+///
+/// Dropping the handle stops the thread after its current frame.
+#[derive(Debug)]
+pub struct PrintingThreadHandle {
+  thread_handle: JoinHandle<()>,
+  kill_sender: Sender<()>,
+  fps_sender: Sender<u32>,
+}
+
+impl PrintingThreadHandle {
+  /// Spawns a thread running the print function at the given framerate.
+  ///
+  /// When a duration is given, the thread stops once that much time has passed.
+  pub(crate) fn spawn<F>(fps: u32, print_duration: Option<Duration>, mut print_frame: F) -> Self
+  where
+    F: FnMut() + Send + 'static,
+  {
+    let (kill_sender, kill_receiver) = mpsc::channel::<()>();
+    let (fps_sender, fps_receiver) = mpsc::channel::<u32>();
+
+    let thread_handle = thread::spawn(move || {
+      let start_time = Instant::now();
+      let mut frame_interval = Self::fps_to_interval(fps);
+
+      'printing: loop {
+        let frame_start = Instant::now();
+        print_frame();
+
+        if print_duration.is_some_and(|print_duration| start_time.elapsed() >= print_duration) {
+          break;
+        }
+
+        loop {
+          let next_frame = frame_start + frame_interval;
+
+          match kill_receiver.recv_timeout(next_frame.saturating_duration_since(Instant::now())) {
+            Err(RecvTimeoutError::Timeout) => break,
+            // set_fps sends the framerate before waking the thread, so a signal with a framerate
+            // waiting is a framerate change. Anything else is a kill.
+            Ok(()) => match fps_receiver.try_recv() {
+              Ok(new_fps) => frame_interval = Self::fps_to_interval(new_fps),
+              Err(_) => break 'printing,
+            },
+            // The handle was dropped.
+            Err(RecvTimeoutError::Disconnected) => break 'printing,
+          }
+        }
+      }
+    });
+
+    Self {
+      thread_handle,
+      kill_sender,
+      fps_sender,
+    }
+  }
+
+  /// Changes the framerate of the printing thread.
+  ///
+  /// The thread is woken to apply the change right away, meaning the next frame is printed once
+  /// the new frame interval has passed since the last one.
+  ///
+  /// A framerate of 0 is treated as 1.
+  pub fn set_fps(&self, fps: u32) {
+    // The framerate is sent before waking the thread, so it's always there once the thread wakes.
+    if self.fps_sender.send(fps).is_err() || self.kill_sender.send(()).is_err() {
+      log::warn!("Attempted to change the framerate of a printing thread that has stopped.");
+    }
+  }
+
+  /// Returns a sender that stops the printing thread when sent to.
+  ///
+  /// This can be passed to other threads to stop printing from anywhere.
+  pub fn get_kill_sender(&self) -> Sender<()> {
+    self.kill_sender.clone()
+  }
+
+  /// Returns true if the printing thread has stopped.
+  pub fn is_finished(&self) -> bool {
+    self.thread_handle.is_finished()
+  }
+
+  /// Stops the printing thread, and waits for it to finish its current frame.
+  ///
+  /// # Errors
+  ///
+  /// - Returns the panic of the printing thread if it panicked.
+  pub fn kill(self) -> thread::Result<()> {
+    // The thread has already stopped if the receiver is gone.
+    let _ = self.kill_sender.send(());
+
+    self.thread_handle.join()
+  }
+
+  fn fps_to_interval(fps: u32) -> Duration {
+    Duration::from_secs(1) / fps.max(1)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
-  use model_data_structures::models::testing_data::*;
+  use crate::screen::model_storage::ModelStorage;
+  use model_data_structures::models::{strata::Strata, testing_data::*};
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Barrier;
 
   const WORLD_POSITION: (usize, usize) = (10, 10);
   const SHAPE: &str = "xxxxx\nxxaxx\nxxxxx";
@@ -129,7 +373,7 @@ mod tests {
     let expected_pixel_count =
       ((CONFIG.grid_width * CONFIG.grid_height) + CONFIG.grid_height - 1) as usize;
 
-    let blank_frame = ScreenPrinter::create_blank_frame();
+    let blank_frame = ScreenPrinter::create_blank_frame(ScreenPrinter::default_empty_pixel());
 
     assert!(blank_frame.chars().count() == expected_pixel_count);
   }
@@ -147,7 +391,8 @@ mod tests {
       let model_data = TestingData::new_test_model(WORLD_POSITION);
       let find_character = SHAPE.chars().next().unwrap();
       let top_left_index = model_data.get_frame_position();
-      let mut current_frame = ScreenPrinter::create_blank_frame();
+      let mut current_frame =
+        ScreenPrinter::create_blank_frame(ScreenPrinter::default_empty_pixel());
 
       let expected_top_left_character = find_character;
       let expected_left_of_expected_character = CONFIG.empty_pixel.chars().next().unwrap();
@@ -167,4 +412,225 @@ mod tests {
       );
     }
   }
+
+  #[cfg(test)]
+  mod render_air_override_logic {
+    use super::*;
+    use model_data_structures::prelude::{Hitbox, Sprite};
+
+    /// Returns a model shaped like a plus, with air in each corner.
+    fn new_plus_model() -> ModelData {
+      let sprite = Sprite::new("-x-\nxax\n-x-", 'a', 'x', '-').unwrap();
+      let hitbox = Hitbox::new(engine_math::rectangle::Rectangle::new(3, 3), 4);
+
+      ModelData::new(
+        WORLD_POSITION,
+        sprite,
+        hitbox,
+        Strata(0),
+        "Plus".to_string(),
+      )
+      .unwrap()
+    }
+
+    #[test]
+    fn override_fills_air() {
+      let mut model = new_plus_model();
+      let top_left_index = model.get_frame_position();
+      let mut current_frame =
+        ScreenPrinter::create_blank_frame(ScreenPrinter::default_empty_pixel());
+
+      model.set_render_air_override(Some('#'));
+      ScreenPrinter::apply_model_in_frame(model.clone(), &mut current_frame);
+
+      assert_eq!(current_frame.chars().nth(top_left_index), Some('#'));
+      assert_eq!(current_frame.chars().nth(top_left_index + 1), Some('x'));
+      assert_eq!(model.get_sprite().get_appearance(), "-x-\nxxx\n-x-");
+    }
+
+    #[test]
+    fn clearing_override_restores_transparency() {
+      let mut model = new_plus_model();
+      let top_left_index = model.get_frame_position();
+      let mut current_frame =
+        ScreenPrinter::create_blank_frame(ScreenPrinter::default_empty_pixel());
+
+      let expected_character = CONFIG.empty_pixel.chars().next();
+
+      model.set_render_air_override(Some('#'));
+      model.set_render_air_override(None);
+      ScreenPrinter::apply_model_in_frame(model, &mut current_frame);
+
+      assert_eq!(
+        current_frame.chars().nth(top_left_index),
+        expected_character
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod coalesced_paint_logic {
+    use super::*;
+
+    #[test]
+    fn concurrent_prints_in_one_tick() {
+      let thread_count = 8;
+      // A long tick to guarantee every thread requests a print within the same one.
+      let event_sync = EventSync::new(10_000);
+      let model_storage = Arc::new(RwLock::new(ModelStorage::default()));
+      let screen_printer = ScreenPrinter::new(
+        Arc::new(Mutex::new(Printer::new())),
+        ModelStorage::create_read_only(model_storage),
+        event_sync.clone_immutable(),
+      );
+      let paint_count = Arc::new(AtomicUsize::new(0));
+      let barrier = Arc::new(Barrier::new(thread_count));
+
+      let handles: Vec<_> = (0..thread_count)
+        .map(|_| {
+          let screen_printer = screen_printer.clone();
+          let paint_count = paint_count.clone();
+          let barrier = barrier.clone();
+
+          std::thread::spawn(move || {
+            barrier.wait();
+
+            screen_printer.coalesced_paint(|_| {
+              paint_count.fetch_add(1, Ordering::SeqCst);
+
+              Ok(())
+            })
+          })
+        })
+        .collect();
+
+      for handle in handles {
+        handle.join().unwrap().unwrap();
+      }
+
+      assert_eq!(paint_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn changed_frame_in_same_tick_is_painted() {
+      // A long tick to guarantee both prints happen within the same one.
+      let event_sync = EventSync::new(10_000);
+      let model_storage = Arc::new(RwLock::new(ModelStorage::default()));
+      let screen_printer = ScreenPrinter::new(
+        Arc::new(Mutex::new(Printer::new())),
+        ModelStorage::create_read_only(model_storage.clone()),
+        event_sync.clone_immutable(),
+      );
+      let mut painted_frames = vec![];
+
+      screen_printer
+        .coalesced_paint(|frame| {
+          painted_frames.push(frame);
+
+          Ok(())
+        })
+        .unwrap();
+      model_storage
+        .write()
+        .unwrap()
+        .insert(TestingData::new_test_model(WORLD_POSITION))
+        .unwrap();
+      screen_printer
+        .coalesced_paint(|frame| {
+          painted_frames.push(frame);
+
+          Ok(())
+        })
+        .unwrap();
+
+      assert_eq!(painted_frames.len(), 2);
+      assert_ne!(painted_frames[0], painted_frames[1]);
+    }
+
+    #[test]
+    fn forced_paint_ignores_previous_paint() {
+      let event_sync = EventSync::new(10_000);
+      let model_storage = Arc::new(RwLock::new(ModelStorage::default()));
+      let screen_printer = ScreenPrinter::new(
+        Arc::new(Mutex::new(Printer::new())),
+        ModelStorage::create_read_only(model_storage),
+        event_sync.clone_immutable(),
+      );
+      let paint_count = AtomicUsize::new(0);
+      let count_paint = |_| {
+        paint_count.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+      };
+
+      screen_printer.coalesced_paint(count_paint).unwrap();
+      screen_printer.coalesced_paint(count_paint).unwrap();
+      screen_printer.exclusive_paint(true, count_paint).unwrap();
+
+      assert_eq!(paint_count.load(Ordering::SeqCst), 2);
+    }
+  }
+
+  #[cfg(test)]
+  mod printing_thread_logic {
+    use super::*;
+
+    #[test]
+    fn thread_is_killed() {
+      let frame_count = Arc::new(AtomicUsize::new(0));
+      let thread_frame_count = frame_count.clone();
+
+      let printing_thread = PrintingThreadHandle::spawn(1000, None, move || {
+        thread_frame_count.fetch_add(1, Ordering::SeqCst);
+      });
+      thread::sleep(Duration::from_millis(50));
+
+      printing_thread.kill().unwrap();
+      let frames_after_kill = frame_count.load(Ordering::SeqCst);
+      thread::sleep(Duration::from_millis(20));
+
+      assert!(frames_after_kill > 1);
+      assert_eq!(frame_count.load(Ordering::SeqCst), frames_after_kill);
+    }
+
+    #[test]
+    fn fps_is_changed_while_running() {
+      let frame_count = Arc::new(AtomicUsize::new(0));
+      let thread_frame_count = frame_count.clone();
+
+      // At 1 fps only the first frame would be printed before the kill.
+      let printing_thread = PrintingThreadHandle::spawn(1, None, move || {
+        thread_frame_count.fetch_add(1, Ordering::SeqCst);
+      });
+      printing_thread.set_fps(1000);
+      thread::sleep(Duration::from_millis(50));
+
+      printing_thread.kill().unwrap();
+
+      assert!(frame_count.load(Ordering::SeqCst) > 2);
+    }
+
+    #[test]
+    fn kill_sender_stops_the_thread_after_fps_changes() {
+      let printing_thread = PrintingThreadHandle::spawn(1, None, || {});
+      let kill_sender = printing_thread.get_kill_sender();
+
+      printing_thread.set_fps(2);
+      printing_thread.set_fps(3);
+      kill_sender.send(()).unwrap();
+      thread::sleep(Duration::from_millis(100));
+
+      assert!(printing_thread.is_finished());
+    }
+
+    #[test]
+    fn thread_stops_after_duration() {
+      let printing_thread =
+        PrintingThreadHandle::spawn(1000, Some(Duration::from_millis(10)), || {});
+
+      thread::sleep(Duration::from_millis(100));
+
+      assert!(printing_thread.is_finished());
+    }
+  }
 }
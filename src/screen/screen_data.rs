@@ -5,14 +5,14 @@ use crate::screen::model_storage::*;
 use crate::screen::printer::*;
 use crate::screen::stored_worlds::*;
 use crate::CONFIG;
+use engine_math::coordinates::*;
 use event_sync::EventSync;
 use event_sync::Immutable;
 use model_data_structures::models::model_data::*;
-use model_data_structures::models::model_movements::ModelCollisions;
 use screen_printer::printer::*;
-use std::collections::VecDeque;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
-use std::time::Instant;
+use std::time::Duration;
 
 /// ScreenData is where all the internal information required to create frames is held.
 ///
@@ -45,7 +45,10 @@ pub struct ScreenData {
   printer: ScreenPrinter,
   event_sync: EventSync,
   model_storage: Arc<RwLock<ModelStorage>>,
-  collision_events: Arc<RwLock<VecDeque<(Instant, ModelCollisions)>>>,
+  collision_state: CollisionState,
+  tag_groups: Arc<RwLock<HashMap<String, Vec<String>>>>,
+  particles: Arc<RwLock<HashMap<u64, Particle>>>,
+  model_parents: Arc<RwLock<HashMap<u64, u64>>>,
 
   /// Hides the terminal cursor as long as this lives
   _cursor_hider: termion::cursor::HideCursor<std::io::Stdout>,
@@ -83,10 +86,56 @@ impl ScreenData {
     Self::new_screen(Default::default())
   }
 
-  pub fn from_world(world: StoredWorld) -> Self {
-    let stored_models = ModelStorage::from(world);
+  /// Creates a new screen with every model from the given world.
+  ///
+  /// A world saved under a different config can contain models positioned outside of the current
+  /// grid. Those models are moved along each axis until their whole sprite fits inside the grid,
+  /// and a warning is logged.
+  ///
+  /// Returns the screen, and the hashes of every model that had to be clamped.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when a model from the world failed to be inserted.
+  pub fn from_world(world: StoredWorld) -> Result<(Self, Vec<u64>), ScreenError> {
+    let mut stored_models = ModelStorage::default();
+    let mut clamped_models = vec![];
+
+    for mut model in world {
+      let model_position = model.get_frame_position();
+      let clamped_position = Self::clamp_into_grid(&model);
 
-    Self::new_screen(stored_models)
+      if clamped_position != model_position {
+        log::warn!(
+          "Model {} was outside of the grid at position {model_position}, and was clamped to {clamped_position}.",
+          model.get_hash()
+        );
+
+        model.change_position(clamped_position);
+        clamped_models.push(model.get_hash());
+      }
+
+      stored_models
+        .insert(model)
+        .map_err(ScreenError::ModelError)?;
+    }
+
+    Ok((Self::new_screen(stored_models), clamped_models))
+  }
+
+  /// Returns the closest frame position to the model's current one where its entire sprite is
+  /// inside of the grid.
+  ///
+  /// Sprites larger than the grid are placed at the top left.
+  fn clamp_into_grid(model: &ModelData) -> usize {
+    let (x, y) = model
+      .get_frame_position()
+      .index_to_coordinates(CONFIG.frame_width());
+    let sprite_dimensions = model.get_sprite().get_dimensions();
+    let last_x = (CONFIG.grid_width as usize).saturating_sub(sprite_dimensions.x);
+    let last_y = (CONFIG.grid_height as usize).saturating_sub(sprite_dimensions.y);
+
+    (x.min(last_x), y.min(last_y)).coordinates_to_index(CONFIG.frame_width())
   }
 
   fn new_screen(stored_models: ModelStorage) -> Self {
@@ -99,16 +148,21 @@ impl ScreenData {
       PrintingPosition::new(XPrintingPosition::Middle, YPrintingPosition::Middle);
     let printer = Printer::new_with_printing_position(printing_position);
     let model_storage: Arc<RwLock<ModelStorage>> = Arc::new(RwLock::new(stored_models));
+    let event_sync = EventSync::new(CONFIG.tick_duration);
     let printer = ScreenPrinter::new(
       Arc::new(Mutex::new(printer)),
       ModelStorage::create_read_only(model_storage.clone()),
+      event_sync.clone_immutable(),
     );
 
     ScreenData {
       printer,
-      event_sync: EventSync::new(CONFIG.tick_duration),
+      event_sync,
       model_storage,
-      collision_events: Default::default(),
+      collision_state: Default::default(),
+      tag_groups: Default::default(),
+      particles: Default::default(),
+      model_parents: Default::default(),
       _cursor_hider: cursor_hider,
     }
   }
@@ -130,6 +184,14 @@ impl ScreenData {
   /// This will use a built in printer to efficiently print to the screen.
   /// This prevents any flickers that normally appear in the terminal when printing a lot in a given time frame.
   ///
+  /// Only the first print draws the entire frame. Every print after that moves the cursor to the
+  /// characters that changed since the previous frame and only redraws those.
+  /// For terminals that don't support cursor addressing use
+  /// [`print_screen_full()`](crate::screen::screen_data::ScreenData::print_screen_full) instead.
+  ///
+  /// Prints requested from multiple threads within the same tick are coalesced into a single repaint,
+  /// unless the frame changed since the screen was last painted.
+  ///
   /// # Usage
   ///
   /// ```ignore
@@ -153,6 +215,150 @@ impl ScreenData {
     self.printer.print_screen()
   }
 
+  /// Prints the entire screen as plain text, without diffing against the previous frame.
+  ///
+  /// This is for terminals that don't support the cursor addressing used by
+  /// [`print_screen()`](crate::screen::screen_data::ScreenData::print_screen).
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error if the frame failed to be built.
+  #[cfg(not(tarpaulin_include))]
+  pub fn print_screen_full(&mut self) -> Result<(), ScreenError> {
+    self.printer.print_screen_full()
+  }
+
+  /// Returns the frame that [`print_screen()`](crate::screen::screen_data::ScreenData::print_screen)
+  /// would print, without printing it.
+  ///
+  /// This is useful for testing what's rendered, or redirecting the output somewhere other than
+  /// the terminal.
+  ///
+  /// # Errors
+  ///
+  /// - Returns an error if the frame failed to be built.
+  pub fn print_screen_to_string(&self) -> Result<String, ScreenError> {
+    self.printer.print_screen_to_string()
+  }
+
+  /// Creates a new frame of the world as a grid of characters, indexed as `frame[y][x]`.
+  ///
+  /// Built from the same frame as [`display()`](crate::screen::screen_data::ScreenData::display),
+  /// without the newlines. There's a row for every line of the grid's height, and every row is
+  /// as long as the grid's width.
+  ///
+  /// The world position drawn in a cell can be found with
+  /// [`frame_to_world`](crate::screen::model_manager::frame_to_world).
+  pub fn capture_frame(&self) -> Vec<Vec<char>> {
+    self
+      .display()
+      .lines()
+      .map(|row| row.chars().collect())
+      .collect()
+  }
+
+  /// Returns a frame the size of the grid, filled with the screen's empty pixel.
+  ///
+  /// Rows are separated by newlines, matching the frames built by
+  /// [`display()`](crate::screen::screen_data::ScreenData::display).
+  /// This can be used as a template for building frames with your own renderer.
+  pub fn blank_frame(&self) -> String {
+    self.printer.blank_frame()
+  }
+
+  /// Changes the character placed in every cell of the screen that has no visible model in it.
+  ///
+  /// The default is the empty pixel assigned in the config file.
+  /// This can be used to render empty space as something visible like '.' when debugging.
+  pub fn set_empty_pixel(&mut self, empty_pixel: char) {
+    self.printer.set_empty_pixel(empty_pixel);
+  }
+
+  /// Returns the width and height of the grid in characters.
+  ///
+  /// The width doesn't include the new line at the end of each row in a frame.
+  pub fn dimensions(&self) -> (usize, usize) {
+    (CONFIG.grid_width as usize, CONFIG.grid_height as usize)
+  }
+
+  /// Returns true if the given world position is within the grid.
+  pub fn position_in_bounds(&self, position: (usize, usize)) -> bool {
+    let (width, height) = self.dimensions();
+
+    position.0 < width && position.1 < height
+  }
+
+  /// Compares two frames and returns the commands needed to turn the previous frame into the current one.
+  ///
+  /// The patch is made of cursor movements to each changed run of characters in a row, followed by
+  /// those characters. Printing the patch over a terminal displaying the previous frame will result in
+  /// the current frame.
+  ///
+  /// Rows that were longer in the previous frame have the rest of their line erased, along with every
+  /// row the previous frame had past the end of the current one.
+  ///
+  /// This can be used for when you want to send updates of the screen somewhere other than the
+  /// terminal the engine is running in.
+  pub fn frame_patch(&self, previous: &str, current: &str) -> String {
+    let mut patch = String::new();
+    let mut previous_rows = previous.lines();
+
+    for (y, current_row) in current.lines().enumerate() {
+      let mut previous_characters = previous_rows.next().unwrap_or("").chars();
+      let mut changed_run: Option<(usize, String)> = None;
+
+      for (x, character) in current_row.chars().enumerate() {
+        if previous_characters.next() == Some(character) {
+          if let Some((run_start, run)) = changed_run.take() {
+            Self::push_patch_run(&mut patch, (run_start, y), &run);
+          }
+
+          continue;
+        }
+
+        changed_run
+          .get_or_insert_with(|| (x, String::new()))
+          .1
+          .push(character);
+      }
+
+      if let Some((run_start, run)) = changed_run {
+        Self::push_patch_run(&mut patch, (run_start, y), &run);
+      }
+
+      if previous_characters.next().is_some() {
+        Self::push_patch_erase(&mut patch, (current_row.chars().count(), y));
+      }
+    }
+
+    let row_count = current.lines().count();
+
+    for (y, _) in previous_rows.enumerate() {
+      Self::push_patch_erase(&mut patch, (0, row_count + y));
+    }
+
+    patch
+  }
+
+  /// Adds the cursor movement to the given position, followed by the changed characters to the patch.
+  fn push_patch_run(patch: &mut String, (x, y): (usize, usize), run: &str) {
+    // Terminal positions start at 1.
+    let cursor_movement = termion::cursor::Goto(x as u16 + 1, y as u16 + 1);
+
+    patch.push_str(&format!("{cursor_movement}{run}"));
+  }
+
+  /// Adds the cursor movement to the given position, followed by erasing the rest of that line to the patch.
+  fn push_patch_erase(patch: &mut String, (x, y): (usize, usize)) {
+    // Terminal positions start at 1.
+    let cursor_movement = termion::cursor::Goto(x as u16 + 1, y as u16 + 1);
+
+    patch.push_str(&format!(
+      "{cursor_movement}{}",
+      termion::clear::UntilNewline
+    ));
+  }
+
   /// Prints whitespace over the screen.
   ///
   /// This can be used to reset the grid if things get desynced from possible bugs.
@@ -161,6 +367,41 @@ impl ScreenData {
     self.printer.clear_screen();
   }
 
+  /// Spawns a thread that prints the screen at the given framerate.
+  ///
+  /// The framerate can be changed while the thread is running through
+  /// [`PrintingThreadHandle::set_fps`](crate::screen::printer::PrintingThreadHandle::set_fps).
+  /// When a print duration is given, the thread stops once that much time has passed.
+  /// Otherwise it runs until it's killed, or the handle is dropped.
+  ///
+  /// # Usage
+  ///
+  /// ```ignore
+  ///  use ascii_engine::prelude::*;
+  ///
+  ///  let screen_data = ScreenData::new();
+  ///  let printing_thread = screen_data.spawn_printing_thread(60, None);
+  ///
+  ///  // Lower the framerate to save battery.
+  ///  printing_thread.set_fps(20);
+  ///
+  ///  printing_thread.kill().unwrap();
+  /// ```
+  #[cfg(not(tarpaulin_include))]
+  pub fn spawn_printing_thread(
+    &self,
+    fps: u32,
+    print_duration: Option<Duration>,
+  ) -> PrintingThreadHandle {
+    let mut screen_printer = self.get_screen_printer();
+
+    PrintingThreadHandle::spawn(fps, print_duration, move || {
+      if let Err(error) = screen_printer.print_screen() {
+        log::error!("An error has occurred while printing the screen: {error:?}");
+      }
+    })
+  }
+
   /// Returns a copy of the ScreenPrinter.
   ///
   /// The ScreenPrinter can be used for printing the screen, and can be passed around to different threads.
@@ -177,6 +418,7 @@ impl ScreenData {
   /// # Errors
   ///
   /// - An error is returned when attempting to add a model that already exists.
+  /// - An error is returned when the world already holds the maximum amount of models.
   pub fn add_model(&mut self, model: ModelData) -> Result<(), ModelError> {
     self.model_storage.write().unwrap().insert(model)
   }
@@ -195,6 +437,7 @@ impl ScreenData {
     let mut existing_models = self.model_storage.write().unwrap();
 
     let old_world_models = std::mem::take(&mut *existing_models);
+    existing_models.set_max_models(old_world_models.get_max_models());
 
     old_world_models.extract_model_list()
   }
@@ -207,6 +450,7 @@ impl ScreenData {
 
     let old_model_list = std::mem::take(&mut *existing_models);
     *existing_models = ModelStorage::from(new_world);
+    existing_models.set_max_models(old_model_list.get_max_models());
 
     old_model_list.extract_model_list()
   }
@@ -222,7 +466,14 @@ impl ScreenData {
   ///
   /// The ModelManager will handle all actions requested to models in the world.
   pub fn get_model_manager(&self) -> ModelManager {
-    ModelManager::new(self.model_storage.clone(), self.collision_events.clone())
+    ModelManager::new(
+      self.model_storage.clone(),
+      self.collision_state.clone(),
+      self.tag_groups.clone(),
+      self.particles.clone(),
+      self.model_parents.clone(),
+      self.event_sync.clone_immutable(),
+    )
   }
 
   /// Get an immutable copy of the internal EventSync.
@@ -1,12 +1,19 @@
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use model_data_structures::{
   models::{model_data::*, stored_models::StoredDisplayModel},
   prelude::ScreenError,
 };
 use std::fs;
 use std::fs::OpenOptions;
-use std::io::Write;
+use std::io::{BufReader, Read, Write};
 use std::path::Path;
 
+/// The file extension used for worlds saved with [`save_compressed`](StoredWorld::save_compressed).
+pub const COMPRESSED_WORLD_EXTENSION: &str = "wrldz";
+
+/// The first two bytes of any gzip stream.
+const GZIP_MAGIC_HEADER: [u8; 2] = [0x1f, 0x8b];
+
 /// A storage for the list of models that exist in a given state of the world.
 #[derive(Debug)]
 pub struct StoredWorld {
@@ -59,28 +66,50 @@ impl StoredWorld {
 
   /// Logs an error for every model that failed to get loaded into the world.
   ///
+  /// Worlds saved with [`save_compressed`](StoredWorld::save_compressed) are detected by either
+  /// the `.wrldz` extension or the gzip header, and are decompressed before being loaded.
+  ///
   /// # Errors
   ///
   /// - Failed to read the given path from the file system.
+  /// - Failed to decompress a compressed world.
   /// - Failed to deserialize the contents of the file to the expected values from binary.
   pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ScreenError> {
     let path = path.as_ref();
+    let file_contents = read_world_file(path)?;
 
-    if !path.exists() {
-      return Err(ScreenError::FileDoesNotExist);
+    let has_compressed_extension = path
+      .extension()
+      .is_some_and(|extension| extension == COMPRESSED_WORLD_EXTENSION);
+
+    if has_compressed_extension || file_contents.starts_with(&GZIP_MAGIC_HEADER) {
+      Self::from_encoded_contents(&decompress(&file_contents)?)
+    } else {
+      Self::from_encoded_contents(&file_contents)
     }
+  }
 
-    let encoded_file_contents: Vec<u8> = match fs::read(path) {
-      Ok(file_contents) => file_contents,
+  /// Loads a world that was saved with [`save_compressed`](StoredWorld::save_compressed).
+  ///
+  /// Logs an error for every model that failed to get loaded into the world.
+  ///
+  /// # Errors
+  ///
+  /// - Failed to read the given path from the file system.
+  /// - Failed to decompress the contents of the file.
+  /// - Failed to deserialize the decompressed contents to the expected values from binary.
+  pub fn load_compressed<P: AsRef<Path>>(path: P) -> Result<Self, ScreenError> {
+    let file_contents = read_world_file(path.as_ref())?;
 
-      #[cfg(not(tarpaulin_inclue))]
-      Err(error) => return Err(ScreenError::Other(error.to_string())),
-    };
+    Self::from_encoded_contents(&decompress(&file_contents)?)
+  }
 
+  /// Deserializes the binary contents of a world file into a StoredWorld.
+  fn from_encoded_contents(encoded_file_contents: &[u8]) -> Result<Self, ScreenError> {
     // Tarpaulin is bugged and doesn't include the overlapped match statement.
-    #[cfg(not(tarpaulin_inclue))]
+    #[cfg(not(tarpaulin_include))]
     let deserialized_stored_model_list =
-      match bincode::deserialize::<Vec<StoredDisplayModel>>(&encoded_file_contents) {
+      match bincode::deserialize::<Vec<StoredDisplayModel>>(encoded_file_contents) {
         Ok(data) => data,
         Err(error) => return Err(ScreenError::FailedToLoadWorld(error.to_string())),
       };
@@ -102,6 +131,45 @@ impl StoredWorld {
     Ok(Self::new(models))
   }
 
+  /// Reads the world at the given path one model at a time, passing each model into the given closure.
+  ///
+  /// Unlike [`load`](StoredWorld::load), the world is never held in memory all at once.
+  /// This can be used to incrementally insert the models of a very large world as they're read.
+  ///
+  /// # Errors
+  ///
+  /// - Failed to read the given path from the file system.
+  /// - Failed to deserialize the contents of the file to the expected values from binary.
+  pub fn load_streaming<F>(path: &Path, mut closure: F) -> Result<(), ScreenError>
+  where
+    F: FnMut(StoredDisplayModel),
+  {
+    if !path.exists() {
+      return Err(ScreenError::FileDoesNotExist);
+    }
+
+    let file = match fs::File::open(path) {
+      Ok(file) => file,
+      Err(error) => return Err(ScreenError::Other(error.to_string())),
+    };
+    let mut reader = BufReader::new(file);
+
+    // Worlds are saved as a list of models, which is prefixed with the amount of models in the list.
+    let model_count: u64 = match bincode::deserialize_from(&mut reader) {
+      Ok(model_count) => model_count,
+      Err(error) => return Err(ScreenError::FailedToLoadWorld(error.to_string())),
+    };
+
+    for _ in 0..model_count {
+      match bincode::deserialize_from::<_, StoredDisplayModel>(&mut reader) {
+        Ok(stored_model) => closure(stored_model),
+        Err(error) => return Err(ScreenError::FailedToLoadWorld(error.to_string())),
+      }
+    }
+
+    Ok(())
+  }
+
   /// Writes the data for the world in a file at the given path.
   /// Overwrites any file that was in that location.
   // TODO: List the errors.
@@ -114,12 +182,70 @@ impl StoredWorld {
     truncate_or_create_then_write(path, serialized_world)
   }
 
+  /// Writes the data for the world in a gzip compressed file at the given path.
+  /// Overwrites any file that was in that location.
+  ///
+  /// Compressed worlds are conventionally saved with the `.wrldz` extension.
+  /// They can be loaded with either [`load_compressed`](StoredWorld::load_compressed) or [`load`](StoredWorld::load).
+  ///
+  /// # Errors
+  ///
+  /// - Failed to serialize or compress the world.
+  /// - When the parent directory didn't exist.
+  /// - When the file couldn't be opened or written to.
+  pub fn save_compressed<P: AsRef<Path>>(&self, path: P) -> Result<(), ScreenError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+
+    if let Err(error) = bincode::serialize_into(&mut encoder, &self.models) {
+      return Err(ScreenError::Other(error.to_string()));
+    }
+
+    let compressed_world = match encoder.finish() {
+      Ok(compressed_world) => compressed_world,
+      Err(error) => return Err(ScreenError::Other(error.to_string())),
+    };
+
+    truncate_or_create_then_write(path, compressed_world)
+  }
+
+  /// Adds every model stored in the other world to this one.
+  ///
+  /// Every model gets a new hash when it's loaded, so models from both worlds will never share a hash.
+  /// Models that overlap each other are kept as they are, it's up to the caller to resolve them.
+  pub fn merge(&mut self, mut other: StoredWorld) {
+    self.models.append(&mut other.models);
+  }
+
   /// Returns the amount of models stored.
   pub fn model_count(&self) -> usize {
     self.models.len()
   }
 }
 
+/// Reads the entire contents of the world file at the given path.
+fn read_world_file(path: &Path) -> Result<Vec<u8>, ScreenError> {
+  if !path.exists() {
+    return Err(ScreenError::FileDoesNotExist);
+  }
+
+  match fs::read(path) {
+    Ok(file_contents) => Ok(file_contents),
+
+    #[cfg(not(tarpaulin_include))]
+    Err(error) => Err(ScreenError::Other(error.to_string())),
+  }
+}
+
+/// Decompresses the contents of a gzip compressed world file.
+fn decompress(compressed_contents: &[u8]) -> Result<Vec<u8>, ScreenError> {
+  let mut decompressed_contents = Vec::new();
+
+  match GzDecoder::new(compressed_contents).read_to_end(&mut decompressed_contents) {
+    Ok(_) => Ok(decompressed_contents),
+    Err(error) => Err(ScreenError::FailedToLoadWorld(error.to_string())),
+  }
+}
+
 /// Takes a given path and creates the file if it doesn't exist, then writing the data to it.
 /// If the file in the path already exists, this function will truncate it.
 ///
@@ -165,6 +291,7 @@ mod tests {
   use crate::screen::screen_data::ScreenData;
   use engine_math::hasher::get_unique_hash;
   use model_data_structures::models::testing_data::TestingData;
+  use std::collections::HashSet;
   use std::path::PathBuf;
 
   #[test]
@@ -176,9 +303,20 @@ mod tests {
     assert!(stored_world.into_iter().count() == model_count as usize);
   }
 
+  #[test]
+  fn merge_logic() {
+    let mut first_room = StoredWorld::new(TestingData::get_multiple_test_models((10, 10), 2));
+    let second_room = StoredWorld::new(TestingData::get_multiple_test_models((10, 10), 3));
+
+    first_room.merge(second_room);
+
+    assert_eq!(first_room.model_count(), 5);
+    assert_eq!(first_room.into_iter().count(), 5);
+  }
+
   #[test]
   fn test_world_from_file() {
-    let screen = ScreenData::from_world(get_test_world());
+    let (screen, _) = ScreenData::from_world(get_test_world()).unwrap();
     let model_manager = screen.get_model_manager();
 
     model_manager.get_model_list(|model_list| {
@@ -203,6 +341,72 @@ mod tests {
     assert!(!temporary_test_file_path.exists());
   }
 
+  #[test]
+  fn save_and_load_compressed_file() {
+    let temporary_test_file_path: PathBuf =
+      generate_temporary_test_file_path().with_extension(COMPRESSED_WORLD_EXTENSION);
+    let test_models = TestingData::get_multiple_test_models((10, 10), 100);
+    test_models
+      .iter()
+      .enumerate()
+      .for_each(|(index, model)| model.change_name(index.to_string()));
+    let stored_world = StoredWorld::new(test_models);
+
+    stored_world
+      .save_compressed(temporary_test_file_path.clone())
+      .unwrap();
+    let compressed_world = StoredWorld::load_compressed(temporary_test_file_path.clone()).unwrap();
+    let auto_detected_world = StoredWorld::load(temporary_test_file_path.clone()).unwrap();
+
+    fs::remove_file(temporary_test_file_path.clone()).unwrap();
+    assert!(!temporary_test_file_path.exists());
+
+    let expected_names: Vec<String> = stored_world
+      .into_iter()
+      .map(|model| model.get_name())
+      .collect();
+    let compressed_names: Vec<String> = compressed_world
+      .into_iter()
+      .map(|model| model.get_name())
+      .collect();
+    let auto_detected_names: Vec<String> = auto_detected_world
+      .into_iter()
+      .map(|model| model.get_name())
+      .collect();
+
+    assert_eq!(compressed_names, expected_names);
+    assert_eq!(auto_detected_names, expected_names);
+  }
+
+  #[test]
+  fn load_detects_compression_by_header() {
+    // Uses the regular world extension so only the gzip header can be used for detection.
+    let temporary_test_file_path: PathBuf = generate_temporary_test_file_path();
+    let stored_world = StoredWorld::new(TestingData::get_multiple_test_models((10, 10), 5));
+
+    stored_world
+      .save_compressed(temporary_test_file_path.clone())
+      .unwrap();
+    let loaded_world = StoredWorld::load(temporary_test_file_path.clone()).unwrap();
+
+    fs::remove_file(temporary_test_file_path.clone()).unwrap();
+    assert!(!temporary_test_file_path.exists());
+    assert_eq!(loaded_world.model_count(), 5);
+  }
+
+  #[test]
+  fn load_compressed_uncompressed_file() {
+    let temporary_test_file_path: PathBuf = generate_temporary_test_file_path();
+    let stored_world = StoredWorld::new(TestingData::get_multiple_test_models((10, 10), 5));
+
+    stored_world.save(temporary_test_file_path.clone()).unwrap();
+    let result = StoredWorld::load_compressed(temporary_test_file_path.clone());
+
+    fs::remove_file(temporary_test_file_path.clone()).unwrap();
+    assert!(!temporary_test_file_path.exists());
+    assert!(result.is_err());
+  }
+
   #[test]
   fn save_path_parent_does_not_exist() {
     let mut temporary_test_file_path: PathBuf = generate_temporary_test_file_path();
@@ -236,6 +440,46 @@ mod tests {
     assert!(result.is_err());
   }
 
+  #[test]
+  fn load_streaming_logic() {
+    let temporary_test_file_path: PathBuf = generate_temporary_test_file_path();
+    let model_count = 100;
+    let test_models = TestingData::get_multiple_test_models((10, 10), model_count);
+    test_models
+      .iter()
+      .enumerate()
+      .for_each(|(index, model)| model.change_name(index.to_string()));
+    let stored_world = StoredWorld::new(test_models);
+
+    stored_world.save(temporary_test_file_path.clone()).unwrap();
+
+    let mut loaded_model_names = HashSet::new();
+    let mut callback_count = 0;
+    let result = StoredWorld::load_streaming(&temporary_test_file_path, |stored_model| {
+      let model = ModelData::from_stored(stored_model).unwrap();
+
+      loaded_model_names.insert(model.get_name());
+      callback_count += 1;
+    });
+
+    fs::remove_file(temporary_test_file_path.clone()).unwrap();
+    assert!(!temporary_test_file_path.exists());
+    assert!(result.is_ok());
+    assert_eq!(callback_count, model_count);
+    assert_eq!(loaded_model_names.len(), model_count as usize);
+  }
+
+  #[test]
+  fn load_streaming_fake_path() {
+    let path: PathBuf = generate_temporary_test_file_path();
+
+    let expected_result = ScreenError::FileDoesNotExist;
+
+    let result = StoredWorld::load_streaming(&path, |_| {}).unwrap_err();
+
+    assert_eq!(result, expected_result);
+  }
+
   #[test]
   fn load_fake_path() {
     let path: PathBuf = generate_temporary_test_file_path();
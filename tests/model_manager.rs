@@ -2,6 +2,7 @@
 
 use ascii_engine::prelude::*;
 use model_data_structures::models::testing_data::TestingData;
+use std::collections::HashMap;
 
 const WORLD_POSITION: (usize, usize) = (10, 10);
 
@@ -17,6 +18,41 @@ fn model_list_contains_models() {
   })
 }
 
+#[test]
+fn count_models_logic() {
+  let mut tagged_model = TestingData::new_test_model(WORLD_POSITION);
+  tagged_model.add_tags(vec!["Enemy".to_string()]);
+  let models = vec![
+    tagged_model,
+    TestingData::new_test_model((20, 15)),
+    TestingData::new_test_model((35, 5)),
+  ];
+  let (_, model_manager) = setup_model_manager(models);
+
+  assert_eq!(model_manager.count_models(), 3);
+  assert_eq!(model_manager.count_models_with_tag("Enemy"), 1);
+  assert_eq!(model_manager.count_models_with_tag("Wall"), 0);
+}
+
+#[test]
+fn model_positions_logic() {
+  let models = vec![
+    TestingData::new_test_model(WORLD_POSITION),
+    TestingData::new_test_model((20, 15)),
+    TestingData::new_test_model((35, 5)),
+  ];
+  let (_, model_manager) = setup_model_manager(models.clone());
+
+  let expected_positions: HashMap<u64, (isize, isize)> = models
+    .iter()
+    .map(|model| (model.get_hash(), model.get_world_position()))
+    .collect();
+
+  let model_positions = model_manager.model_positions();
+
+  assert_eq!(model_positions, expected_positions);
+}
+
 #[cfg(test)]
 mod get_model_logic {
   use super::*;
@@ -224,6 +260,110 @@ mod model_movement_and_collision_logic {
     assert_eq!(model_one.get_world_position(), expected_model_one_position); // Ensure there was no movement.
   }
 
+  #[test]
+  fn hitbox_anchor_offset_shifts_collisions() {
+    let mut model_one = TestingData::new_test_model(WORLD_POSITION);
+    // Places the hitboxes 1 space apart from each other.
+    let model_two = TestingData::new_test_model(WORLD_POSITION.add((6, 0)));
+    let (_, model_manager) = setup_model_manager(vec![model_one.clone(), model_two.clone()]);
+    let no_movement = ModelMovement::Relative((0, 0));
+
+    let expected_collisions = VecDeque::from([model_two.get_hash()]);
+    let expected_frame_position = model_one.get_frame_position();
+    let expected_world_position = model_one.get_world_position();
+    let expected_sprite = model_one.get_sprite();
+
+    let collisions_before_offset = model_manager
+      .check_if_movement_causes_collisions(&model_one.get_hash(), no_movement)
+      .unwrap();
+
+    model_one.set_hitbox_anchor_offset((1, 0));
+    let collisions_with_small_offset = model_manager
+      .check_if_movement_causes_collisions(&model_one.get_hash(), no_movement)
+      .unwrap();
+
+    model_one.set_hitbox_anchor_offset((2, 0));
+    let collisions_with_offset = model_manager
+      .check_if_movement_causes_collisions(&model_one.get_hash(), no_movement)
+      .unwrap()
+      .expect("There were no collisions detected.");
+
+    assert!(collisions_before_offset.is_none());
+    assert!(collisions_with_small_offset.is_none());
+    assert_eq!(expected_collisions, collisions_with_offset.collision_list);
+    assert_eq!(model_one.get_hitbox_anchor_offset(), (2, 0));
+    assert_eq!(model_one.get_frame_position(), expected_frame_position);
+    assert_eq!(model_one.get_world_position(), expected_world_position);
+    assert_eq!(model_one.get_sprite(), expected_sprite);
+  }
+
+  #[test]
+  fn collision_layers_filter_collisions() {
+    const PLAYER_LAYER: u32 = 0b001;
+    const DECORATION_LAYER: u32 = 0b010;
+    const ENEMY_LAYER: u32 = 0b100;
+
+    let mut player = TestingData::new_test_model(WORLD_POSITION);
+    let mut decoration = TestingData::new_test_model(WORLD_POSITION.add((2, 0)));
+    let mut enemy = TestingData::new_test_model(WORLD_POSITION.add((0, 1)));
+    player.set_collision_layer(PLAYER_LAYER);
+    player.set_collision_mask(ENEMY_LAYER);
+    decoration.set_collision_layer(DECORATION_LAYER);
+    decoration.set_collision_mask(DECORATION_LAYER);
+    enemy.set_collision_layer(ENEMY_LAYER);
+    enemy.set_collision_mask(PLAYER_LAYER);
+    let (_, model_manager) =
+      setup_model_manager(vec![player.clone(), decoration.clone(), enemy.clone()]);
+    let no_movement = ModelMovement::Relative((0, 0));
+
+    let expected_collisions = VecDeque::from([enemy.get_hash()]);
+
+    let collisions = model_manager
+      .check_if_movement_causes_collisions(&player.get_hash(), no_movement)
+      .unwrap()
+      .expect("There were no collisions detected.");
+
+    assert_eq!(collisions.collision_list, expected_collisions);
+    assert!(!player.collision_layers_overlap(&decoration));
+    assert!(player.collision_layers_overlap(&enemy));
+  }
+
+  #[test]
+  fn apply_knockback_open_space() {
+    let model = TestingData::new_test_model((20, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    let expected_position = (25, 10);
+
+    let collisions = model_manager
+      .apply_knockback(&model.get_hash(), (10, 10), 5)
+      .unwrap();
+
+    assert!(collisions.is_none());
+    assert_eq!(model.get_world_position(), expected_position);
+  }
+
+  #[test]
+  fn apply_knockback_into_wall() {
+    let model = TestingData::new_test_model((20, 10));
+    let wall = TestingData::new_test_model((30, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone(), wall.clone()]);
+
+    // The model's hitbox is touching the wall after 5 spaces.
+    let expected_position = (25, 10);
+    let expected_collisions = VecDeque::from([wall.get_hash()]);
+
+    let collisions = model_manager
+      .apply_knockback(&model.get_hash(), (10, 10), 10)
+      .unwrap()
+      .expect("There were no collisions detected.");
+
+    assert_eq!(collisions.collider, model.get_hash());
+    assert_eq!(collisions.caused_movement, ModelMovement::Relative((1, 0)));
+    assert_eq!(collisions.collision_list, expected_collisions);
+    assert_eq!(model.get_world_position(), expected_position);
+  }
+
   #[test]
   fn check_if_movement_causes_collisions_no_collisions() {
     let model = TestingData::new_test_model(WORLD_POSITION);
@@ -253,199 +393,1686 @@ fn check_if_movement_causes_collisions_model_does_not_exist() {
   assert_eq!(result, expected_result);
 }
 
-#[cfg(test)]
-mod animation_tests {
-  use super::*;
-
-  #[cfg(test)]
-  mod queue_model_animation_logic {
-    use super::*;
+#[test]
+fn models_within_radius_logic() {
+  let center = (40, 20);
+  let horizontal_model = TestingData::new_test_model((46, 20));
+  // With the configured aspect ratio of 2.0, 4 rows is 8 characters away.
+  let vertical_model = TestingData::new_test_model((40, 24));
+  let distant_model = TestingData::new_test_model((60, 20));
+  let (_, model_manager) = setup_model_manager(vec![
+    horizontal_model.clone(),
+    vertical_model.clone(),
+    distant_model.clone(),
+  ]);
+
+  let expected_models = vec![horizontal_model.get_hash()];
+
+  let models = model_manager.models_within_radius(center, 6.0);
+
+  assert_eq!(models, expected_models);
+}
 
-    #[test]
-    fn model_doesnt_exist() {
-      let (_, mut model_manager) = setup_model_manager(vec![]);
+#[test]
+fn nearest_model_with_tag_logic() {
+  let mut player = TestingData::new_test_model((10, 10));
+  let mut near_enemy = TestingData::new_test_model((20, 10));
+  let mut far_enemy = TestingData::new_test_model((40, 30));
+  let untagged_model = TestingData::new_test_model((12, 10));
+  player.add_tags(vec!["Enemy".to_string()]);
+  near_enemy.add_tags(vec!["Enemy".to_string()]);
+  far_enemy.add_tags(vec!["Enemy".to_string()]);
+  let (_, model_manager) = setup_model_manager(vec![
+    player.clone(),
+    near_enemy.clone(),
+    far_enemy.clone(),
+    untagged_model.clone(),
+  ]);
+
+  let nearest_enemy = model_manager.nearest_model_with_tag(&player.get_hash(), "Enemy");
+  let nearest_missing_tag = model_manager.nearest_model_with_tag(&player.get_hash(), "Ally");
+  let missing_model = model_manager.nearest_model_with_tag(&0, "Enemy");
+
+  assert_eq!(nearest_enemy, Some(near_enemy.get_hash()));
+  assert!(nearest_missing_tag.is_none());
+  assert!(missing_model.is_none());
+}
 
-      let expected_result = ModelError::ModelDoesntExist;
+#[test]
+fn models_in_strata_logic() {
+  let background_model = TestingData::new_test_model(WORLD_POSITION);
+  let mut foreground_model = TestingData::new_test_model(WORLD_POSITION);
+  foreground_model.change_strata(Strata(90)).unwrap();
+  let (_, model_manager) =
+    setup_model_manager(vec![background_model.clone(), foreground_model.clone()]);
+
+  let background_models = model_manager.models_in_strata(background_model.get_strata());
+  let foreground_models = model_manager.models_in_strata(Strata(90));
+  let empty_strata_models = model_manager.models_in_strata(Strata(0));
+
+  assert_eq!(background_models, vec![background_model.get_hash()]);
+  assert_eq!(foreground_models, vec![foreground_model.get_hash()]);
+  assert!(empty_strata_models.is_empty());
+}
 
-      let result = model_manager
-        .queue_model_animation(&0, "", false)
-        .unwrap_err();
+#[test]
+fn models_in_region_logic() {
+  // The test model's hitbox is 5x3 around its anchor, so its top left corner is at (8, 9).
+  let corner_model = TestingData::new_test_model((10, 10));
+  let distant_model = TestingData::new_test_model((30, 10));
+  let hitboxless_model = TestingData::new_test_model_no_hitbox((5, 5));
+  let (_, model_manager) = setup_model_manager(vec![
+    corner_model.clone(),
+    distant_model.clone(),
+    hitboxless_model.clone(),
+  ]);
+
+  let expected_models = vec![corner_model.get_hash()];
+
+  let models = model_manager.models_in_region((0, 0), Rectangle::new(9, 10));
+  let models_just_outside = model_manager.models_in_region((0, 0), Rectangle::new(8, 10));
+
+  assert_eq!(models, expected_models);
+  assert!(models_just_outside.is_empty());
+}
 
-      assert_eq!(result, expected_result);
-    }
+#[cfg(test)]
+mod move_model_checked_logic {
+  use super::*;
 
-    #[test]
-    fn queue_animation() {
-      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
-      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+  #[test]
+  fn movement_without_collisions() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
 
-      let expected_animation_name = Some(TestingData::ANIMATION_NAME.to_string());
+    let collisions = model_manager
+      .move_model_checked(&model.get_hash(), ModelMovement::Relative((3, 0)))
+      .unwrap();
 
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
-        .unwrap();
+    assert!(collisions.is_none());
+    assert_eq!(model.get_world_position(), (13, 10));
+  }
 
-      let model_appearance = model.get_appearance_data();
-      let model_appearance = model_appearance.lock().unwrap();
+  #[test]
+  fn collision_cancels_movement() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let wall = TestingData::new_test_model((20, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone(), wall.clone()]);
 
-      assert_eq!(
-        model_appearance.current_animation_name(),
-        expected_animation_name
-      );
-    }
+    let collision = model_manager
+      .move_model_checked(&model.get_hash(), ModelMovement::Relative((6, 0)))
+      .unwrap()
+      .unwrap();
+    let collision_events = model_manager.take_collision_events();
 
-    #[test]
-    fn queue_animation_no_duplicates() {
-      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
-      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+    assert_eq!(model.get_world_position(), (10, 10));
+    assert_eq!(collision.collider, model.get_hash());
+    assert_eq!(collision.collision_list, vec![wall.get_hash()]);
+    assert!(collision_events.is_empty());
+  }
 
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
-        .unwrap();
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
-        .unwrap();
+  #[test]
+  fn out_of_bounds() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
 
-      let model_appearance = model.get_appearance_data();
-      let mut model_appearance = model_appearance.lock().unwrap();
+    let result =
+      model_manager.move_model_checked(&model.get_hash(), ModelMovement::Relative((0, -20)));
 
-      model_appearance.remove_current_model_animation();
+    assert_eq!(result, Err(ModelError::ModelOutOfBounds));
+    assert_eq!(model.get_world_position(), (10, 10));
+  }
 
-      assert!(model_appearance
-        .animation_is_currently_queued(TestingData::ANIMATION_NAME)
-        .is_none());
-    }
+  #[test]
+  fn model_doesnt_exist() {
+    let (_, mut model_manager) = setup_model_manager(vec![]);
 
-    #[test]
-    fn queue_animation_allow_duplicates() {
-      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
-      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+    let result = model_manager.move_model_checked(&0, ModelMovement::Relative((1, 0)));
 
-      let expected_animation_name = Some(TestingData::ANIMATION_NAME.to_string());
+    assert_eq!(result, Err(ModelError::ModelDoesntExist));
+  }
+}
 
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
-        .unwrap();
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
-        .unwrap();
+#[cfg(test)]
+mod teleport_model_to_model_logic {
+  use super::*;
 
-      let model_appearance = model.get_appearance_data();
-      let mut model_appearance = model_appearance.lock().unwrap();
+  #[test]
+  fn mover_is_placed_at_offset() {
+    let mover = TestingData::new_test_model((30, 20));
+    let target = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![mover.clone(), target.clone()]);
 
-      model_appearance.remove_current_model_animation();
+    let collisions = model_manager
+      .teleport_model_to_model(&mover.get_hash(), &target.get_hash(), (0, -3))
+      .unwrap();
 
-      assert_eq!(
-        model_appearance.current_animation_name(),
-        expected_animation_name
-      );
-    }
+    assert!(collisions.is_none());
+    assert_eq!(mover.get_world_position(), (10, 7));
   }
 
-  #[cfg(test)]
-  mod overwrite_current_model_animation_logic {
-    use super::*;
-
-    #[test]
-    fn model_doesnt_exist() {
-      let (_, mut model_manager) = setup_model_manager(vec![]);
+  #[test]
+  fn overlapping_target_collides() {
+    let mover = TestingData::new_test_model((30, 20));
+    let target = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![mover.clone(), target.clone()]);
 
-      let expected_result = ModelError::ModelDoesntExist;
+    let collisions = model_manager
+      .teleport_model_to_model(&mover.get_hash(), &target.get_hash(), (0, 0))
+      .unwrap()
+      .expect("The mover didn't collide with the target.");
 
-      let result = model_manager
-        .overwrite_current_model_animation(&0, "")
-        .unwrap_err();
+    assert!(collisions.contains_model(&target.get_hash()));
+  }
 
-      assert_eq!(result, expected_result);
-    }
+  #[test]
+  fn missing_models() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
 
-    #[test]
-    fn model_exists() {
-      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
-      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
-      let new_animation =
-        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(2));
-      let new_animation_name = "new_animation".to_string();
+    let missing_target = model_manager.teleport_model_to_model(&model.get_hash(), &0, (0, 0));
+    let missing_mover = model_manager.teleport_model_to_model(&0, &model.get_hash(), (0, 0));
 
-      model_manager
-        .add_animation_to_model(&model.get_hash(), new_animation_name.clone(), new_animation)
-        .unwrap();
+    assert_eq!(missing_target, Err(ModelError::ModelDoesntExist));
+    assert_eq!(missing_mover, Err(ModelError::ModelDoesntExist));
+  }
+}
 
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
-        .unwrap();
+#[cfg(test)]
+mod raycast_logic {
+  use super::*;
 
-      model_manager
-        .overwrite_current_model_animation(&model.get_hash(), &new_animation_name)
-        .unwrap();
+  #[test]
+  fn first_model_is_hit() {
+    let caster = TestingData::new_test_model(WORLD_POSITION);
+    let near_model = TestingData::new_test_model((30, 10));
+    let far_model = TestingData::new_test_model((50, 10));
+    let (_, model_manager) =
+      setup_model_manager(vec![caster.clone(), near_model.clone(), far_model]);
 
-      let model_appearance = model.get_appearance_data();
-      let model_appearance = model_appearance.lock().unwrap();
+    // The test model's hitbox is 5 wide, so the ray enters the near model 2 cells before its position.
+    let hit = model_manager.raycast(caster.get_world_position(), (1, 0), 100);
 
-      assert_eq!(
-        model_appearance.current_animation_name(),
-        Some(new_animation_name)
-      );
-    }
+    assert_eq!(hit, Some((near_model.get_hash(), 18)));
   }
 
-  #[cfg(test)]
-  mod add_animation_to_model_logic {
-    use super::*;
+  #[test]
+  fn overlapping_models_hit_by_strata_then_hash() {
+    let caster = TestingData::new_test_model(WORLD_POSITION);
+    let mut top_model = TestingData::new_test_model((30, 10));
+    let same_strata_models = TestingData::get_multiple_test_models((30, 10), 2);
+    top_model.change_strata(Strata(90)).unwrap();
+    let mut models = same_strata_models.clone();
+    models.extend([caster.clone(), top_model.clone()]);
+    let (_, mut model_manager) = setup_model_manager(models);
+
+    let top_hit = model_manager.raycast(caster.get_world_position(), (1, 0), 100);
+    model_manager.remove_model(&top_model.get_hash()).unwrap();
+    let same_strata_hit = model_manager.raycast(caster.get_world_position(), (1, 0), 100);
+
+    let lowest_hash = same_strata_models
+      .iter()
+      .map(ModelData::get_hash)
+      .min()
+      .unwrap();
 
-    #[test]
-    fn model_doesnt_exist() {
-      let animation =
-        TestingData::get_test_animation(['1', '2', '3'], AnimationLoopCount::Limited(2));
-      let (_, mut model_manager) = setup_model_manager(vec![]);
+    assert_eq!(top_hit, Some((top_model.get_hash(), 18)));
+    assert_eq!(same_strata_hit, Some((lowest_hash, 18)));
+  }
 
-      let expected_result = ModelError::ModelDoesntExist;
+  #[test]
+  fn direction_is_reduced_to_its_sign() {
+    let caster = TestingData::new_test_model(WORLD_POSITION);
+    let target = TestingData::new_test_model((10, 30));
+    let (_, model_manager) = setup_model_manager(vec![caster.clone(), target.clone()]);
 
-      let result = model_manager
-        .add_animation_to_model(&0, "This model does not exist.".to_string(), animation)
-        .unwrap_err();
+    let hit = model_manager.raycast(caster.get_world_position(), (0, 7), 100);
 
-      assert_eq!(result, expected_result);
-    }
+    assert_eq!(hit, Some((target.get_hash(), 19)));
   }
 
-  #[cfg(test)]
-  mod clear_model_animation_queue_logic {
-    use super::*;
+  #[test]
+  fn nothing_is_hit() {
+    let caster = TestingData::new_test_model(WORLD_POSITION);
+    let target = TestingData::new_test_model((30, 10));
+    let (_, model_manager) = setup_model_manager(vec![caster.clone(), target]);
+
+    let out_of_range = model_manager.raycast(caster.get_world_position(), (1, 0), 17);
+    let wrong_direction = model_manager.raycast(caster.get_world_position(), (-1, 0), 100);
+    let no_direction = model_manager.raycast(caster.get_world_position(), (0, 0), 100);
+
+    assert!(out_of_range.is_none());
+    assert!(wrong_direction.is_none());
+    assert!(no_direction.is_none());
+  }
+}
 
-    #[test]
-    fn model_doesnt_exist() {
-      let (_, mut model_manager) = setup_model_manager(vec![]);
+#[cfg(test)]
+mod change_model_strata_logic {
+  use super::*;
 
-      let expected_result = ModelError::ModelDoesntExist;
+  #[test]
+  fn model_is_drawn_on_new_strata() {
+    let bottom_model = TestingData::new_test_model(WORLD_POSITION);
+    let top_model = TestingData::new_test_model(WORLD_POSITION);
+    let (screen, mut model_manager) =
+      setup_model_manager(vec![bottom_model.clone(), top_model.clone()]);
+    let top_left = top_model.get_frame_position();
+    let mut top_model_appearance = top_model.clone();
+    top_model_appearance
+      .get_appearance_data()
+      .lock()
+      .unwrap()
+      .update_default_sprite(Sprite::new("#####\n##a##\n#####", 'a', '#', '-').unwrap());
 
-      let result = model_manager.clear_model_animation_queue(&0).unwrap_err();
+    model_manager
+      .change_model_strata(&top_model.get_hash(), Strata(100))
+      .unwrap();
 
-      assert_eq!(result, expected_result);
-    }
+    assert_eq!(
+      model_manager.models_in_strata(Strata(100)),
+      vec![top_model.get_hash()]
+    );
+    assert_eq!(screen.display().chars().nth(top_left), Some('#'));
+  }
 
-    #[test]
-    fn model_exists() {
-      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
-      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+  #[test]
+  fn model_doesnt_exist() {
+    let (_, mut model_manager) = setup_model_manager(vec![]);
 
-      model_manager
-        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
-        .unwrap();
+    let result = model_manager.change_model_strata(&0, Strata(10));
 
-      model_manager
-        .clear_model_animation_queue(&model.get_hash())
-        .unwrap();
+    assert_eq!(result, Err(ModelError::ModelDoesntExist));
+  }
+}
 
-      let model_appearance = model.get_appearance_data();
-      let model_appearance = model_appearance.lock().unwrap();
+#[test]
+fn models_sorted_by_strata_logic() {
+  let bottom_model = TestingData::new_test_model(WORLD_POSITION);
+  let middle_model = TestingData::new_test_model((20, 15));
+  let top_model = TestingData::new_test_model((35, 5));
+  let (_, mut model_manager) = setup_model_manager(vec![
+    top_model.clone(),
+    bottom_model.clone(),
+    middle_model.clone(),
+  ]);
+  model_manager
+    .change_model_strata(&bottom_model.get_hash(), Strata(-5))
+    .unwrap();
+  model_manager
+    .change_model_strata(&top_model.get_hash(), Strata(100))
+    .unwrap();
+
+  let expected_order = vec![
+    (Strata(-5), vec![bottom_model.get_hash()]),
+    (Strata(20), vec![middle_model.get_hash()]),
+    (Strata(100), vec![top_model.get_hash()]),
+  ];
+
+  let strata_order = model_manager.models_sorted_by_strata();
+
+  assert_eq!(strata_order, expected_order);
+}
 
-      assert_eq!(
-        model_appearance.animation_is_currently_queued(TestingData::ANIMATION_NAME),
+#[cfg(test)]
+mod batch_logic {
+  use super::*;
+
+  #[test]
+  fn changes_are_applied() {
+    let moved_model = TestingData::new_test_model(WORLD_POSITION);
+    let removed_model = TestingData::new_test_model((30, 20));
+    let (_, mut model_manager) =
+      setup_model_manager(vec![moved_model.clone(), removed_model.clone()]);
+
+    let result = model_manager.batch(|batch| {
+      batch.move_model(&moved_model.get_hash(), ModelMovement::Absolute((40, 10)))?;
+      batch.change_model_strata(&moved_model.get_hash(), Strata(50))?;
+      batch.remove_model(&removed_model.get_hash());
+
+      batch
+        .get_model(&removed_model.get_hash())
+        .map_or(Ok(()), |_| {
+          Err(ModelError::Other("The model wasn't removed".to_string()))
+        })
+    });
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(moved_model.get_world_position(), (40, 10));
+    assert_eq!(
+      model_manager.models_in_strata(Strata(50)),
+      vec![moved_model.get_hash()]
+    );
+    assert!(!model_manager.model_exists(&removed_model.get_hash()));
+  }
+
+  #[test]
+  fn collisions_are_added_after_batch() {
+    let moving_model = TestingData::new_test_model(WORLD_POSITION);
+    let collided_model = TestingData::new_test_model((30, 20));
+    let (_, mut model_manager) =
+      setup_model_manager(vec![moving_model.clone(), collided_model.clone()]);
+
+    let collision = model_manager
+      .batch(|batch| batch.move_model(&moving_model.get_hash(), ModelMovement::Absolute((29, 20))))
+      .unwrap()
+      .expect("There were no collisions detected.");
+
+    let collision_events = model_manager.take_collision_events();
+
+    assert_eq!(collision.collision_list, vec![collided_model.get_hash()]);
+    assert_eq!(collision_events.len(), 1);
+    assert_eq!(collision_events[0].1, collision);
+  }
+}
+
+#[cfg(test)]
+mod swap_positions_logic {
+  use super::*;
+
+  #[test]
+  fn positions_are_exchanged() {
+    let first_model = TestingData::new_test_model(WORLD_POSITION);
+    let second_model = TestingData::new_test_model((30, 20));
+    let (_, mut model_manager) =
+      setup_model_manager(vec![first_model.clone(), second_model.clone()]);
+
+    model_manager
+      .swap_positions(&first_model.get_hash(), &second_model.get_hash())
+      .unwrap();
+
+    assert_eq!(first_model.get_world_position(), (30, 20));
+    assert_eq!(second_model.get_world_position(), (10, 10));
+  }
+
+  #[test]
+  fn missing_models() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    let missing_second = model_manager.swap_positions(&model.get_hash(), &0);
+    let missing_first = model_manager.swap_positions(&0, &model.get_hash());
+
+    assert_eq!(missing_second, Err(ModelError::ModelDoesntExist));
+    assert_eq!(missing_first, Err(ModelError::ModelDoesntExist));
+    assert_eq!(model.get_world_position(), (10, 10));
+  }
+}
+
+#[cfg(test)]
+mod snapshot_logic {
+  use super::*;
+
+  #[test]
+  fn changes_are_undone() {
+    let mut model = TestingData::new_test_model(WORLD_POSITION);
+    model.add_tags(vec!["Player".to_string()]);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+    let snapshot = model_manager.snapshot();
+
+    model_manager
+      .move_model(&model.get_hash(), ModelMovement::Absolute((30, 20)))
+      .unwrap();
+    model_manager
+      .change_model_strata(&model.get_hash(), Strata(100))
+      .unwrap();
+    model.clear_tags();
+    model.add_tags(vec!["Enemy".to_string()]);
+
+    model_manager.restore(snapshot);
+
+    assert_eq!(model.get_world_position(), (10, 10));
+    assert_eq!(model.get_strata(), Strata(20));
+    assert!(model_manager.models_in_strata(Strata(100)).is_empty());
+    assert_eq!(
+      model_manager.get_models_with_tags(vec!["Player"]),
+      vec![model.get_hash()]
+    );
+    assert!(!model.contains_tag("Enemy"));
+  }
+
+  #[test]
+  fn missing_and_new_models_are_ignored() {
+    let removed_model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![removed_model.clone()]);
+    let snapshot = model_manager.snapshot();
+    let new_model = TestingData::new_test_model((30, 20));
+
+    model_manager.remove_model(&removed_model.get_hash());
+    model_manager
+      .add_models_to_world(vec![new_model.clone()])
+      .unwrap();
+
+    assert_eq!(snapshot.len(), 1);
+    assert!(snapshot.contains_model(&removed_model.get_hash()));
+
+    model_manager.restore(snapshot);
+
+    assert!(!model_manager.model_exists(&removed_model.get_hash()));
+    assert_eq!(new_model.get_world_position(), (30, 20));
+  }
+}
+
+#[cfg(test)]
+mod find_path_logic {
+  use super::*;
+
+  #[test]
+  fn path_goes_around_models() {
+    let wall = TestingData::new_test_model(WORLD_POSITION);
+    let (_, model_manager) = setup_model_manager(vec![wall]);
+    // The wall's hitbox covers the cells from (8, 9) to (12, 11).
+    let blocked_cells = |(x, y): &(usize, usize)| (8..=12).contains(x) && (9..=11).contains(y);
+
+    let path = model_manager.find_path((5, 10), (15, 10), &[]).unwrap();
+
+    assert_eq!(path.first(), Some(&(5, 10)));
+    assert_eq!(path.last(), Some(&(15, 10)));
+    // 10 steps across, and 2 steps up and back down around the wall.
+    assert_eq!(path.len(), 15);
+    assert!(!path.iter().any(blocked_cells));
+    assert!(path
+      .windows(2)
+      .all(|cells| cells[0].0.abs_diff(cells[1].0) + cells[0].1.abs_diff(cells[1].1) == 1));
+  }
+
+  #[test]
+  fn ignored_models_arent_obstacles() {
+    let wall = TestingData::new_test_model(WORLD_POSITION);
+    let (_, model_manager) = setup_model_manager(vec![wall.clone()]);
+
+    let path = model_manager
+      .find_path((5, 10), (15, 10), &[wall.get_hash()])
+      .unwrap();
+
+    let expected_path: Vec<(usize, usize)> = (5..=15).map(|x| (x, 10)).collect();
+
+    assert_eq!(path, expected_path);
+  }
+
+  #[test]
+  fn unreachable_goal() {
+    let wall = TestingData::new_test_model(WORLD_POSITION);
+    let (_, model_manager) = setup_model_manager(vec![wall]);
+
+    let blocked_goal = model_manager.find_path((5, 10), WORLD_POSITION, &[]);
+    let out_of_bounds_goal = model_manager.find_path((5, 10), (1000, 10), &[]);
+
+    assert!(blocked_goal.is_none());
+    assert!(out_of_bounds_goal.is_none());
+  }
+}
+
+#[cfg(test)]
+mod fractional_movement_logic {
+  use super::*;
+
+  #[test]
+  fn moves_once_a_whole_cell_is_built_up() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+    let movement = ModelMovement::RelativeF((0.25, -0.5));
+
+    for _ in 0..3 {
+      model_manager
+        .move_model(&model.get_hash(), movement)
+        .unwrap();
+    }
+    let position_after_three_moves = model.get_world_position();
+    model_manager
+      .move_model(&model.get_hash(), movement)
+      .unwrap();
+
+    assert_eq!(position_after_three_moves, (10, 9));
+    assert_eq!(model.get_world_position(), (11, 8));
+    assert_eq!(model.get_movement_remainder(), (0.0, 0.0));
+  }
+
+  #[test]
+  fn checking_movement_keeps_remainder() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    model_manager
+      .move_model(&model.get_hash(), ModelMovement::RelativeF((0.75, 0.0)))
+      .unwrap();
+    model_manager
+      .check_if_movement_causes_collisions(&model.get_hash(), ModelMovement::RelativeF((0.5, 0.0)))
+      .unwrap();
+
+    assert_eq!(model.get_movement_remainder(), (0.75, 0.0));
+    assert_eq!(model.get_world_position(), (10, 10));
+  }
+}
+
+#[cfg(test)]
+mod move_all_with_tag_logic {
+  use super::*;
+
+  #[test]
+  fn only_tagged_models_move() {
+    let mut background_1 = TestingData::new_test_model((10, 10));
+    let mut background_2 = TestingData::new_test_model((30, 10));
+    let untagged = TestingData::new_test_model((50, 10));
+    let wall = TestingData::new_test_model((32, 20));
+    background_1.add_tags(vec!["Background".to_string()]);
+    background_2.add_tags(vec!["Background".to_string()]);
+    let (_, mut model_manager) = setup_model_manager(vec![
+      background_1.clone(),
+      background_2.clone(),
+      untagged.clone(),
+      wall.clone(),
+    ]);
+
+    let collisions =
+      model_manager.move_all_with_tag("Background", ModelMovement::Relative((0, 10)));
+
+    assert_eq!(background_1.get_world_position(), (10, 20));
+    assert_eq!(background_2.get_world_position(), (30, 20));
+    assert_eq!(untagged.get_world_position(), (50, 10));
+
+    assert_eq!(collisions.len(), 1);
+    let (collider, collision) = &collisions[0];
+    assert_eq!(*collider, background_2.get_hash());
+    assert_eq!(collision.collision_list, vec![wall.get_hash()]);
+    assert_eq!(model_manager.take_collision_events().len(), 1);
+  }
+
+  #[test]
+  fn out_of_bounds_models_stay_in_place() {
+    let mut near_edge = TestingData::new_test_model((10, 2));
+    let mut far_from_edge = TestingData::new_test_model((30, 20));
+    near_edge.add_tags(vec!["Background".to_string()]);
+    far_from_edge.add_tags(vec!["Background".to_string()]);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![near_edge.clone(), far_from_edge.clone()]);
+
+    let collisions =
+      model_manager.move_all_with_tag("Background", ModelMovement::Relative((0, -5)));
+
+    assert!(collisions.is_empty());
+    assert_eq!(near_edge.get_world_position(), (10, 2));
+    assert_eq!(far_from_edge.get_world_position(), (30, 15));
+  }
+}
+
+mod move_model_axis_separated_logic {
+  use super::*;
+  use std::collections::VecDeque;
+
+  #[test]
+  fn slides_along_wall() {
+    // The test models are 5 wide and 3 tall, so this wall sits directly to the right of the mover.
+    let mover = TestingData::new_test_model((10, 10));
+    let wall = TestingData::new_test_model((15, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![mover.clone(), wall.clone()]);
+
+    let axis_collisions = model_manager
+      .move_model_axis_separated(&mover.get_hash(), (1, 3))
+      .unwrap();
+
+    let expected_collisions = AxisCollisions {
+      x_collisions: vec![wall.get_hash()].into(),
+      y_collisions: vec![].into(),
+    };
+
+    assert_eq!(axis_collisions, expected_collisions);
+    assert_eq!(mover.get_world_position(), (10, 13));
+  }
+
+  #[test]
+  fn both_axes_blocked() {
+    let mover = TestingData::new_test_model((10, 10));
+    let right_wall = TestingData::new_test_model((15, 10));
+    let floor = TestingData::new_test_model((10, 13));
+    let (_, mut model_manager) =
+      setup_model_manager(vec![mover.clone(), right_wall.clone(), floor.clone()]);
+
+    let axis_collisions = model_manager
+      .move_model_axis_separated(&mover.get_hash(), (1, 1))
+      .unwrap();
+
+    assert_eq!(
+      axis_collisions.x_collisions,
+      VecDeque::from([right_wall.get_hash()])
+    );
+    assert_eq!(
+      axis_collisions.y_collisions,
+      VecDeque::from([floor.get_hash()])
+    );
+    assert_eq!(mover.get_world_position(), (10, 10));
+    assert_eq!(model_manager.take_collision_events().len(), 2);
+  }
+
+  #[test]
+  fn free_movement() {
+    let mover = TestingData::new_test_model((10, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![mover.clone()]);
+
+    let axis_collisions = model_manager
+      .move_model_axis_separated(&mover.get_hash(), (2, 1))
+      .unwrap();
+
+    assert_eq!(axis_collisions, AxisCollisions::default());
+    assert_eq!(mover.get_world_position(), (12, 11));
+  }
+
+  #[test]
+  fn out_of_bounds() {
+    let mover = TestingData::new_test_model((10, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![mover.clone()]);
+
+    let result = model_manager.move_model_axis_separated(&mover.get_hash(), (-20, -20));
+
+    assert_eq!(result, Err(ModelError::ModelOutOfBounds));
+    assert_eq!(mover.get_world_position(), (10, 10));
+  }
+}
+
+#[cfg(test)]
+mod particle_logic {
+  use super::*;
+
+  fn particle_sprite() -> Sprite {
+    Sprite::new("a", 'a', '*', '-').unwrap()
+  }
+
+  #[test]
+  fn particle_moves_and_despawns() {
+    let (screen, mut model_manager) = setup_model_manager(vec![]);
+
+    let particle_hash = model_manager
+      .spawn_particle(particle_sprite(), (20, 10), 2, (1, 0))
+      .unwrap();
+    model_manager.step_particles();
+    let position_after_step = model_manager
+      .get_model(&particle_hash)
+      .unwrap()
+      .get_world_position();
+
+    screen.get_event_sync().wait_for_x_ticks(2).unwrap();
+    model_manager.step_particles();
+
+    assert_eq!(position_after_step, (21, 10));
+    assert!(!model_manager.model_exists(&particle_hash));
+    assert!(!model_manager.is_particle(&particle_hash));
+  }
+
+  #[test]
+  fn removed_particles_are_forgotten() {
+    let (_, mut model_manager) = setup_model_manager(vec![]);
+    let removed_particle_hash = model_manager
+      .spawn_particle(particle_sprite(), (20, 10), 10, (0, 0))
+      .unwrap();
+    let despawned_particle_hash = model_manager
+      .spawn_particle(particle_sprite(), (30, 10), 10, (0, 0))
+      .unwrap();
+    model_manager
+      .get_model(&despawned_particle_hash)
+      .unwrap()
+      .add_tags(vec!["spark".to_string()]);
+
+    model_manager.remove_model(&removed_particle_hash);
+    model_manager.despawn_all_with_tag("spark");
+
+    assert!(!model_manager.is_particle(&removed_particle_hash));
+    assert!(!model_manager.is_particle(&despawned_particle_hash));
+  }
+
+  #[test]
+  fn particles_never_collide() {
+    let model = TestingData::new_test_model((22, 10));
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    let particle_hash = model_manager
+      .spawn_particle(particle_sprite(), (20, 10), 10, (0, 0))
+      .unwrap();
+    let model_collisions = model_manager
+      .move_model(&model.get_hash(), ModelMovement::Relative((-2, 0)))
+      .unwrap();
+    let particle_collisions = model_manager
+      .check_if_movement_causes_collisions(&particle_hash, ModelMovement::Relative((0, 0)))
+      .unwrap();
+
+    assert!(model_collisions.is_none());
+    assert!(particle_collisions.is_none());
+    assert!(model_manager.model_has_collided(&particle_hash).is_none());
+    assert!(model_manager.is_particle(&particle_hash));
+  }
+}
+
+#[cfg(test)]
+mod model_relatives_logic {
+  use super::*;
+
+  #[test]
+  fn relatives_are_reported_both_ways() {
+    let models = TestingData::get_multiple_test_models(WORLD_POSITION, 3);
+    let (parent, first_child, second_child) = (
+      models[0].get_hash(),
+      models[1].get_hash(),
+      models[2].get_hash(),
+    );
+    let (_, mut model_manager) = setup_model_manager(models);
+
+    model_manager.attach_model(&first_child, &parent).unwrap();
+    model_manager.attach_model(&second_child, &parent).unwrap();
+
+    let (parents_parent, mut parents_children) = model_manager.relatives_of(&parent);
+    parents_children.sort();
+    let mut expected_children = vec![first_child, second_child];
+    expected_children.sort();
+
+    assert_eq!(parents_parent, None);
+    assert_eq!(parents_children, expected_children);
+    assert_eq!(
+      model_manager.relatives_of(&first_child),
+      (Some(parent), vec![])
+    );
+  }
+
+  #[test]
+  fn detached_model_has_no_relatives() {
+    let models = TestingData::get_multiple_test_models(WORLD_POSITION, 2);
+    let (parent, child) = (models[0].get_hash(), models[1].get_hash());
+    let (_, mut model_manager) = setup_model_manager(models);
+
+    model_manager.attach_model(&child, &parent).unwrap();
+    let former_parent = model_manager.detach_model(&child);
+
+    assert_eq!(former_parent, Some(parent));
+    assert_eq!(model_manager.relatives_of(&child), (None, vec![]));
+    assert_eq!(model_manager.relatives_of(&parent), (None, vec![]));
+  }
+
+  #[test]
+  fn circular_attachment_is_rejected() {
+    let models = TestingData::get_multiple_test_models(WORLD_POSITION, 2);
+    let (parent, child) = (models[0].get_hash(), models[1].get_hash());
+    let (_, mut model_manager) = setup_model_manager(models);
+
+    model_manager.attach_model(&child, &parent).unwrap();
+
+    assert_eq!(
+      model_manager.attach_model(&parent, &child),
+      Err(ModelError::CircularAttachment)
+    );
+    assert_eq!(
+      model_manager.attach_model(&parent, &parent),
+      Err(ModelError::CircularAttachment)
+    );
+  }
+
+  #[test]
+  fn attaching_to_missing_model() {
+    let model = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    let result = model_manager.attach_model(&model.get_hash(), &0);
+
+    assert_eq!(result, Err(ModelError::ModelDoesntExist));
+  }
+
+  #[test]
+  fn despawn_with_children_removes_whole_tree() {
+    let models = TestingData::get_multiple_test_models(WORLD_POSITION, 4);
+    let (root, child, grandchild, unrelated) = (
+      models[0].get_hash(),
+      models[1].get_hash(),
+      models[2].get_hash(),
+      models[3].get_hash(),
+    );
+    let (_, mut model_manager) = setup_model_manager(models);
+
+    model_manager.attach_model(&child, &root).unwrap();
+    model_manager.attach_model(&grandchild, &child).unwrap();
+
+    let despawned_models = model_manager.despawn_with_children(&root);
+
+    assert_eq!(despawned_models.len(), 3);
+    assert!(!model_manager.model_exists(&root));
+    assert!(!model_manager.model_exists(&child));
+    assert!(!model_manager.model_exists(&grandchild));
+    assert!(model_manager.model_exists(&unrelated));
+    assert_eq!(model_manager.relatives_of(&child), (None, vec![]));
+  }
+}
+
+#[cfg(test)]
+mod remove_model_logic {
+  use super::*;
+
+  #[test]
+  fn remove_model_logic() {
+    let parent = TestingData::new_test_model(WORLD_POSITION);
+    let child = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) = setup_model_manager(vec![parent.clone(), child.clone()]);
+    model_manager
+      .attach_model(&child.get_hash(), &parent.get_hash())
+      .unwrap();
+
+    let removed_model = model_manager.remove_model(&parent.get_hash()).unwrap();
+    let missing_model = model_manager.remove_model(&parent.get_hash());
+
+    assert_eq!(removed_model.get_hash(), parent.get_hash());
+    assert!(missing_model.is_none());
+    assert!(!model_manager.model_exists(&parent.get_hash()));
+    assert!(model_manager.model_exists(&child.get_hash()));
+    assert_eq!(
+      model_manager.relatives_of(&child.get_hash()),
+      (None, vec![])
+    );
+  }
+
+  #[test]
+  fn despawn_all_with_tag_logic() {
+    let mut enemy_1 = TestingData::new_test_model(WORLD_POSITION);
+    let mut enemy_2 = TestingData::new_test_model(WORLD_POSITION);
+    let player = TestingData::new_test_model(WORLD_POSITION);
+    enemy_1.add_tags(vec!["Enemy".to_string()]);
+    enemy_2.add_tags(vec!["Enemy".to_string()]);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![enemy_1.clone(), enemy_2.clone(), player.clone()]);
+
+    let mut expected_hashes = vec![enemy_1.get_hash(), enemy_2.get_hash()];
+    expected_hashes.sort();
+
+    let mut despawned_hashes: Vec<u64> = model_manager
+      .despawn_all_with_tag("Enemy")
+      .iter()
+      .map(ModelData::get_hash)
+      .collect();
+    despawned_hashes.sort();
+
+    assert_eq!(despawned_hashes, expected_hashes);
+    assert!(model_manager.get_models_with_tags(vec!["Enemy"]).is_empty());
+    assert!(model_manager.model_exists(&player.get_hash()));
+  }
+}
+
+#[cfg(test)]
+mod set_max_models_logic {
+  use super::*;
+
+  #[test]
+  fn rejects_models_past_the_limit() {
+    let (mut screen, mut model_manager) = setup_model_manager(vec![]);
+    model_manager.set_max_models(2);
+    let models = TestingData::get_multiple_test_models((10, 10), 3);
+
+    let expected_error = ModelError::ModelLimitReached;
+
+    model_manager
+      .add_models_to_world(models[..2].to_vec())
+      .unwrap();
+    let manager_result = model_manager
+      .add_models_to_world(vec![models[2].clone()])
+      .unwrap_err();
+    let screen_result = screen.add_model(models[2].clone()).unwrap_err();
+
+    assert_eq!(manager_result, expected_error);
+    assert_eq!(screen_result, expected_error);
+    assert!(model_manager.model_exists(&models[1].get_hash()));
+    assert!(!model_manager.model_exists(&models[2].get_hash()));
+  }
+
+  #[test]
+  fn removing_a_model_frees_space() {
+    let (mut screen, mut model_manager) = setup_model_manager(vec![]);
+    model_manager.set_max_models(1);
+    let models = TestingData::get_multiple_test_models((10, 10), 2);
+
+    model_manager
+      .add_models_to_world(vec![models[0].clone()])
+      .unwrap();
+    screen.remove_model(&models[0].get_hash());
+    let result = model_manager.add_models_to_world(vec![models[1].clone()]);
+
+    assert!(result.is_ok());
+  }
+}
+
+#[cfg(test)]
+mod explain_move_logic {
+  use super::*;
+
+  #[test]
+  fn report_lists_every_reason() {
+    let model = TestingData::new_test_model((20, 10));
+    let colliding_model = TestingData::new_test_model((27, 10));
+    let distant_model = TestingData::new_test_model((50, 30));
+    let empty_hitbox_model = TestingData::new_test_model_no_hitbox((23, 20));
+    let mut mismatched_layer_model = TestingData::new_test_model((23, 11));
+    mismatched_layer_model.set_collision_layer(0);
+    let (_, model_manager) = setup_model_manager(vec![
+      model.clone(),
+      colliding_model.clone(),
+      distant_model.clone(),
+      empty_hitbox_model.clone(),
+      mismatched_layer_model.clone(),
+    ]);
+    let movement = ModelMovement::Relative((3, 0));
+
+    let report = model_manager
+      .explain_move(&model.get_hash(), movement)
+      .unwrap();
+
+    let line_for = |hash: u64| {
+      report
+        .lines()
+        .find(|line| line.contains(&format!("({hash})")))
+        .unwrap()
+        .to_string()
+    };
+
+    assert!(report.contains("Start position: (20, 10)"));
+    assert!(report.contains("End position: (23, 10)"));
+    assert!(line_for(colliding_model.get_hash()).ends_with("collision, hitboxes overlap"));
+    assert!(line_for(distant_model.get_hash()).ends_with("hitboxes don't overlap"));
+    assert!(line_for(empty_hitbox_model.get_hash()).ends_with("a hitbox is empty"));
+    assert!(line_for(mismatched_layer_model.get_hash()).ends_with("collision layers don't match"));
+    // The model doesn't actually move.
+    assert_eq!(model.get_world_position(), (20, 10));
+  }
+
+  #[test]
+  fn model_doesnt_exist() {
+    let (_, model_manager) = setup_model_manager(vec![]);
+
+    let expected_result = ModelError::ModelDoesntExist;
+
+    let result = model_manager
+      .explain_move(&0, ModelMovement::Relative((1, 0)))
+      .unwrap_err();
+
+    assert_eq!(result, expected_result);
+  }
+}
+
+#[cfg(test)]
+mod animation_tests {
+  use super::*;
+
+  #[cfg(test)]
+  mod queue_model_animation_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, mut model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager
+        .queue_model_animation(&0, "", false)
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn queue_animation() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let expected_animation_name = Some(TestingData::ANIMATION_NAME.to_string());
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let model_appearance = model_appearance.lock().unwrap();
+
+      assert_eq!(
+        model_appearance.current_animation_name(),
+        expected_animation_name
+      );
+    }
+
+    #[test]
+    fn queue_animation_no_duplicates() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let mut model_appearance = model_appearance.lock().unwrap();
+
+      model_appearance.remove_current_model_animation();
+
+      assert!(model_appearance
+        .animation_is_currently_queued(TestingData::ANIMATION_NAME)
+        .is_none());
+    }
+
+    #[test]
+    fn queue_animation_allow_duplicates() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let expected_animation_name = Some(TestingData::ANIMATION_NAME.to_string());
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
+        .unwrap();
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let mut model_appearance = model_appearance.lock().unwrap();
+
+      model_appearance.remove_current_model_animation();
+
+      assert_eq!(
+        model_appearance.current_animation_name(),
+        expected_animation_name
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod overwrite_current_model_animation_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, mut model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager
+        .overwrite_current_model_animation(&0, "")
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn model_exists() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+      let new_animation =
+        TestingData::get_test_animation(['l', 'm', 'n'], AnimationLoopCount::Limited(2));
+      let new_animation_name = "new_animation".to_string();
+
+      model_manager
+        .add_animation_to_model(&model.get_hash(), new_animation_name.clone(), new_animation)
+        .unwrap();
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
+        .unwrap();
+
+      model_manager
+        .overwrite_current_model_animation(&model.get_hash(), &new_animation_name)
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let model_appearance = model_appearance.lock().unwrap();
+
+      assert_eq!(
+        model_appearance.current_animation_name(),
+        Some(new_animation_name)
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod add_animation_to_model_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let animation =
+        TestingData::get_test_animation(['1', '2', '3'], AnimationLoopCount::Limited(2));
+      let (_, mut model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager
+        .add_animation_to_model(&0, "This model does not exist.".to_string(), animation)
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn invalid_animation() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let invalid_frame = AnimationFrame::new(Sprite::default(), 1);
+      let animation = AnimationFrames::new(vec![invalid_frame], AnimationLoopCount::Forever, None);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let result = model_manager
+        .add_animation_to_model(&model.get_hash(), "invalid".to_string(), animation)
+        .unwrap_err();
+
+      assert!(matches!(
+        result,
+        ModelError::AnimationError(AnimationError::AnimationValidityCheckFailed(_))
+      ));
+      assert!(model_manager
+        .queue_model_animation(&model.get_hash(), "invalid", false)
+        .is_err());
+    }
+  }
+
+  #[cfg(test)]
+  mod clear_model_animation_queue_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, mut model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager.clear_model_animation_queue(&0).unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn model_exists() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, true)
+        .unwrap();
+
+      model_manager
+        .clear_model_animation_queue(&model.get_hash())
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let model_appearance = model_appearance.lock().unwrap();
+
+      assert_eq!(
+        model_appearance.animation_is_currently_queued(TestingData::ANIMATION_NAME),
         None
       );
     }
   }
+
+  #[cfg(test)]
+  mod get_animation_queue_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let result = model_manager.get_animation_queue(&0).unwrap_err();
+
+      assert_eq!(result, ModelError::ModelDoesntExist);
+    }
+
+    #[test]
+    fn no_animation_data() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let (_, model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let queue = model_manager
+        .get_animation_queue(&model.get_hash())
+        .unwrap();
+
+      assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn queue_is_in_order() {
+      let first_animation =
+        TestingData::get_test_animation(['1', '2', '3'], AnimationLoopCount::Forever);
+      let second_animation =
+        TestingData::get_test_animation(['4', '5', '6'], AnimationLoopCount::Forever);
+      let model = TestingData::new_test_model_with_animation(
+        WORLD_POSITION,
+        vec![
+          ("first".to_string(), first_animation),
+          ("second".to_string(), second_animation),
+        ],
+      );
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), "first", false)
+        .unwrap();
+      model_manager
+        .queue_model_animation(&model.get_hash(), "second", false)
+        .unwrap();
+
+      let queue = model_manager
+        .get_animation_queue(&model.get_hash())
+        .unwrap();
+
+      assert_eq!(queue, vec!["first".to_string(), "second".to_string()]);
+    }
+  }
+
+  #[cfg(test)]
+  mod move_queued_animation_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, mut model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager
+        .move_queued_animation(&0, TestingData::ANIMATION_NAME, 0)
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn animation_not_queued() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let expected_result = ModelError::AnimationError(AnimationError::AnimationNotQueued {
+        animation_name: TestingData::ANIMATION_NAME.to_string(),
+      });
+
+      let result = model_manager
+        .move_queued_animation(&model.get_hash(), TestingData::ANIMATION_NAME, 0)
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn second_animation_moved_to_front() {
+      let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+      let model_hash = model.get_hash();
+      let animation_names = ["first", "second", "third"];
+
+      for (animation_name, characters) in
+        animation_names
+          .iter()
+          .zip([['b', 'c', 'd'], ['e', 'f', 'g'], ['h', 'i', 'j']])
+      {
+        let animation = TestingData::get_test_animation(characters, AnimationLoopCount::Limited(1));
+
+        model_manager
+          .add_animation_to_model(&model_hash, animation_name.to_string(), animation)
+          .unwrap();
+        model_manager
+          .queue_model_animation(&model_hash, animation_name, true)
+          .unwrap();
+      }
+
+      model_manager
+        .move_queued_animation(&model_hash, "second", 0)
+        .unwrap();
+
+      let model_appearance = model.get_appearance_data();
+      let model_appearance = model_appearance.lock().unwrap();
+
+      assert_eq!(
+        model_appearance.current_animation_name().as_deref(),
+        Some("second")
+      );
+      assert_eq!(
+        model_appearance.animation_is_currently_queued("second"),
+        Some(0)
+      );
+      assert_eq!(
+        model_appearance.animation_is_currently_queued("first"),
+        Some(1)
+      );
+      assert_eq!(
+        model_appearance.animation_is_currently_queued("third"),
+        Some(2)
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod ticks_since_last_animation_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager.ticks_since_last_animation(&0).unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn no_animation_has_finished() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let expected_result = ModelError::AnimationError(AnimationError::NoAnimationHasFinished);
+
+      let result = model_manager
+        .ticks_since_last_animation(&model.get_hash())
+        .unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn idle_ticks_increase() {
+      let (model, animation) =
+        TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (screen, mut model_manager) = setup_model_manager(vec![model.clone()]);
+      let event_sync = screen.get_event_sync();
+      let animation_duration = animation.get_total_duration().unwrap() as u32;
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+      let ticks_while_running = model_manager
+        .ticks_since_last_animation(&model.get_hash())
+        .unwrap();
+
+      // Wait an extra tick to make sure the animation has finished.
+      event_sync.wait_for_x_ticks(animation_duration + 1).unwrap();
+      let ticks_after_finishing = model_manager
+        .ticks_since_last_animation(&model.get_hash())
+        .unwrap();
+
+      event_sync.wait_for_x_ticks(3).unwrap();
+      let ticks_after_waiting = model_manager
+        .ticks_since_last_animation(&model.get_hash())
+        .unwrap();
+
+      assert_eq!(ticks_while_running, 0);
+      assert!(ticks_after_waiting > ticks_after_finishing);
+    }
+  }
+
+  #[cfg(test)]
+  mod animation_just_finished_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let result = model_manager.animation_just_finished(&0);
+
+      assert_eq!(result, Err(ModelError::ModelDoesntExist));
+    }
+
+    #[test]
+    fn limited_animation_reports_once() {
+      let (model, animation) =
+        TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (screen, mut model_manager) = setup_model_manager(vec![model.clone()]);
+      let event_sync = screen.get_event_sync();
+      let animation_duration = animation.get_total_duration().unwrap() as u32;
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+      let while_running = model_manager
+        .animation_just_finished(&model.get_hash())
+        .unwrap();
+
+      // Wait an extra tick to make sure the animation has finished.
+      event_sync.wait_for_x_ticks(animation_duration + 1).unwrap();
+      let after_finishing = model_manager
+        .animation_just_finished(&model.get_hash())
+        .unwrap();
+      let checked_again = model_manager
+        .animation_just_finished(&model.get_hash())
+        .unwrap();
+
+      assert!(!while_running);
+      assert!(after_finishing);
+      assert!(!checked_again);
+    }
+
+    #[test]
+    fn forever_animation_never_finishes() {
+      let animation = TestingData::get_test_animation(['x', 'y', 'z'], AnimationLoopCount::Forever);
+      let two_cycles = animation.get_cycle_duration() as u32 * 2;
+      let model = TestingData::new_test_model_with_animation(
+        WORLD_POSITION,
+        vec![("forever".to_string(), animation)],
+      );
+      let (screen, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), "forever", false)
+        .unwrap();
+      screen
+        .get_event_sync()
+        .wait_for_x_ticks(two_cycles)
+        .unwrap();
+
+      let result = model_manager
+        .animation_just_finished(&model.get_hash())
+        .unwrap();
+
+      assert!(!result);
+    }
+  }
+
+  #[cfg(test)]
+  mod animation_loops_remaining_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager.animation_loops_remaining(&0).unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn idle_model() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let result = model_manager
+        .animation_loops_remaining(&model.get_hash())
+        .unwrap();
+
+      assert!(result.is_none());
+    }
+
+    #[test]
+    fn animation_running() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      let result = model_manager
+        .animation_loops_remaining(&model.get_hash())
+        .unwrap();
+
+      assert_eq!(result, Some(2));
+    }
+  }
+
+  #[cfg(test)]
+  mod current_frame_index_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let expected_result = ModelError::ModelDoesntExist;
+
+      let result = model_manager.current_frame_index(&0).unwrap_err();
+
+      assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn idle_model() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let result = model_manager
+        .current_frame_index(&model.get_hash())
+        .unwrap();
+
+      assert!(result.is_none());
+    }
+
+    #[test]
+    fn animation_running() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      let result = model_manager
+        .current_frame_index(&model.get_hash())
+        .unwrap();
+
+      assert_eq!(result, Some(0));
+    }
+  }
+
+  #[test]
+  fn paused_animations_do_not_advance() {
+    let (mut model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+    let (screen, mut model_manager) = setup_model_manager(vec![model.clone()]);
+    model_manager
+      .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+      .unwrap();
+
+    model_manager.pause_animations();
+    screen.get_event_sync().wait_for_x_ticks(2).unwrap();
+    let paused_frame_index = model_manager
+      .current_frame_index(&model.get_hash())
+      .unwrap();
+    model_manager.resume_animations();
+
+    assert_eq!(paused_frame_index, Some(0));
+    assert!(!model
+      .get_appearance_data()
+      .lock()
+      .unwrap()
+      .animations_are_paused());
+  }
+
+  #[cfg(test)]
+  mod current_anchor_offset_logic {
+    use super::*;
+
+    #[test]
+    fn model_doesnt_exist() {
+      let (_, model_manager) = setup_model_manager(vec![]);
+
+      let result = model_manager.current_anchor_offset(&0).unwrap_err();
+
+      assert_eq!(result, ModelError::ModelDoesntExist);
+    }
+
+    #[test]
+    fn idle_model() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, model_manager) = setup_model_manager(vec![model.clone()]);
+
+      let result = model_manager
+        .current_anchor_offset(&model.get_hash())
+        .unwrap();
+
+      assert!(result.is_none());
+    }
+
+    #[test]
+    fn animation_running() {
+      let frames: Vec<AnimationFrame> =
+        TestingData::get_test_frames(vec![(TestingData::get_frame_appearance('x'), 10, 'x')])
+          .into_iter()
+          .map(|frame| frame.with_anchor_offset((2, -1)))
+          .collect();
+      let animation = AnimationFrames::new(frames, AnimationLoopCount::Forever, None);
+      let model = TestingData::new_test_model_with_animation(
+        WORLD_POSITION,
+        vec![("lunge".to_string(), animation)],
+      );
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), "lunge", false)
+        .unwrap();
+
+      let result = model_manager
+        .current_anchor_offset(&model.get_hash())
+        .unwrap();
+
+      assert_eq!(result, Some((2, -1)));
+    }
+  }
+
+  #[cfg(test)]
+  mod has_active_animations_logic {
+    use super::*;
+
+    #[test]
+    fn idle_world() {
+      let model = TestingData::new_test_model(WORLD_POSITION);
+      let (animated_model, _) =
+        TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, model_manager) = setup_model_manager(vec![model, animated_model]);
+
+      assert!(!model_manager.has_active_animations());
+    }
+
+    #[test]
+    fn animation_queued() {
+      let (model, _) = TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      assert!(model_manager.has_active_animations());
+    }
+
+    #[test]
+    fn animation_finished() {
+      let (model, animation) =
+        TestingData::new_test_model_animated(WORLD_POSITION, ['x', 'y', 'z']);
+      let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+      let animation_duration = animation.get_total_duration().unwrap();
+
+      model_manager
+        .queue_model_animation(&model.get_hash(), TestingData::ANIMATION_NAME, false)
+        .unwrap();
+
+      // Wait an extra tick to make sure the animation has finished.
+      let wait_time = (animation_duration + 1) * CONFIG.tick_duration as u64;
+      std::thread::sleep(std::time::Duration::from_millis(wait_time));
+
+      assert!(!model_manager.has_active_animations());
+    }
+  }
 }
 
 #[cfg(test)]
@@ -457,7 +2084,7 @@ mod model_tag_logic {
   fn get_models_with_tags() {
     let mut model_1 = TestingData::new_test_model(WORLD_POSITION);
     let mut model_2 = TestingData::new_test_model(WORLD_POSITION);
-    let tags = vec!["Player".to_string(), "Test".to_string()];
+    let tags = ["Player".to_string(), "Test".to_string()];
     model_1.add_tags(vec![tags[0].clone(), tags[1].clone()]);
     model_2.add_tags(vec![tags[1].clone()]);
     let (_, model_manager) = setup_model_manager(vec![model_1.clone(), model_2.clone()]);
@@ -473,6 +2100,58 @@ mod model_tag_logic {
     assert_eq!(multiple_tag_models, vec![model_1.get_hash()]);
   }
 
+  #[test]
+  fn get_models_matching_any_tag() {
+    let mut goblin = TestingData::new_test_model(WORLD_POSITION);
+    let mut orc = TestingData::new_test_model(WORLD_POSITION);
+    let mut player = TestingData::new_test_model(WORLD_POSITION);
+    goblin.add_tags(vec!["Goblin".to_string()]);
+    orc.add_tags(vec!["Orc".to_string(), "Large".to_string()]);
+    player.add_tags(vec!["Player".to_string()]);
+    let (_, model_manager) = setup_model_manager(vec![goblin.clone(), orc.clone(), player.clone()]);
+
+    let mut expected_models = vec![goblin.get_hash(), orc.get_hash()];
+    expected_models.sort();
+
+    let mut any_models =
+      model_manager.get_models_matching_tags(vec!["Goblin", "Orc"], TagMatch::Any);
+    any_models.sort();
+    let all_models = model_manager.get_models_matching_tags(vec!["Goblin", "Orc"], TagMatch::All);
+    let no_tags = model_manager.get_models_matching_tags(Vec::<&str>::new(), TagMatch::Any);
+
+    assert_eq!(any_models, expected_models);
+    assert!(all_models.is_empty());
+    assert!(no_tags.is_empty());
+  }
+
+  #[test]
+  fn get_models_with_tag_group() {
+    let mut goblin = TestingData::new_test_model(WORLD_POSITION);
+    let mut orc = TestingData::new_test_model(WORLD_POSITION);
+    let mut player = TestingData::new_test_model(WORLD_POSITION);
+    goblin.add_tags(vec!["Goblin".to_string(), "Small".to_string()]);
+    orc.add_tags(vec!["Orc".to_string()]);
+    player.add_tags(vec!["Player".to_string(), "Small".to_string()]);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![goblin.clone(), orc.clone(), player.clone()]);
+    model_manager.define_tag_group(
+      "enemies".to_string(),
+      vec!["Goblin".to_string(), "Orc".to_string()],
+    );
+
+    let mut expected_enemies = vec![goblin.get_hash(), orc.get_hash()];
+    expected_enemies.sort();
+
+    let mut enemies = model_manager.get_models_with_tags(vec!["@enemies"]);
+    enemies.sort();
+    let small_enemies = model_manager.get_models_with_tags(vec!["@enemies", "Small"]);
+    let undefined_group = model_manager.get_models_with_tags(vec!["@allies"]);
+
+    assert_eq!(enemies, expected_enemies);
+    assert_eq!(small_enemies, vec![goblin.get_hash()]);
+    assert!(undefined_group.is_empty());
+  }
+
   #[test]
   fn get_tags_of_model() {
     let mut model = TestingData::new_test_model(WORLD_POSITION);
@@ -486,15 +2165,172 @@ mod model_tag_logic {
 
     assert_eq!(result, expected_result);
   }
+
+  #[test]
+  fn remove_tags_from_model() {
+    let mut model = TestingData::new_test_model(WORLD_POSITION);
+    model.add_tags(vec!["Player".to_string(), "Stunned".to_string()]);
+    let (_, mut model_manager) = setup_model_manager(vec![model.clone()]);
+
+    let expected_tags = HashSet::from(["Player".to_string()]);
+
+    model_manager
+      .remove_tags_from_model(&model.get_hash(), vec!["Stunned"])
+      .unwrap();
+    let missing_model_result = model_manager.remove_tags_from_model(&0, vec!["Player"]);
+
+    assert_eq!(model.get_tags(), expected_tags);
+    assert_eq!(missing_model_result, Err(ModelError::ModelDoesntExist));
+  }
 }
 
 #[cfg(test)]
 mod collision_event_logic {
   use std::collections::VecDeque;
+  use std::sync::{Arc, Mutex};
 
   #[allow(unused)]
   use super::*;
 
+  #[test]
+  fn collision_event_limit_drops_oldest_events() {
+    let model_mover = TestingData::new_test_model(WORLD_POSITION);
+    let model_collided = TestingData::new_test_model(WORLD_POSITION);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![model_mover.clone(), model_collided.clone()]);
+    model_manager.set_collision_event_limit(2);
+
+    for x in 1..=3 {
+      model_manager
+        .move_model(&model_mover.get_hash(), ModelMovement::Relative((x, 0)))
+        .unwrap();
+      model_manager
+        .move_model(&model_mover.get_hash(), ModelMovement::Relative((-x, 0)))
+        .unwrap();
+    }
+
+    let movements: Vec<ModelMovement> = model_manager
+      .take_collision_events()
+      .into_iter()
+      .map(|(_, collision)| collision.caused_movement)
+      .collect();
+    let expected_movements = vec![
+      ModelMovement::Relative((3, 0)),
+      ModelMovement::Relative((-3, 0)),
+    ];
+
+    assert_eq!(movements, expected_movements);
+  }
+
+  #[test]
+  fn dispatch_collision_events_runs_tagged_handlers() {
+    let model_mover = TestingData::new_test_model(WORLD_POSITION);
+    let mut model_collided = TestingData::new_test_model(WORLD_POSITION);
+    model_collided.add_tags(vec!["Wall".to_string()]);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![model_mover.clone(), model_collided.clone()]);
+
+    let wall_colliders = Arc::new(Mutex::new(vec![]));
+    let handler_colliders = wall_colliders.clone();
+    let enemy_collision_count = Arc::new(Mutex::new(0));
+    let handler_enemy_collision_count = enemy_collision_count.clone();
+
+    model_manager.on_collision(
+      "Wall".to_string(),
+      Box::new(move |collision| handler_colliders.lock().unwrap().push(collision.collider)),
+    );
+    model_manager.on_collision(
+      "Enemy".to_string(),
+      Box::new(move |_| *handler_enemy_collision_count.lock().unwrap() += 1),
+    );
+
+    model_manager
+      .move_model(&model_mover.get_hash(), ModelMovement::Relative((1, 0)))
+      .unwrap();
+    model_manager.dispatch_collision_events();
+
+    assert_eq!(
+      *wall_colliders.lock().unwrap(),
+      vec![model_mover.get_hash()]
+    );
+    assert_eq!(*enemy_collision_count.lock().unwrap(), 0);
+    assert!(model_manager.take_collision_events().is_empty());
+  }
+
+  #[test]
+  fn handlers_run_in_tag_order() {
+    let model_mover = TestingData::new_test_model(WORLD_POSITION);
+    let mut model_collided = TestingData::new_test_model(WORLD_POSITION);
+    model_collided.add_tags(vec![
+      "Wall".to_string(),
+      "Enemy".to_string(),
+      "Spike".to_string(),
+    ]);
+    let (_, mut model_manager) =
+      setup_model_manager(vec![model_mover.clone(), model_collided.clone()]);
+
+    let dispatched_tags = Arc::new(Mutex::new(vec![]));
+
+    for tag in ["Wall", "Enemy", "Spike"] {
+      let handler_tags = dispatched_tags.clone();
+
+      model_manager.on_collision(
+        tag.to_string(),
+        Box::new(move |_| handler_tags.lock().unwrap().push(tag)),
+      );
+    }
+
+    model_manager
+      .move_model(&model_mover.get_hash(), ModelMovement::Relative((1, 0)))
+      .unwrap();
+    model_manager.dispatch_collision_events();
+
+    assert_eq!(
+      *dispatched_tags.lock().unwrap(),
+      vec!["Enemy", "Spike", "Wall"]
+    );
+  }
+
+  #[test]
+  fn handler_can_register_handlers() {
+    let model_mover = TestingData::new_test_model(WORLD_POSITION);
+    let mut model_collided = TestingData::new_test_model(WORLD_POSITION);
+    model_collided.add_tags(vec!["Wall".to_string()]);
+    let (screen_data, mut model_manager) =
+      setup_model_manager(vec![model_mover.clone(), model_collided.clone()]);
+
+    let handler_run_count = Arc::new(Mutex::new(0));
+    let registering_run_count = handler_run_count.clone();
+    let mut handler_model_manager = screen_data.get_model_manager();
+
+    model_manager.on_collision(
+      "Wall".to_string(),
+      Box::new(move |_| {
+        let added_run_count = registering_run_count.clone();
+
+        handler_model_manager.dispatch_collision_events();
+        handler_model_manager.on_collision(
+          "Wall".to_string(),
+          Box::new(move |_| *added_run_count.lock().unwrap() += 1),
+        );
+      }),
+    );
+
+    model_manager
+      .move_model(&model_mover.get_hash(), ModelMovement::Relative((1, 0)))
+      .unwrap();
+    model_manager.dispatch_collision_events();
+
+    assert_eq!(*handler_run_count.lock().unwrap(), 0);
+
+    model_manager
+      .move_model(&model_mover.get_hash(), ModelMovement::Relative((-1, 0)))
+      .unwrap();
+    model_manager.dispatch_collision_events();
+
+    assert_eq!(*handler_run_count.lock().unwrap(), 1);
+  }
+
   #[test]
   fn take_collision_events_does_remove_events() {
     let model_mover = TestingData::new_test_model(WORLD_POSITION);
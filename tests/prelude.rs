@@ -0,0 +1,21 @@
+#![cfg(test)]
+
+use ascii_engine::prelude::*;
+
+/// Fails to compile if any part of the model based API is missing from the prelude.
+#[test]
+fn prelude_exposes_model_api() {
+  let _: Option<ModelData> = None;
+  let _: Option<Sprite> = None;
+  let _: Option<Hitbox> = None;
+  let _: Option<ModelManager> = None;
+  let _: Option<ModelMovement> = None;
+  let _: Option<ModelCollisions> = None;
+  let _: Option<Strata> = None;
+  let _: Option<AnimationFrames> = None;
+  let _: Option<AnimationFrame> = None;
+  let _: Option<AnimationLoopCount> = None;
+  let _: Option<StoredWorld> = None;
+  let _: Option<EventSync> = None;
+  let _: Option<EventSync<Immutable>> = None;
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use ascii_engine::prelude::*;
+use engine_math::coordinates::*;
 use model_data_structures::models::testing_data::TestingData;
 
 const WORLD_POSITION: (usize, usize) = (10, 10);
@@ -35,6 +36,109 @@ mod display_logic {
     assert_eq!(display.chars().count(), expected_pixel_count);
   }
 
+  #[test]
+  fn print_screen_to_string_logic() {
+    let mut screen = ScreenData::new();
+    let test_model = TestingData::new_test_model(WORLD_POSITION);
+    let top_left = test_model.get_frame_position();
+    screen.add_model(test_model).unwrap();
+    let row_width = CONFIG.frame_width();
+
+    let frame = screen.print_screen_to_string().unwrap();
+
+    // The test model is 5x3, with its anchor replaced with an 'x'.
+    assert_eq!(frame, screen.display());
+    assert!((0..3)
+      .map(|row| top_left + (row * row_width))
+      .all(|row_start| &frame[row_start..row_start + 5] == "xxxxx"));
+  }
+
+  #[test]
+  fn hidden_model_is_not_drawn() {
+    let mut screen = ScreenData::new();
+    let mut test_model = TestingData::new_test_model(WORLD_POSITION);
+    screen.add_model(test_model.clone()).unwrap();
+
+    test_model.set_visible(false);
+    let hidden_display = screen.display();
+    test_model.set_visible(true);
+    let shown_display = screen.display();
+
+    assert_eq!(hidden_display, screen.blank_frame());
+    assert_ne!(shown_display, screen.blank_frame());
+    assert!(screen
+      .get_model_manager()
+      .model_exists(&test_model.get_hash()));
+  }
+
+  #[test]
+  fn blank_frame_logic() {
+    let screen = ScreenData::new();
+    let empty_pixel = CONFIG.empty_pixel.chars().next().unwrap();
+
+    let blank_frame = screen.blank_frame();
+    let rows: Vec<&str> = blank_frame.lines().collect();
+
+    assert_eq!(rows.len(), CONFIG.grid_height as usize);
+    assert!(rows
+      .iter()
+      .all(|row| row.chars().count() == CONFIG.grid_width as usize));
+    assert!(rows
+      .iter()
+      .flat_map(|row| row.chars())
+      .all(|pixel| pixel == empty_pixel));
+  }
+
+  #[test]
+  fn capture_frame_logic() {
+    let mut screen = ScreenData::new();
+    let test_model = TestingData::new_test_model(WORLD_POSITION);
+    let world_position = test_model.get_world_position();
+    let top_left = test_model.get_frame_position();
+    let (left, top) = (
+      top_left % CONFIG.frame_width(),
+      top_left / CONFIG.frame_width(),
+    );
+    screen.add_model(test_model).unwrap();
+    let empty_pixel = CONFIG.empty_pixel.chars().next().unwrap();
+
+    let frame = screen.capture_frame();
+
+    assert_eq!(frame.len(), CONFIG.grid_height as usize);
+    assert!(frame
+      .iter()
+      .all(|row| row.len() == CONFIG.grid_width as usize));
+    // The test model is 5x3, with its anchor replaced with an 'x'.
+    assert_eq!(
+      frame_to_world((left as isize + 2, top as isize + 1)),
+      world_position
+    );
+    assert_eq!(frame[top][left], 'x');
+    assert_eq!(frame[top + 2][left + 4], 'x');
+    assert_eq!(frame[top][left - 1], empty_pixel);
+    assert_eq!(frame[top + 3][left], empty_pixel);
+  }
+
+  #[test]
+  fn set_empty_pixel_logic() {
+    let mut screen = ScreenData::new();
+    let test_model = TestingData::new_test_model(WORLD_POSITION);
+    let top_left = test_model.get_frame_position();
+    screen.add_model(test_model).unwrap();
+
+    screen.set_empty_pixel('.');
+    let display = screen.display();
+
+    assert!(screen
+      .blank_frame()
+      .lines()
+      .flat_map(|row| row.chars())
+      .all(|pixel| pixel == '.'));
+    assert_eq!(display.chars().next(), Some('.'));
+    assert_eq!(display.chars().nth(top_left - 1), Some('.'));
+    assert_eq!(display.chars().nth(top_left), Some('x'));
+  }
+
   #[test]
   fn get_screen_printer_logic() {
     let screen = ScreenData::new();
@@ -42,6 +146,88 @@ mod display_logic {
   }
 }
 
+#[cfg(test)]
+mod frame_patch_logic {
+  use super::*;
+
+  #[test]
+  fn single_cell_difference() {
+    let screen = ScreenData::new();
+    let previous = "-----\n-----\n-----";
+    let current = "-----\n---x-\n-----";
+
+    // Terminal positions start at 1.
+    let expected_patch = format!("{}x", termion::cursor::Goto(4, 2));
+
+    let patch = screen.frame_patch(previous, current);
+
+    assert_eq!(patch, expected_patch);
+  }
+
+  #[test]
+  fn separate_runs_in_a_row() {
+    let screen = ScreenData::new();
+    let previous = "------\n------";
+    let current = "ab--c-\n------";
+
+    let expected_patch = format!(
+      "{}ab{}c",
+      termion::cursor::Goto(1, 1),
+      termion::cursor::Goto(5, 1)
+    );
+
+    let patch = screen.frame_patch(previous, current);
+
+    assert_eq!(patch, expected_patch);
+  }
+
+  #[test]
+  fn shorter_row_erases_leftover_characters() {
+    let screen = ScreenData::new();
+    let previous = "-----\n-----";
+    let current = "---\n-----";
+
+    let expected_patch = format!(
+      "{}{}",
+      termion::cursor::Goto(4, 1),
+      termion::clear::UntilNewline
+    );
+
+    let patch = screen.frame_patch(previous, current);
+
+    assert_eq!(patch, expected_patch);
+  }
+
+  #[test]
+  fn missing_rows_are_erased() {
+    let screen = ScreenData::new();
+    let previous = "-----\n-----\n-----";
+    let current = "-----";
+
+    let expected_patch = format!(
+      "{}{}{}{}",
+      termion::cursor::Goto(1, 2),
+      termion::clear::UntilNewline,
+      termion::cursor::Goto(1, 3),
+      termion::clear::UntilNewline
+    );
+
+    let patch = screen.frame_patch(previous, current);
+
+    assert_eq!(patch, expected_patch);
+  }
+
+  #[test]
+  fn identical_frames() {
+    let screen = ScreenData::new();
+    let frame = screen.display();
+
+    let patch = screen.frame_patch(&frame, &frame);
+
+    assert!(patch.is_empty());
+  }
+}
+
 #[test]
 fn add_and_remove_model() {
   let mut screen = ScreenData::new();
@@ -66,7 +252,7 @@ mod world_management_logic {
   #[test]
   fn reset_world_logic() {
     let test_world = StoredWorld::load(PathBuf::from("tests/worlds/test_template.world")).unwrap();
-    let mut screen_data = ScreenData::from_world(test_world);
+    let (mut screen_data, _) = ScreenData::from_world(test_world).unwrap();
     let model_manager = screen_data.get_model_manager();
 
     let reset_world = screen_data.reset_world();
@@ -91,10 +277,77 @@ mod world_management_logic {
     });
   }
 
+  #[test]
+  fn from_world_clamps_out_of_bounds_models() {
+    let in_bounds_model = TestingData::new_test_model(WORLD_POSITION);
+    let mut out_of_bounds_model = TestingData::new_test_model(WORLD_POSITION);
+    let past_bottom_right = (CONFIG.grid_width as usize, CONFIG.grid_height as usize * 2)
+      .coordinates_to_index(CONFIG.frame_width());
+    out_of_bounds_model.change_position(past_bottom_right);
+    let test_world = StoredWorld::new(vec![in_bounds_model.clone(), out_of_bounds_model]);
+
+    let (sprite_width, sprite_height) = (5, 3);
+    let last_frame_position = (
+      CONFIG.grid_width as usize - sprite_width,
+      CONFIG.grid_height as usize - sprite_height,
+    )
+      .coordinates_to_index(CONFIG.frame_width());
+
+    let (screen_data, clamped_models) = ScreenData::from_world(test_world).unwrap();
+    let model_manager = screen_data.get_model_manager();
+
+    assert_eq!(clamped_models.len(), 1);
+    assert_eq!(
+      model_manager
+        .get_model(&clamped_models[0])
+        .unwrap()
+        .get_frame_position(),
+      last_frame_position
+    );
+    assert!(model_manager.get_model_list(|model_list| {
+      model_list
+        .values()
+        .any(|model| model.get_frame_position() == in_bounds_model.get_frame_position())
+    }));
+    let display = screen_data.display();
+    assert!(display
+      .lines()
+      .rev()
+      .take(3)
+      .all(|row| row.ends_with("xxxxx")));
+  }
+
+  #[test]
+  fn from_world_clamps_models_past_the_right_edge() {
+    let mut out_of_bounds_model = TestingData::new_test_model(WORLD_POSITION);
+    let row = 4;
+    let past_right_edge =
+      (CONFIG.grid_width as usize, row).coordinates_to_index(CONFIG.frame_width());
+    out_of_bounds_model.change_position(past_right_edge);
+    let test_world = StoredWorld::new(vec![out_of_bounds_model]);
+
+    let expected_position =
+      (CONFIG.grid_width as usize - 5, row).coordinates_to_index(CONFIG.frame_width());
+
+    let (screen_data, clamped_models) = ScreenData::from_world(test_world).unwrap();
+    let model_manager = screen_data.get_model_manager();
+    let display = screen_data.display();
+
+    assert_eq!(clamped_models.len(), 1);
+    assert_eq!(
+      model_manager
+        .get_model(&clamped_models[0])
+        .unwrap()
+        .get_frame_position(),
+      expected_position
+    );
+    assert!(display.lines().nth(row).unwrap().ends_with("xxxxx"));
+  }
+
   #[test]
   fn copy_current_world_logic() {
     let test_world = StoredWorld::load(PathBuf::from("tests/worlds/test_template.world")).unwrap();
-    let screen_data = ScreenData::from_world(test_world);
+    let (screen_data, _) = ScreenData::from_world(test_world).unwrap();
     let model_manager = screen_data.get_model_manager();
 
     let copied_world = screen_data.copy_current_world();
@@ -107,6 +360,28 @@ mod world_management_logic {
   }
 }
 
+#[test]
+fn dimensions_logic() {
+  let screen_data = ScreenData::new();
+  let (width, height) = screen_data.dimensions();
+
+  assert_eq!((width, height), (175, 40));
+  assert_eq!(CONFIG.frame_width(), width + 1);
+  assert!(screen_data.position_in_bounds((0, 0)));
+  assert!(screen_data.position_in_bounds((width - 1, height - 1)));
+  assert!(!screen_data.position_in_bounds((width, 0)));
+  assert!(!screen_data.position_in_bounds((0, height)));
+}
+
+#[test]
+fn config_accessors_logic() {
+  let config = config();
+
+  assert_eq!(config.tick_duration_ms(), CONFIG.tick_duration);
+  assert_eq!(config.grid_width(), CONFIG.grid_width);
+  assert_eq!(config.grid_height(), CONFIG.grid_height);
+}
+
 #[test]
 fn get_event_sync_logic() {
   let screen = ScreenData::new();